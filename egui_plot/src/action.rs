@@ -59,6 +59,16 @@ pub struct PinRow {
     pub color_rgba: [u8; 4],
 }
 
+/// Aggregate of the Y values of one series, see [`PlotEvent::SelectionStats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub sum: f64,
+}
+
 /// Adapter trait: executor mutates your bounds type without depending on its API.
 ///
 /// An impl for `crate::transform::PlotBounds` is provided below.
@@ -159,6 +169,20 @@ pub enum PlotEvent {
         input: InputInfo,
     },
 
+    /// A pan drag began at `start_pos` (plot coordinates). Fires once per gesture.
+    DragStarted {
+        start_pos: PlotPoint,
+    },
+
+    /// The pan drag that began at `start_pos` was released.
+    ///
+    /// `end_pos` is the release point in the plot coordinates at the start of the drag, so
+    /// `start_pos - end_pos` is how far the view moved.
+    DragEnded {
+        start_pos: PlotPoint,
+        end_pos: PlotPoint,
+    },
+
     ZoomStarted {
         input: InputInfo,
     },
@@ -208,11 +232,16 @@ pub enum PlotEvent {
         pos: PlotPoint,
     },
 
+    /// A primary click landed on a sample of `item` (within its marker radius, or the
+    /// interaction radius, whichever is larger).
+    ///
+    /// `index` counts the item's samples in order (across blocks for block series); for
+    /// lines it is the nearest vertex.
     ItemClicked {
         item: PlotItemId,
-        pos: PlotPoint,
-        button: PointerButton,
-        input: InputInfo,
+        series_name: String,
+        index: usize,
+        value: PlotPoint,
     },
 
     LegendItemToggled {
@@ -220,6 +249,28 @@ pub enum PlotEvent {
         now_visible: bool,
     },
 
+    /// A box selection was released (see [`crate::NavigationConfig::box_select`]).
+    ///
+    /// One entry per series with at least one sample inside `bounds`.
+    SelectionStats {
+        bounds: PlotBounds,
+        per_series: Vec<(String, SeriesStats)>,
+    },
+
+    /// The measure ruler was placed (see [`crate::NavigationConfig::measure`]).
+    MeasureUpdated {
+        start: PlotPoint,
+        end: PlotPoint,
+    },
+
+    /// A [`crate::DraggablePoint`] was dragged to `pos` this frame.
+    PointDragged {
+        id: PlotItemId,
+        pos: PlotPoint,
+        /// Name of the series whose sample the point snapped to, if any.
+        snapped_to: Option<String>,
+    },
+
     // Pins
     PinAdded {
         snapshot: PinSnapshot,
@@ -228,6 +279,145 @@ pub enum PlotEvent {
         index: usize,
     },
     PinsCleared,
+    /// The pin key was pressed with [`crate::TooltipOptions::max_pins`] pins already set.
+    ///
+    /// `evicted` oldest pins were dropped to make room, or `0` if the new pin was dropped
+    /// instead (see [`crate::PinOverflow`]).
+    PinOverflow {
+        evicted: usize,
+    },
+}
+
+/// A set of [`PlotEvent`] variants, one bit per variant.
+///
+/// Events outside the set are not reported by [`crate::Plot::show_actions`]; the hot ones
+/// (e.g. [`PlotEvent::Hover`], which fires every frame) are not even constructed.
+///
+/// ```
+/// # use egui_plot::EventMask;
+/// let mask = EventMask::ALL.without(EventMask::HOVER | EventMask::CURSOR_MOVED);
+/// assert!(!mask.contains(EventMask::HOVER));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventMask(u64);
+
+impl EventMask {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u64::MAX);
+
+    pub const KEY_PRESSED: Self = Self(1 << 0);
+    pub const KEY_RELEASED: Self = Self(1 << 1);
+    pub const ACTIVATE: Self = Self(1 << 2);
+    pub const HOVER: Self = Self(1 << 3);
+    pub const CONTEXT_MENU_REQUESTED: Self = Self(1 << 4);
+    pub const BOUNDS_CHANGED: Self = Self(1 << 5);
+    pub const TRANSFORM_CHANGED: Self = Self(1 << 6);
+    pub const AUTO_FIT_APPLIED: Self = Self(1 << 7);
+    pub const RESET_APPLIED: Self = Self(1 << 8);
+    pub const PAN_STARTED: Self = Self(1 << 9);
+    pub const PAN_DELTA: Self = Self(1 << 10);
+    pub const PAN_FINISHED: Self = Self(1 << 11);
+    pub const ZOOM_STARTED: Self = Self(1 << 12);
+    pub const ZOOM_DELTA: Self = Self(1 << 13);
+    pub const ZOOM_FINISHED: Self = Self(1 << 14);
+    pub const AXIS_ZOOM_DRAG_STARTED: Self = Self(1 << 15);
+    pub const AXIS_ZOOM_DRAG_DELTA: Self = Self(1 << 16);
+    pub const AXIS_ZOOM_DRAG_FINISHED: Self = Self(1 << 17);
+    pub const BOX_ZOOM_STARTED: Self = Self(1 << 18);
+    pub const BOX_ZOOM_FINISHED: Self = Self(1 << 19);
+    pub const CURSOR_MOVED: Self = Self(1 << 20);
+    pub const ITEM_HOVERED: Self = Self(1 << 21);
+    pub const ITEM_CLICKED: Self = Self(1 << 22);
+    pub const LEGEND_ITEM_TOGGLED: Self = Self(1 << 23);
+    pub const MEASURE_UPDATED: Self = Self(1 << 24);
+    pub const POINT_DRAGGED: Self = Self(1 << 25);
+    pub const PIN_ADDED: Self = Self(1 << 26);
+    pub const PIN_REMOVED: Self = Self(1 << 27);
+    pub const PINS_CLEARED: Self = Self(1 << 28);
+    pub const SELECTION_STATS: Self = Self(1 << 29);
+    pub const DRAG_STARTED: Self = Self(1 << 30);
+    pub const DRAG_ENDED: Self = Self(1 << 31);
+    pub const PIN_OVERFLOW: Self = Self(1 << 32);
+
+    /// `true` if every event in `other` is in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// `self` with the events of `other` removed.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// The single-variant mask of `event`.
+    pub fn of(event: &PlotEvent) -> Self {
+        match event {
+            PlotEvent::KeyPressed { .. } => Self::KEY_PRESSED,
+            PlotEvent::KeyReleased { .. } => Self::KEY_RELEASED,
+            PlotEvent::Activate { .. } => Self::ACTIVATE,
+            PlotEvent::Hover { .. } => Self::HOVER,
+            PlotEvent::ContextMenuRequested { .. } => Self::CONTEXT_MENU_REQUESTED,
+            PlotEvent::BoundsChanged { .. } => Self::BOUNDS_CHANGED,
+            PlotEvent::TransformChanged { .. } => Self::TRANSFORM_CHANGED,
+            PlotEvent::AutoFitApplied { .. } => Self::AUTO_FIT_APPLIED,
+            PlotEvent::ResetApplied { .. } => Self::RESET_APPLIED,
+            PlotEvent::PanStarted { .. } => Self::PAN_STARTED,
+            PlotEvent::PanDelta { .. } => Self::PAN_DELTA,
+            PlotEvent::PanFinished { .. } => Self::PAN_FINISHED,
+            PlotEvent::DragStarted { .. } => Self::DRAG_STARTED,
+            PlotEvent::DragEnded { .. } => Self::DRAG_ENDED,
+            PlotEvent::ZoomStarted { .. } => Self::ZOOM_STARTED,
+            PlotEvent::ZoomDelta { .. } => Self::ZOOM_DELTA,
+            PlotEvent::ZoomFinished { .. } => Self::ZOOM_FINISHED,
+            PlotEvent::AxisZoomDragStarted { .. } => Self::AXIS_ZOOM_DRAG_STARTED,
+            PlotEvent::AxisZoomDragDelta { .. } => Self::AXIS_ZOOM_DRAG_DELTA,
+            PlotEvent::AxisZoomDragFinished { .. } => Self::AXIS_ZOOM_DRAG_FINISHED,
+            PlotEvent::BoxZoomStarted { .. } => Self::BOX_ZOOM_STARTED,
+            PlotEvent::BoxZoomFinished { .. } => Self::BOX_ZOOM_FINISHED,
+            PlotEvent::CursorMoved { .. } => Self::CURSOR_MOVED,
+            PlotEvent::ItemHovered { .. } => Self::ITEM_HOVERED,
+            PlotEvent::ItemClicked { .. } => Self::ITEM_CLICKED,
+            PlotEvent::LegendItemToggled { .. } => Self::LEGEND_ITEM_TOGGLED,
+            PlotEvent::MeasureUpdated { .. } => Self::MEASURE_UPDATED,
+            PlotEvent::SelectionStats { .. } => Self::SELECTION_STATS,
+            PlotEvent::PointDragged { .. } => Self::POINT_DRAGGED,
+            PlotEvent::PinAdded { .. } => Self::PIN_ADDED,
+            PlotEvent::PinRemoved { .. } => Self::PIN_REMOVED,
+            PlotEvent::PinsCleared => Self::PINS_CLEARED,
+            PlotEvent::PinOverflow { .. } => Self::PIN_OVERFLOW,
+        }
+    }
+
+    /// `true` if `event` is in the set.
+    #[inline]
+    pub fn allows(self, event: &PlotEvent) -> bool {
+        self.contains(Self::of(event))
+    }
+}
+
+impl Default for EventMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for EventMask {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for EventMask {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
 }
 
 /// Input actions recorded during the build phase (`PlotUi`).
@@ -256,6 +446,10 @@ pub enum PlotAction<I> {
     // ------------------------ Decorations / overlays --------------------------
     /// Add an overlay `Shape` to be painted after items.
     AddOverlayShape(Shape),
+
+    // ------------------------------ Events -------------------------------------
+    /// Report an event that happened during the build phase (e.g. a pin evicted by the tooltip).
+    EmitEvent(PlotEvent),
 }
 
 #[derive(Debug)]
@@ -1,11 +1,15 @@
 use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 
 use egui::{
-    Pos2, Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
-    emath::remap_clamp, epaint::TextShape,
+    Color32, Painter, Pos2, Rangef, Rect, Response, Sense, Stroke, TextStyle, TextWrapMode, Ui,
+    Vec2, WidgetText,
+    emath::{Rot2, remap_clamp},
+    epaint::TextShape,
 };
 
-use super::{GridMark, transform::PlotTransform};
+use super::{
+    BreakStyle, GridMark, NumberFormat, SegmentSizing, TimeFormat, transform::PlotTransform,
+};
 
 // Gap between tick labels and axis label in units of the axis label height
 const AXIS_LABEL_GAP: f32 = 0.25;
@@ -96,6 +100,29 @@ impl From<Placement> for VPlacement {
     }
 }
 
+/// Short perpendicular marks drawn on the axis strip at each tick, see [`AxisHints::tick_marks`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickMarkStyle {
+    /// Mark length in points. Segment edges of a [`crate::SegmentedAxis`] get 1.5× this.
+    pub length: f32,
+
+    /// A transparent color means the (faded) text color is used.
+    pub stroke: Stroke,
+
+    /// Also mark ticks too dense to be labeled, at half the length.
+    pub minor: bool,
+}
+
+impl Default for TickMarkStyle {
+    fn default() -> Self {
+        Self {
+            length: 4.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            minor: false,
+        }
+    }
+}
+
 /// Axis configuration.
 ///
 /// Used to configure axis label and ticks.
@@ -103,9 +130,16 @@ impl From<Placement> for VPlacement {
 pub struct AxisHints<'a> {
     pub(super) label: WidgetText,
     pub(super) formatter: Arc<AxisFormatterFn<'a>>,
+    /// Whether `formatter` was set by the user (and must not be localized).
+    pub(super) custom_formatter: bool,
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) label_angle: f32,
+    pub(super) max_ticks: Option<usize>,
+    pub(super) tick_marks: Option<TickMarkStyle>,
+    pub(super) label_color: Option<Color32>,
+    pub(super) tick_label_color: Option<Color32>,
 }
 
 impl<'a> AxisHints<'a> {
@@ -127,12 +161,18 @@ impl<'a> AxisHints<'a> {
         Self {
             label: Default::default(),
             formatter: Arc::new(Self::default_formatter),
+            custom_formatter: false,
             min_thickness: 14.0,
             placement: Placement::LeftBottom,
             label_spacing: match axis {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            label_angle: 0.0,
+            max_ticks: None,
+            tick_marks: None,
+            label_color: None,
+            tick_label_color: None,
         }
     }
 
@@ -145,6 +185,27 @@ impl<'a> AxisHints<'a> {
         fmt: impl Fn(GridMark, &RangeInclusive<f64>) -> String + 'a,
     ) -> Self {
         self.formatter = Arc::new(fmt);
+        self.custom_formatter = true;
+        self
+    }
+
+    /// Label ticks as Unix timestamps (seconds, UTC), e.g. `12:30:45` or `2024-06-01`.
+    ///
+    /// [`TimeFormat::Auto`] picks the granularity from the visible range. Use it together with
+    /// [`crate::time_grid_spacer`] so ticks fall on clock boundaries.
+    pub fn time_formatter(self, format: TimeFormat) -> Self {
+        self.formatter(move |mark: GridMark, range: &RangeInclusive<f64>| {
+            format.format(mark.value, mark.step_size, range.end() - range.start())
+        })
+    }
+
+    /// Apply `number_format` to the default formatter. Custom formatters are left untouched.
+    pub(super) fn localized(mut self, number_format: NumberFormat) -> Self {
+        if !self.custom_formatter && number_format != NumberFormat::default() {
+            self.formatter = Arc::new(move |mark: GridMark, range: &RangeInclusive<f64>| {
+                number_format.localize(&Self::default_formatter(mark, range))
+            });
+        }
         self
     }
 
@@ -199,6 +260,57 @@ impl<'a> AxisHints<'a> {
         self.label_spacing = range.into();
         self
     }
+
+    /// Rotate the tick labels by `angle` radians (clockwise, as in egui).
+    ///
+    /// The rotated labels are laid out by their bounding box, so they don't overlap
+    /// the plot; labels that would overlap each other are hidden.
+    /// Useful for long labels on a crowded X axis, e.g. `std::f32::consts::FRAC_PI_4`.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn label_angle(mut self, angle: f32) -> Self {
+        self.label_angle = angle;
+        self
+    }
+
+    /// Cap the number of grid marks generated for this axis.
+    ///
+    /// When the grid spacer would produce more, the finer levels are dropped and, if needed,
+    /// the remaining marks are thinned to a coarser multiple of their step.
+    /// Protects against thousands of grid lines on extreme aspect ratios.
+    /// With several axes on the same side, the smallest cap wins.
+    ///
+    /// Default: `None` (no cap).
+    #[inline]
+    pub fn max_ticks(mut self, max_ticks: usize) -> Self {
+        self.max_ticks = Some(max_ticks.max(1));
+        self
+    }
+
+    /// Draw short tick marks on the axis strip, between the plot and the tick labels.
+    ///
+    /// The labels move outwards by the mark length. Default: `None` (labels only).
+    #[inline]
+    pub fn tick_marks(mut self, style: Option<TickMarkStyle>) -> Self {
+        self.tick_marks = style;
+        self
+    }
+
+    /// Color of the axis label. Default: `None` (the theme's text color).
+    #[inline]
+    pub fn label_color(mut self, color: Option<Color32>) -> Self {
+        self.label_color = color;
+        self
+    }
+
+    /// Color of the tick labels, e.g. to stay legible over a custom plot background.
+    /// Labels still fade in as they get further apart. Default: `None` (the theme's text color).
+    #[inline]
+    pub fn tick_label_color(mut self, color: Option<Color32>) -> Self {
+        self.tick_label_color = color;
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -288,8 +400,12 @@ impl<'a> AxisWidget<'a> {
             Axis::Y => -std::f32::consts::FRAC_PI_2,
         };
 
+        let label_color = self
+            .hints
+            .label_color
+            .unwrap_or_else(|| ui.visuals().text_color());
         ui.painter()
-            .add(TextShape::new(text_pos, galley, ui.visuals().text_color()).with_angle(angle));
+            .add(TextShape::new(text_pos, galley, label_color).with_angle(angle));
 
         (response, tick_labels_thickness + axis_label_thickness)
     }
@@ -301,18 +417,37 @@ impl<'a> AxisWidget<'a> {
 
         const SIDE_MARGIN: f32 = 4.0; // Add some margin to both sides of the text on the Y axis.
         let painter = ui.painter();
+        let angle = self.hints.label_angle;
+        let mark_len = self.hints.tick_marks.map_or(0.0, |s| s.length);
+        let base_color = self
+            .hints
+            .tick_label_color
+            .unwrap_or_else(|| ui.visuals().text_color());
 
         // Add tick labels:
         if axis == Axis::X {
             if let Some(bx) = transform.segment_xaxis() {
-                let text_color = ui.visuals().text_color();
+                let text_color = base_color;
 
                 let raw_ticks = build_segmented_ticks_from_steps(transform, bx, &self.steps);
-                let ticks = cluster_ticks_for_labels(raw_ticks, label_spacing.min);
+                if let Some(style) = self.hints.tick_marks.filter(|s| s.minor) {
+                    for tick in &raw_ticks {
+                        let length = 0.5 * style.length;
+                        self.paint_tick_mark(painter, axis, tick.screen_x, length, style, 1.0);
+                    }
+                }
+                let cluster_px = bx.tick_cluster_px.unwrap_or(label_spacing.min);
+                let ticks = cluster_ticks_for_labels(raw_ticks, cluster_px);
 
                 let mut thickness: f32 = 0.0;
 
                 for tick in ticks {
+                    if let Some(style) = self.hints.tick_marks {
+                        let edge = if tick.is_segment_edge { 1.5 } else { 1.0 };
+                        let length = edge * style.length;
+                        self.paint_tick_mark(painter, axis, tick.screen_x, length, style, 1.0);
+                    }
+
                     let gm = GridMark {
                         value: tick.world_x,
                         step_size: tick.step_size,
@@ -324,11 +459,11 @@ impl<'a> AxisWidget<'a> {
                     }
 
                     let galley = painter.layout_no_wrap(txt, font_id.clone(), text_color);
-                    let galley_size = galley.size();
+                    let (offset, galley_size) = rotated_bounds(galley.size(), angle);
 
                     let y = match VPlacement::from(self.hints.placement) {
-                        VPlacement::Bottom => self.rect.min.y,
-                        VPlacement::Top => self.rect.max.y - galley_size.y,
+                        VPlacement::Bottom => self.rect.min.y + mark_len,
+                        VPlacement::Top => self.rect.max.y - galley_size.y - mark_len,
                     };
 
                     let x = tick.screen_x - galley_size.x * 0.5;
@@ -337,21 +472,45 @@ impl<'a> AxisWidget<'a> {
                         continue;
                     }
 
-                    painter.add(TextShape::new(Pos2::new(x, y), galley, text_color));
-                    thickness = thickness.max(galley_size.y);
+                    painter.add(
+                        TextShape::new(Pos2::new(x, y) - offset, galley, text_color)
+                            .with_angle(angle),
+                    );
+                    thickness = thickness.max(galley_size.y + mark_len);
                 }
 
+                self.paint_break_marks(painter, transform, bx.break_style, text_color);
+
                 return thickness;
             }
         }
 
         let mut thickness: f32 = 0.0;
         for step in self.steps.iter() {
+            let main = match axis {
+                Axis::X => {
+                    transform
+                        .position_from_point(&super::PlotPoint::new(step.value, 0.0))
+                        .x
+                }
+                Axis::Y => {
+                    transform
+                        .position_from_point(&super::PlotPoint::new(0.0, step.value))
+                        .y
+                }
+            };
             let text = (self.hints.formatter)(*step, &self.range);
+            let spacing_in_points = transform.mark_spacing_px(axis, step);
+            if text.is_empty() || spacing_in_points <= label_spacing.min {
+                if let Some(style) = self.hints.tick_marks.filter(|s| s.minor) {
+                    // Keep very dense minor marks from turning into a solid bar.
+                    if spacing_in_points >= 2.0 {
+                        let length = 0.5 * style.length;
+                        self.paint_tick_mark(painter, axis, main, length, style, 0.5);
+                    }
+                }
+            }
             if !text.is_empty() {
-                let spacing_in_points =
-                    (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
-
                 if spacing_in_points <= label_spacing.min {
                     // Labels are too close together - don't paint them.
 
@@ -360,45 +519,48 @@ impl<'a> AxisWidget<'a> {
                 // Fade in labels as they get further apart:
 
                 let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
+                if let Some(style) = self.hints.tick_marks {
+                    self.paint_tick_mark(painter, axis, main, style.length, style, strength);
+                }
 
-                let text_color = super::color_from_strength(ui, strength);
+                let text_color = base_color.gamma_multiply(strength.sqrt());
                 let galley = painter.layout_no_wrap(text, font_id.clone(), text_color);
+                let (offset, bbox) = rotated_bounds(galley.size(), angle);
                 let galley_size = match axis {
-                    Axis::X => galley.size(),
-                    Axis::Y => galley.size() + 2.0 * SIDE_MARGIN * Vec2::X,
+                    Axis::X => bbox,
+                    Axis::Y => bbox + 2.0 * SIDE_MARGIN * Vec2::X,
                 };
 
-                if spacing_in_points < galley_size[axis as usize] {
+                if spacing_in_points < label_footprint(galley.size(), angle, axis) {
                     continue; // the galley won't fit (likely too wide on the X axis).
                 }
 
                 match axis {
                     Axis::X => {
-                        thickness = thickness.max(galley_size.y);
-                        let projected_point = super::PlotPoint::new(step.value, 0.0);
-                        let center_x = transform.position_from_point(&projected_point).x;
+                        thickness = thickness.max(galley_size.y + mark_len);
                         let y = match VPlacement::from(self.hints.placement) {
-                            VPlacement::Bottom => self.rect.min.y,
-                            VPlacement::Top => self.rect.max.y - galley_size.y,
+                            VPlacement::Bottom => self.rect.min.y + mark_len,
+                            VPlacement::Top => self.rect.max.y - galley_size.y - mark_len,
                         };
-                        let pos = Pos2::new(center_x - galley_size.x / 2.0, y);
-                        painter.add(TextShape::new(pos, galley, text_color));
+                        let pos = Pos2::new(main - galley_size.x / 2.0, y) - offset;
+                        painter.add(TextShape::new(pos, galley, text_color).with_angle(angle));
                     }
                     Axis::Y => {
-                        thickness = thickness.max(galley_size.x);
-                        let projected_point = super::PlotPoint::new(0.0, step.value);
-                        let center_y = transform.position_from_point(&projected_point).y;
+                        thickness = thickness.max(galley_size.x + mark_len);
+                        let center_y = main;
 
                         match HPlacement::from(self.hints.placement) {
                             HPlacement::Left => {
-                                let x = self.rect.max.x - galley_size.x + SIDE_MARGIN;
-                                let pos = Pos2::new(x, center_y - galley_size.y / 2.0);
-                                painter.add(TextShape::new(pos, galley, text_color));
+                                let x = self.rect.max.x - galley_size.x + SIDE_MARGIN - mark_len;
+                                let pos = Pos2::new(x, center_y - galley_size.y / 2.0) - offset;
+                                painter
+                                    .add(TextShape::new(pos, galley, text_color).with_angle(angle));
                             }
                             HPlacement::Right => {
-                                let x = self.rect.min.x + SIDE_MARGIN;
-                                let pos = Pos2::new(x, center_y - galley_size.y / 2.0);
-                                painter.add(TextShape::new(pos, galley, text_color));
+                                let x = self.rect.min.x + SIDE_MARGIN + mark_len;
+                                let pos = Pos2::new(x, center_y - galley_size.y / 2.0) - offset;
+                                painter
+                                    .add(TextShape::new(pos, galley, text_color).with_angle(angle));
                             }
                         };
                     }
@@ -408,6 +570,153 @@ impl<'a> AxisWidget<'a> {
 
         thickness
     }
+
+    /// Paint one tick mark at `main` (screen coordinate along `axis`), from the plot-side edge
+    /// of the strip outwards.
+    fn paint_tick_mark(
+        &self,
+        painter: &Painter,
+        axis: Axis,
+        main: f32,
+        length: f32,
+        style: TickMarkStyle,
+        strength: f32,
+    ) {
+        let rect = self.rect;
+        let [a, b] = match axis {
+            Axis::X => {
+                if main < rect.min.x || main > rect.max.x {
+                    return;
+                }
+                match VPlacement::from(self.hints.placement) {
+                    VPlacement::Bottom => [
+                        Pos2::new(main, rect.min.y),
+                        Pos2::new(main, rect.min.y + length),
+                    ],
+                    VPlacement::Top => [
+                        Pos2::new(main, rect.max.y),
+                        Pos2::new(main, rect.max.y - length),
+                    ],
+                }
+            }
+            Axis::Y => {
+                if main < rect.min.y || main > rect.max.y {
+                    return;
+                }
+                match HPlacement::from(self.hints.placement) {
+                    HPlacement::Left => [
+                        Pos2::new(rect.max.x, main),
+                        Pos2::new(rect.max.x - length, main),
+                    ],
+                    HPlacement::Right => [
+                        Pos2::new(rect.min.x, main),
+                        Pos2::new(rect.min.x + length, main),
+                    ],
+                }
+            }
+        };
+        let mut stroke = style.stroke;
+        if stroke.color == Color32::TRANSPARENT {
+            stroke.color = painter.ctx().style().visuals.text_color();
+        }
+        stroke.color = stroke.color.gamma_multiply(strength.sqrt());
+        painter.line_segment([a, b], stroke);
+    }
+
+    /// Draw `style` centered in each gap of the segmented X axis, at the edge facing the plot.
+    ///
+    /// The glyph scales with the axis thickness and never gets wider than the gap.
+    fn paint_break_marks(
+        &self,
+        painter: &Painter,
+        transform: &PlotTransform,
+        style: BreakStyle,
+        color: Color32,
+    ) {
+        if style == BreakStyle::None {
+            return;
+        }
+        let Some(gaps) = transform.segment_x_gap_screen_ranges() else {
+            return;
+        };
+        let rect = self.rect;
+        let height = (0.4 * rect.height()).clamp(6.0, 16.0);
+        let (top, bottom) = match VPlacement::from(self.hints.placement) {
+            VPlacement::Bottom => (rect.min.y, rect.min.y + height),
+            VPlacement::Top => (rect.max.y - height, rect.max.y),
+        };
+        let stroke = Stroke::new(1.0, color);
+
+        for (left, right) in gaps {
+            let center = 0.5 * (left + right);
+            if center < rect.min.x || center > rect.max.x {
+                continue;
+            }
+            let half = (0.5 * (right - left)).min(0.4 * height).max(2.0);
+            match style {
+                BreakStyle::None => {}
+                BreakStyle::Slash => {
+                    let lean = 0.5 * half;
+                    for dx in [-0.5 * half, 0.5 * half] {
+                        painter.line_segment(
+                            [
+                                Pos2::new(center + dx - lean, bottom),
+                                Pos2::new(center + dx + lean, top),
+                            ],
+                            stroke,
+                        );
+                    }
+                }
+                BreakStyle::Zigzag => {
+                    let mid = 0.5 * (top + bottom);
+                    let amp = 0.5 * (bottom - top);
+                    let points = vec![
+                        Pos2::new(center - half, mid),
+                        Pos2::new(center - 0.5 * half, mid - amp),
+                        Pos2::new(center + 0.5 * half, mid + amp),
+                        Pos2::new(center + half, mid),
+                    ];
+                    painter.add(egui::Shape::line(points, stroke));
+                }
+            }
+        }
+    }
+}
+
+/// Bounding box of a galley of `size` rotated by `angle` about its top-left corner.
+///
+/// Returns the offset from the rotation origin to the box min, and the box size.
+fn rotated_bounds(size: Vec2, angle: f32) -> (Vec2, Vec2) {
+    if angle == 0.0 {
+        return (Vec2::ZERO, size);
+    }
+    let rot = Rot2::from_angle(angle);
+    let corners = [
+        Vec2::ZERO,
+        rot * Vec2::new(size.x, 0.0),
+        rot * Vec2::new(0.0, size.y),
+        rot * size,
+    ];
+    let min = corners.iter().fold(Vec2::INFINITY, |acc, c| acc.min(*c));
+    let max = corners.iter().fold(-Vec2::INFINITY, |acc, c| acc.max(*c));
+    (min, max - min)
+}
+
+/// Spacing along `axis` that a rotated label needs so it doesn't overlap its neighbors.
+///
+/// Parallel rotated labels only need their line height measured across the slant,
+/// which is less than their bounding box.
+fn label_footprint(size: Vec2, angle: f32, axis: Axis) -> f32 {
+    let (_, bbox) = rotated_bounds(size, angle);
+    let across = match axis {
+        Axis::X => angle.sin().abs(),
+        Axis::Y => angle.cos().abs(),
+    };
+    if across > 1e-3 {
+        bbox[axis as usize].min(size.y / across)
+    } else {
+        bbox[axis as usize]
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -439,7 +748,7 @@ fn build_segmented_ticks_from_steps(
         let mut inside = false;
         let mut is_edge = false;
 
-        for seg in &bx.segments {
+        for (index, seg) in bx.intervals().enumerate() {
             if !(seg.start.is_finite() && seg.end.is_finite()) {
                 continue;
             }
@@ -448,7 +757,9 @@ fn build_segmented_ticks_from_steps(
                 continue;
             }
 
-            inside = true;
+            // Log-scaled segments get decade ticks below instead of the linear grid steps,
+            // and equally sized segments get their own step.
+            inside = bx.decade_ticks(index).is_none() && bx.sizing == SegmentSizing::ByDataSpan;
 
             if (x - seg.start).abs() <= EDGE_EPS || (x - seg.end).abs() <= EDGE_EPS {
                 is_edge = true;
@@ -476,7 +787,60 @@ fn build_segmented_ticks_from_steps(
 
     let default_step = steps.first().map(|s| s.step_size).unwrap_or(1.0);
 
-    for seg in &bx.segments {
+    for (index, seg) in bx.intervals().enumerate() {
+        for value in bx.decade_ticks(index).unwrap_or_default() {
+            let screen_x = tf.position_from_point_x(value);
+            if !screen_x.is_finite() {
+                continue;
+            }
+            out.push(ScreenTick {
+                world_x: value,
+                screen_x,
+                step_size: value,
+                is_segment_edge: (value - seg.start).abs() <= EDGE_EPS
+                    || (value - seg.end).abs() <= EDGE_EPS,
+            });
+        }
+    }
+
+    if bx.sizing == SegmentSizing::Equal {
+        // Each segment is stretched by its own factor, so rescale the finest grid step to
+        // keep the same tick spacing on screen.
+        let finest = steps
+            .iter()
+            .map(|s| s.step_size)
+            .fold(f64::INFINITY, f64::min);
+        for (index, seg) in bx.intervals().enumerate() {
+            let len = seg.len();
+            if bx.decade_ticks(index).is_some() || !len.is_finite() || len <= 0.0 {
+                continue;
+            }
+            let step_size = bx
+                .tick_basis
+                .nice_step_at_least(finest * len / bx.segment_width(index));
+            if !step_size.is_finite() || step_size <= 0.0 {
+                continue;
+            }
+            let mut k = (seg.start / step_size).ceil();
+            while k * step_size <= seg.end + EDGE_EPS {
+                let value = k * step_size;
+                k += 1.0;
+                let screen_x = tf.position_from_point_x(value);
+                if !screen_x.is_finite() {
+                    continue;
+                }
+                out.push(ScreenTick {
+                    world_x: value,
+                    screen_x,
+                    step_size,
+                    is_segment_edge: (value - seg.start).abs() <= EDGE_EPS
+                        || (value - seg.end).abs() <= EDGE_EPS,
+                });
+            }
+        }
+    }
+
+    for seg in bx.intervals() {
         for &edge_x in &[seg.start, seg.end] {
             if !edge_x.is_finite() {
                 continue;
@@ -0,0 +1,285 @@
+use crate::GridMark;
+
+/// How values map to screen positions along a whole plot axis.
+///
+/// Bounds, item data, tooltips and the cursor readout all stay in the original units;
+/// only the positioning (and the generated grid marks) follow the scale.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AxisScale {
+    /// Screen position proportional to the value.
+    #[default]
+    Linear,
+
+    /// Symmetric log: linear within `[-threshold, threshold]` and logarithmic beyond,
+    /// for signed data over a wide dynamic range.
+    ///
+    /// Falls back to [`Self::Linear`] unless `threshold` is finite and positive.
+    Symlog { threshold: f64 },
+
+    /// Base-10 logarithmic, for positive data over many decades.
+    ///
+    /// Non-positive values are clamped to [`f64::MIN_POSITIVE`], far off the bottom of any
+    /// sensible view, instead of producing NaN positions.
+    Log10,
+}
+
+impl AxisScale {
+    /// The scale actually applied, after the fallbacks described on the variants.
+    fn effective(self) -> Self {
+        match self {
+            Self::Symlog { threshold } if threshold.is_finite() && threshold > 0.0 => self,
+            Self::Log10 => self,
+            _ => Self::Linear,
+        }
+    }
+
+    /// `true` if values map linearly to the screen.
+    pub fn is_linear(self) -> bool {
+        self.effective() == Self::Linear
+    }
+
+    /// Map a value into the space the screen is linear in.
+    pub fn forward(self, value: f64) -> f64 {
+        match self.effective() {
+            Self::Linear => value,
+            Self::Symlog { threshold } => {
+                let a = value.abs() / threshold;
+                if a <= 1.0 {
+                    value / threshold
+                } else {
+                    value.signum() * (1.0 + a.log10())
+                }
+            }
+            Self::Log10 => {
+                if value.is_nan() {
+                    value
+                } else {
+                    value.max(f64::MIN_POSITIVE).log10()
+                }
+            }
+        }
+    }
+
+    /// Inverse of [`Self::forward`].
+    pub fn inverse(self, scaled: f64) -> f64 {
+        match self.effective() {
+            Self::Linear => scaled,
+            Self::Symlog { threshold } => {
+                if scaled.abs() <= 1.0 {
+                    scaled * threshold
+                } else {
+                    scaled.signum() * threshold * 10f64.powf(scaled.abs() - 1.0)
+                }
+            }
+            Self::Log10 => 10f64.powf(scaled),
+        }
+    }
+
+    /// Widen `(min, max)` by `fraction` of its extent on each side, measured in the scale's
+    /// linear space so a log axis never gains a non-positive bound.
+    pub(crate) fn expand(self, (min, max): (f64, f64), fraction: f64) -> (f64, f64) {
+        let (lo, hi) = (self.forward(min), self.forward(max));
+        let margin = fraction * (hi - lo).max(0.0);
+        (self.inverse(lo - margin), self.inverse(hi + margin))
+    }
+
+    /// Grid marks for a non-linear scale over the visible `(min, max)` range.
+    ///
+    /// Symlog: `0` and `±threshold` in the linear part, then `±threshold·10ᵏ` decades with
+    /// `2..=9` minor marks in between. Log10: `1–2–5` marks per decade (every integer mantissa
+    /// when at most a decade is visible). Empty for [`Self::Linear`], which uses the grid spacer.
+    ///
+    /// Beyond `max_ticks` marks, the minor marks are dropped first, then every other decade
+    /// (or more) is skipped.
+    pub(crate) fn grid_marks(
+        self,
+        (min, max): (f64, f64),
+        max_ticks: Option<usize>,
+    ) -> Vec<GridMark> {
+        if !min.is_finite() || !max.is_finite() {
+            return Vec::new();
+        }
+        let max_ticks = max_ticks.unwrap_or(usize::MAX);
+        let threshold = match self.effective() {
+            Self::Linear => return Vec::new(),
+            Self::Log10 => return log10_grid_marks((min, max), max_ticks),
+            Self::Symlog { threshold } => threshold,
+        };
+        // Marks with or without the minor ones, keeping one decade out of `every`.
+        let symlog_marks = |minor: bool, every: usize| {
+            let mut marks = Vec::new();
+            let mut push = |value: f64, step_size: f64| {
+                if min <= value && value <= max {
+                    marks.push(GridMark { value, step_size });
+                }
+            };
+
+            for value in [-threshold, 0.0, threshold] {
+                push(value, threshold);
+            }
+            let max_abs = min.abs().max(max.abs());
+            let mut decade = threshold;
+            let mut k = 1;
+            while decade < max_abs && decade.is_finite() {
+                for sign in [-1.0, 1.0] {
+                    if minor {
+                        for m in 2..=9 {
+                            push(sign * f64::from(m) * decade, decade);
+                        }
+                    }
+                    if k % every == 0 {
+                        push(sign * 10.0 * decade, 9.0 * decade);
+                    }
+                }
+                decade *= 10.0;
+                k += 1;
+            }
+            marks
+        };
+
+        let mut marks = symlog_marks(true, 1);
+        if marks.len() > max_ticks {
+            marks = symlog_marks(false, 1);
+            let mut every = marks.len().div_ceil(max_ticks.max(1)).max(2);
+            while marks.len() > max_ticks && every <= marks.len() {
+                marks = symlog_marks(false, every);
+                every += 1;
+            }
+        }
+        marks
+    }
+}
+
+/// Most decades [`AxisScale::Log10`] marks one by one; wider ranges skip decades.
+const MAX_LOG_DECADES: i32 = 24;
+
+fn log10_grid_marks((min, max): (f64, f64), max_ticks: usize) -> Vec<GridMark> {
+    let min = min.max(f64::MIN_POSITIVE);
+    if min >= max {
+        return Vec::new();
+    }
+    let first = min.log10().floor() as i32;
+    let last = max.log10().ceil() as i32;
+    let decades = last - first;
+    let log10_marks = |mantissas: &[f64], every: i32| {
+        let mut marks = Vec::new();
+        for k in (first..=last).filter(|k| k.rem_euclid(every) == 0) {
+            let decade = 10f64.powi(k);
+            for &m in mantissas {
+                let value = m * decade;
+                if min <= value && value <= max {
+                    marks.push(GridMark {
+                        value,
+                        step_size: decade,
+                    });
+                }
+            }
+        }
+        marks
+    };
+
+    let mantissas: &[f64] = match decades {
+        ..=1 => &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        2..=MAX_LOG_DECADES => &[1.0, 2.0, 5.0],
+        _ => &[1.0],
+    };
+    let every = ((decades + MAX_LOG_DECADES - 1) / MAX_LOG_DECADES).max(1);
+    let mut marks = log10_marks(mantissas, every);
+    if marks.len() > max_ticks {
+        marks = log10_marks(&[1.0], every);
+        let mut every = every.max(2);
+        while marks.len() > max_ticks && every <= decades {
+            marks = log10_marks(&[1.0], every);
+            every += 1;
+        }
+    }
+    marks
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Pos2, Rect, Vec2};
+
+    use super::*;
+    use crate::{Axis, PlotBounds, PlotTransform};
+
+    #[test]
+    fn test_symlog_round_trip() {
+        let scale = AxisScale::Symlog { threshold: 2.0 };
+        for v in [-5e6, -20.0, -2.0, -0.5, 0.0, 1.0, 2.0, 3.0, 1e9] {
+            let back = scale.inverse(scale.forward(v));
+            assert!(
+                (back - v).abs() <= 1e-12 * v.abs().max(1.0),
+                "{v} -> {back}"
+            );
+        }
+        assert_eq!(scale.forward(1.0), 0.5);
+        assert_eq!(scale.forward(-200.0), -3.0);
+
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 100.0)),
+            PlotBounds::from_min_max([-1000.0, 0.0], [1000.0, 1.0]),
+            false,
+        );
+        transform.set_scale(Axis::X, AxisScale::Symlog { threshold: 1.0 });
+        assert_eq!(transform.position_from_point_x(0.0), 50.0);
+        assert_eq!(transform.position_from_point_x(10.0), 75.0);
+        let x = transform.value_from_position(Pos2::new(75.0, 0.0)).x;
+        assert!((x - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_symlog_grid_marks_cap() {
+        let scale = AxisScale::Symlog { threshold: 1.0 };
+        let values = |max_ticks| -> Vec<f64> {
+            scale
+                .grid_marks((-1e6, 1e6), max_ticks)
+                .iter()
+                .map(|m| m.value)
+                .collect()
+        };
+        assert_eq!(values(None).len(), 111);
+        // Minor marks go first, then decades.
+        assert_eq!(values(Some(20)).len(), 15);
+        assert_eq!(values(Some(8)), vec![-1.0, 0.0, 1.0, -1e3, 1e3, -1e6, 1e6]);
+        assert!(AxisScale::Log10.grid_marks((1.0, 1e20), Some(6)).len() <= 6);
+    }
+
+    #[test]
+    fn test_log10_scale() {
+        let scale = AxisScale::Log10;
+        assert_eq!(scale.forward(1000.0), 3.0);
+        assert!((scale.inverse(-2.0) - 0.01).abs() < 1e-15);
+        // Non-positive values are clamped, not NaN.
+        assert!(scale.forward(0.0).is_finite());
+        assert!(scale.forward(-5.0).is_finite());
+
+        let (lo, hi) = scale.expand((1.0, 100.0), 0.5);
+        assert!((lo - 0.1).abs() < 1e-12 && (hi - 1000.0).abs() < 1e-9);
+
+        let values = |range| -> Vec<f64> {
+            scale
+                .grid_marks(range, None)
+                .iter()
+                .map(|m| m.value)
+                .collect()
+        };
+        assert_eq!(
+            values((1.0, 100.0)),
+            vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+        );
+        assert_eq!(values((2.5, 9.0)).len(), 7); // 3..=9 inside a single decade
+        assert!(values((-1.0, 10.0)).iter().all(|&v| v > 0.0));
+
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 1.0], [1.0, 1e4]),
+            false,
+        );
+        transform.set_scale(Axis::Y, AxisScale::Log10);
+        assert_eq!(transform.position_from_point_y(100.0), 50.0);
+        let y = transform.value_from_position(Pos2::new(0.0, 25.0)).y;
+        assert!((y - 1000.0).abs() < 1e-9);
+    }
+}
@@ -76,4 +76,52 @@ impl Interval {
     pub fn contains(&self, x: f64) -> bool {
         x >= self.start && x <= self.end
     }
+
+    /// Clamp `x` into [start, end]. Infinite endpoints leave that side unbounded.
+    ///
+    /// NaN is returned unchanged.
+    #[inline]
+    pub fn clamp(&self, x: f64) -> f64 {
+        if x < self.start {
+            self.start
+        } else if x > self.end {
+            self.end
+        } else {
+            x
+        }
+    }
+
+    /// A finite point representative of the interval, e.g. to anchor a label.
+    ///
+    /// The center for a bounded interval, the finite endpoint for a half-infinite one,
+    /// and `0.0` for [`Self::all`].
+    #[inline]
+    pub fn midpoint(&self) -> f64 {
+        match (self.start.is_finite(), self.end.is_finite()) {
+            (true, true) => self.start + 0.5 * (self.end - self.start),
+            (true, false) => self.start,
+            (false, true) => self.end,
+            (false, false) => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_clamp_midpoint() {
+        let i = Interval::new(1.0, 3.0);
+        assert_eq!(i.clamp(0.0), 1.0);
+        assert_eq!(i.clamp(2.5), 2.5);
+        assert_eq!(i.clamp(f64::INFINITY), 3.0);
+        assert_eq!(i.midpoint(), 2.0);
+
+        assert_eq!(Interval::above(1.0).clamp(1e300), 1e300);
+        assert_eq!(Interval::above(1.0).midpoint(), 1.0);
+        assert_eq!(Interval::below(-2.0).midpoint(), -2.0);
+        assert_eq!(Interval::all().clamp(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(Interval::all().midpoint(), 0.0);
+    }
 }
@@ -1,8 +1,8 @@
 use egui::{Response, Shape, Vec2b};
 
 use crate::{
-    ActionExecutor, ActionQueue, PlotBounds, PlotEvent,
-    action::{AppliedActions, BoundsChangeCause, BoundsLike, PlotAction},
+    ActionExecutor, ActionQueue, PlotEvent,
+    action::{AppliedActions, BoundsLike, PlotAction},
 };
 
 impl ActionExecutor {
@@ -48,6 +48,7 @@ impl ActionExecutor {
                     auto_bounds = Vec2b::from([false, false]);
                 }
                 PlotAction::AddOverlayShape(shape) => overlays.push(shape),
+                PlotAction::EmitEvent(_) => {} // reported through `as_event`
             }
         }
 
@@ -65,25 +66,12 @@ impl<I> PlotAction<I> {
     /// Turn action to events.
     pub fn as_event(&self) -> Option<PlotEvent> {
         match self {
-            Self::SetBoundsX(range) => Some(PlotEvent::BoundsChanged {
-                old: PlotBounds::NOTHING,
-                new: PlotBounds {
-                    min: [*range.start(), f64::NEG_INFINITY],
-                    max: [*range.end(), f64::INFINITY],
-                },
-                cause: BoundsChangeCause::Programmatic,
-            }),
+            Self::EmitEvent(event) => Some(event.clone()),
 
-            Self::SetBoundsY(range) => Some(PlotEvent::BoundsChanged {
-                old: PlotBounds::NOTHING,
-                new: PlotBounds {
-                    min: [f64::NEG_INFINITY, *range.start()],
-                    max: [f64::INFINITY, *range.end()],
-                },
-                cause: BoundsChangeCause::Programmatic,
-            }),
-
-            Self::Translate(_)
+            // Bounds changes are reported once by the plot, with the final old and new bounds.
+            Self::SetBoundsX(_)
+            | Self::SetBoundsY(_)
+            | Self::Translate(_)
             | Self::Zoom(_, _)
             | Self::SetAutoBounds(_)
             | Self::AddOverlayShape(_)
@@ -0,0 +1,111 @@
+use egui::Color32;
+
+/// Matplotlib's viridis, 16 evenly spaced stops.
+const VIRIDIS: [Color32; 16] = [
+    Color32::from_rgb(0x44, 0x01, 0x54),
+    Color32::from_rgb(0x48, 0x1A, 0x6C),
+    Color32::from_rgb(0x47, 0x2F, 0x7D),
+    Color32::from_rgb(0x41, 0x44, 0x87),
+    Color32::from_rgb(0x39, 0x56, 0x8C),
+    Color32::from_rgb(0x31, 0x68, 0x8E),
+    Color32::from_rgb(0x2A, 0x78, 0x8E),
+    Color32::from_rgb(0x23, 0x88, 0x8E),
+    Color32::from_rgb(0x1F, 0x98, 0x8B),
+    Color32::from_rgb(0x22, 0xA8, 0x84),
+    Color32::from_rgb(0x35, 0xB7, 0x79),
+    Color32::from_rgb(0x54, 0xC5, 0x68),
+    Color32::from_rgb(0x7A, 0xD1, 0x51),
+    Color32::from_rgb(0xA5, 0xDB, 0x36),
+    Color32::from_rgb(0xD2, 0xE2, 0x1B),
+    Color32::from_rgb(0xFD, 0xE7, 0x25),
+];
+
+/// Google's turbo, 16 evenly spaced stops.
+const TURBO: [Color32; 16] = [
+    Color32::from_rgb(48, 18, 59),
+    Color32::from_rgb(74, 65, 181),
+    Color32::from_rgb(66, 112, 242),
+    Color32::from_rgb(47, 158, 245),
+    Color32::from_rgb(37, 198, 215),
+    Color32::from_rgb(47, 229, 173),
+    Color32::from_rgb(78, 249, 131),
+    Color32::from_rgb(124, 253, 94),
+    Color32::from_rgb(176, 244, 68),
+    Color32::from_rgb(223, 220, 50),
+    Color32::from_rgb(255, 184, 39),
+    Color32::from_rgb(255, 140, 31),
+    Color32::from_rgb(244, 92, 23),
+    Color32::from_rgb(206, 49, 13),
+    Color32::from_rgb(163, 19, 2),
+    Color32::from_rgb(122, 4, 3),
+];
+
+const GRAYSCALE: [Color32; 2] = [Color32::BLACK, Color32::WHITE];
+
+/// Maps a scalar in `[0, 1]` to a color, e.g. for [`crate::Scatter::color_values`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColorMap {
+    /// Perceptually uniform dark purple → yellow.
+    Viridis,
+
+    /// Rainbow-like dark blue → dark red with smooth lightness.
+    Turbo,
+
+    /// Black → white.
+    Grayscale,
+
+    /// Evenly spaced stops, interpolated linearly. An empty list samples as transparent.
+    Custom(Vec<Color32>),
+}
+
+impl ColorMap {
+    fn stops(&self) -> &[Color32] {
+        match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Turbo => &TURBO,
+            Self::Grayscale => &GRAYSCALE,
+            Self::Custom(stops) => stops,
+        }
+    }
+
+    /// The color at `t`, clamped to `[0, 1]`. NaN samples the start of the map.
+    pub fn sample(&self, t: f32) -> Color32 {
+        let stops = self.stops();
+        let Some(&last) = stops.last() else {
+            return Color32::TRANSPARENT;
+        };
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let pos = t * (stops.len() - 1) as f32;
+        let i = pos.floor() as usize;
+        if i + 1 >= stops.len() {
+            return last;
+        }
+        stops[i].lerp_to_gamma(stops[i + 1], pos - i as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_map_sample() {
+        assert_eq!(
+            ColorMap::Viridis.sample(0.0),
+            Color32::from_rgb(0x44, 0x01, 0x54)
+        );
+        assert_eq!(ColorMap::Viridis.sample(2.0), ColorMap::Viridis.sample(1.0));
+        assert_eq!(
+            ColorMap::Turbo.sample(f32::NAN),
+            ColorMap::Turbo.sample(0.0)
+        );
+        assert_eq!(ColorMap::Grayscale.sample(1.0), Color32::WHITE);
+
+        let custom = ColorMap::Custom(vec![Color32::BLACK, Color32::from_rgb(200, 100, 0)]);
+        assert_eq!(custom.sample(0.25), Color32::from_rgb(50, 25, 0));
+        assert_eq!(
+            ColorMap::Custom(Vec::new()).sample(0.5),
+            Color32::TRANSPARENT
+        );
+    }
+}
@@ -2,6 +2,13 @@
 //!
 //! visualize variance around a time series.
 //!
+//! To leave a gap in the band, put a NaN in `xs`, `y_min` or `y_max` at that sample:
+//! the fill is broken on both sides of it, like a gapped [`crate::Line`].
+//!
+//! [`Band::new_horizontal`] transposes the band: it fills between `x_left(y)` and
+//! `x_right(y)` over shared `ys`, e.g. for a confidence envelope on a plot with time
+//! running vertically.
+//!
 //! # Example :
 // ```no_run
 // use egui_plot::Band;
@@ -21,9 +28,11 @@
 
 use std::ops::RangeInclusive;
 
-use egui::{Color32, Mesh, Shape, Ui};
+use egui::{Color32, Mesh, Shape, Stroke, Ui};
 
-use super::{PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
+use super::{
+    ColumnarSeries, Orientation, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, geom_helpers,
+};
 use crate::{PlotBounds, PlotTransform};
 
 /// A shaded area between two curves  ``y_min(x) `` and  ``y_max(x) ``.
@@ -35,12 +44,18 @@ pub struct Band {
     /// Base color for the fill
     color: Color32,
 
-    /// Sampled x-coordinates.
+    /// Sampled x-coordinates (y-coordinates when horizontal).
     xs: Vec<f64>,
-    /// Lower envelope `` y_min(x) ``.
+    /// Lower envelope `` y_min(x) `` (`x_left(y)` when horizontal).
     y_min: Vec<f64>,
-    /// Upper envelope  ``y_max(x) ``.
+    /// Upper envelope  ``y_max(x) `` (`x_right(y)` when horizontal).
     y_max: Vec<f64>,
+
+    /// Optional outline for both envelopes.
+    pub(crate) envelope_stroke: Option<Stroke>,
+
+    /// `Vertical` fills between two y-series over x, `Horizontal` between two x-series over y.
+    orientation: Orientation,
 }
 impl Default for Band {
     fn default() -> Self {
@@ -51,6 +66,8 @@ impl Default for Band {
             xs: Vec::new(),
             y_min: Vec::new(),
             y_max: Vec::new(),
+            envelope_stroke: None,
+            orientation: Orientation::default(),
         }
     }
 }
@@ -64,6 +81,12 @@ impl Band {
         Self::default()
     }
 
+    /// Create an empty horizontal band: [`Self::with_series`] then takes
+    /// `(ys, x_left, x_right)` and the fill spans X over shared Y samples.
+    pub fn new_horizontal() -> Self {
+        Self::new().horizontal()
+    }
+
     /// Create a named band
     pub fn with_name(name: impl Into<String>) -> Self {
         let mut s = Self::new();
@@ -71,6 +94,42 @@ impl Band {
         s
     }
 
+    /// A named band between the `ys` of `lower` and `upper`, which must share the same `xs`.
+    ///
+    /// The samples are copied once into the band, as with [`Self::with_series`].
+    ///
+    /// # Panics
+    /// Panics if the `xs` of the two series differ (length or any value, compared bitwise so
+    /// NaN gaps match). For a lenient version, see [`Self::from_series_truncating`].
+    pub fn from_series(
+        name: impl Into<String>,
+        lower: ColumnarSeries<'_>,
+        upper: ColumnarSeries<'_>,
+    ) -> Self {
+        let (a, b) = (lower.xs(), upper.xs());
+        assert!(
+            a.len() == b.len() && a.iter().zip(b).all(|(p, q)| p.to_bits() == q.to_bits()),
+            "Band::from_series: lower and upper must share the same xs (got {} vs {} samples)",
+            a.len(),
+            b.len()
+        );
+        Self::with_name(name).with_series(a, lower.ys(), upper.ys())
+    }
+
+    /// Like [`Self::from_series`], but **never panics**: both series are truncated to the
+    /// shorter one and paired by index, taking the `xs` of `lower`.
+    ///
+    /// No resampling is done, so this is only meaningful when the `xs` agree on the common
+    /// prefix.
+    pub fn from_series_truncating(
+        name: impl Into<String>,
+        lower: ColumnarSeries<'_>,
+        upper: ColumnarSeries<'_>,
+    ) -> Self {
+        let n = lower.len().min(upper.len());
+        Self::with_name(name).with_series(&lower.xs()[..n], &lower.ys()[..n], &upper.ys()[..n])
+    }
+
     /// Override the item's stable id.
     #[inline]
     pub fn with_id(mut self, id: impl Into<egui::Id>) -> Self {
@@ -78,6 +137,21 @@ impl Band {
         self
     }
 
+    /// Fill between `y_min(x)` and `y_max(x)` over shared `xs`. This is the default.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = Orientation::Vertical;
+        self
+    }
+
+    /// Fill between `x_left(y)` and `x_right(y)` over shared `ys`: the series passed to
+    /// [`Self::with_series`] are read as `(ys, x_left, x_right)`.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self
+    }
+
     /// Set the base RGB color of the band.
     #[inline]
     pub fn with_color(mut self, color: Color32) -> Self {
@@ -85,9 +159,50 @@ impl Band {
         self
     }
 
+    /// Also stroke the `y_min` and `y_max` boundary polylines. Default: `None` (fill only).
+    ///
+    /// The outlines break at the same non-finite samples as the fill. A transparent stroke
+    /// color means the band color, at full opacity.
+    #[inline]
+    pub fn envelope_stroke(mut self, stroke: Option<Stroke>) -> Self {
+        self.envelope_stroke = stroke;
+        self
+    }
+
+    /// Draw the min and max edges with `stroke`. Shorthand for
+    /// [`Self::envelope_stroke`]`(Some(stroke))`.
+    ///
+    /// The edges belong to the band itself, so they share its name, legend entry and
+    /// hide/highlight state.
+    #[deprecated = "Use `envelope_stroke` instead"]
+    #[inline]
+    pub fn edge_stroke(self, stroke: impl Into<Stroke>) -> Self {
+        self.envelope_stroke(Some(stroke.into()))
+    }
+
+    /// Toggle the min and max edges. Turning them on keeps an already set
+    /// [`Self::envelope_stroke`], or else uses a 1 px line in the band color.
+    #[deprecated = "Use `envelope_stroke` instead"]
+    #[inline]
+    pub fn edges(mut self, on: bool) -> Self {
+        self.envelope_stroke = if on {
+            Some(
+                self.envelope_stroke
+                    .unwrap_or(Stroke::new(1.0, Color32::TRANSPARENT)),
+            )
+        } else {
+            None
+        };
+        self
+    }
+
     /// Provide series data. All inputs must have identical length.
     ///
-    /// NaN/non-finite samples are skipped segment-wise during tessellation.
+    /// For a [horizontal](Self::horizontal) band the slices are `(ys, x_left, x_right)`.
+    ///
+    /// A non-finite value in any of the three slices breaks the fill at that sample:
+    /// the segments on either side of it are not drawn, so inserting a NaN is enough
+    /// to create a gap. Non-finite samples are also ignored for the bounds.
     pub fn with_series(mut self, xs: &[f64], y_min: &[f64], y_max: &[f64]) -> Self {
         assert_eq!(
             xs.len(),
@@ -149,11 +264,22 @@ impl Band {
             return None;
         }
 
-        Some(PlotBounds::from_min_max([min_x, min_y], [max_x, max_y]))
+        let (min, max) = (self.point(min_x, min_y), self.point(max_x, max_y));
+        Some(PlotBounds::from_min_max([min.x, min.y], [max.x, max.y]))
+    }
+
+    /// The plot point of sample coordinate `t` and envelope value `v`, per orientation.
+    fn point(&self, t: f64, v: f64) -> PlotPoint {
+        match self.orientation {
+            Orientation::Vertical => PlotPoint::new(t, v),
+            Orientation::Horizontal => PlotPoint::new(v, t),
+        }
     }
 
     /// Build a filled triangle mesh for the band in screen space.
-    fn build_mesh(&self, transform: &PlotTransform) -> Mesh {
+    ///
+    /// Each segment with finite endpoints becomes one quad; the others are skipped.
+    pub(crate) fn build_mesh(&self, transform: &PlotTransform) -> Mesh {
         let n = self.xs.len();
         let n_segs = n.saturating_sub(1);
 
@@ -185,10 +311,10 @@ impl Band {
             let (a0, b0) = if yl0 <= yu0 { (yl0, yu0) } else { (yu0, yl0) };
             let (a1, b1) = if yl1 <= yu1 { (yl1, yu1) } else { (yu1, yl1) };
 
-            let p_ll = PlotPoint::new(x0, a0);
-            let p_lr = PlotPoint::new(x1, a1);
-            let p_ur = PlotPoint::new(x1, b1);
-            let p_ul = PlotPoint::new(x0, b0);
+            let p_ll = self.point(x0, a0);
+            let p_lr = self.point(x1, a1);
+            let p_ur = self.point(x1, b1);
+            let p_ul = self.point(x0, b0);
 
             let ll = transform.position_from_point(&p_ll);
             let lr = transform.position_from_point(&p_lr);
@@ -210,6 +336,24 @@ impl Band {
 
         mesh
     }
+
+    /// Push the `y_min` and `y_max` outlines, one polyline per run of finite samples.
+    fn envelope_shapes(&self, stroke: Stroke, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let finite = |i: usize| {
+            self.xs[i].is_finite() && self.y_min[i].is_finite() && self.y_max[i].is_finite()
+        };
+        for (i0, i1) in geom_helpers::iter_runs(self.xs.len(), finite) {
+            if i0 == i1 {
+                continue;
+            }
+            for ys in [&self.y_min, &self.y_max] {
+                let points = (i0..=i1)
+                    .map(|i| transform.position_from_point(&self.point(self.xs[i], ys[i])))
+                    .collect();
+                shapes.push(Shape::line(points, stroke));
+            }
+        }
+    }
 }
 
 impl PlotItem for Band {
@@ -221,6 +365,13 @@ impl PlotItem for Band {
         if !mesh.indices.is_empty() {
             shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
         }
+        if let Some(mut stroke) = self.envelope_stroke {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = self.color.to_opaque();
+            }
+            stroke.width *= 1.0 + self.base.highlight_amount();
+            self.envelope_shapes(stroke, transform, shapes);
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -237,6 +388,11 @@ impl PlotItem for Band {
         self.compute_bounds().unwrap_or(PlotBounds::NOTHING)
     }
 
+    fn envelope(&self) -> Option<[&[f64]; 3]> {
+        // Band stats are sampled along X, which a horizontal band does not run along.
+        (self.orientation == Orientation::Vertical).then_some([&self.xs, &self.y_min, &self.y_max])
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -245,3 +401,81 @@ impl PlotItem for Band {
         &mut self.base
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::{Pos2, Rect, Vec2};
+
+    use super::*;
+    use crate::PlotBounds;
+
+    #[test]
+    fn test_band_nan_gaps() {
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, -1.0], [5.0, 2.0]),
+            false,
+        );
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_max = [1.0; 6];
+
+        // No NaN: one quad (two triangles) per segment.
+        let band = Band::new().with_series(&xs, &[0.0; 6], &y_max);
+        assert_eq!(band.build_mesh(&transform).indices.len(), 5 * 6);
+
+        // A NaN drops both segments touching that sample, whichever slice it is in.
+        let band = Band::new().with_series(&xs, &[0.0, 0.0, f64::NAN, 0.0, 0.0, 0.0], &y_max);
+        assert_eq!(band.build_mesh(&transform).indices.len(), 3 * 6);
+        let mut gapped_xs = xs;
+        gapped_xs[5] = f64::NAN;
+        let band = Band::new().with_series(&gapped_xs, &[0.0; 6], &y_max);
+        assert_eq!(band.build_mesh(&transform).indices.len(), 4 * 6);
+        assert_eq!(band.bounds().max[0], 4.0);
+    }
+
+    #[test]
+    fn test_horizontal_band_bounds() {
+        let ys = [0.0, 1.0, 2.0];
+        let band = Band::new_horizontal().with_series(&ys, &[-1.0, -0.5, 0.0], &[1.0, 2.0, 0.5]);
+        assert_eq!(
+            band.bounds(),
+            PlotBounds::from_min_max([-1.0, 0.0], [2.0, 2.0])
+        );
+        assert!(band.envelope().is_none());
+    }
+
+    #[test]
+    fn test_band_from_series() {
+        let xs = [0.0, 1.0, f64::NAN, 3.0];
+        let lower = ColumnarSeries::new(&xs, &[0.0, 0.0, 0.0, -1.0]);
+        let upper = ColumnarSeries::new(&xs, &[1.0, 2.0, 9.0, 1.0]);
+        let band = Band::from_series("ci", lower, upper);
+        assert_eq!(band.name(), "ci");
+        assert_eq!(
+            band.bounds(),
+            PlotBounds::from_min_max([0.0, -1.0], [3.0, 2.0])
+        );
+
+        let short = ColumnarSeries::new(&xs[..2], &[1.0, 2.0]);
+        let band = Band::from_series_truncating("ci", lower, short);
+        assert_eq!(
+            band.bounds(),
+            PlotBounds::from_min_max([0.0, 0.0], [1.0, 2.0])
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_band_edges() {
+        let band = Band::new().edges(true);
+        assert_eq!(
+            band.envelope_stroke,
+            Some(Stroke::new(1.0, Color32::TRANSPARENT))
+        );
+
+        let red = Stroke::new(2.0, Color32::RED);
+        let band = band.edge_stroke(red).edges(true);
+        assert_eq!(band.envelope_stroke, Some(red));
+        assert_eq!(band.edges(false).envelope_stroke, None);
+    }
+}
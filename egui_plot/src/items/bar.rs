@@ -124,14 +124,10 @@ impl Bar {
     pub(super) fn add_shapes(
         &self,
         transform: &PlotTransform,
-        highlighted: bool,
+        highlight: f32,
         shapes: &mut Vec<Shape>,
     ) {
-        let (stroke, fill) = if highlighted {
-            highlighted_color(self.stroke, self.fill)
-        } else {
-            (self.stroke, self.fill)
-        };
+        let (stroke, fill) = highlighted_color(self.stroke, self.fill, highlight);
 
         let rect = transform.rect_from_values(&self.bounds_min(), &self.bounds_max());
         let rect = Shape::Rect(RectShape::new(
@@ -147,14 +147,10 @@ impl BoxElem {
     pub(super) fn add_shapes(
         &self,
         transform: &PlotTransform,
-        highlighted: bool,
+        highlight: f32,
         shapes: &mut Vec<Shape>,
     ) {
-        let (stroke, fill) = if highlighted {
-            highlighted_color(self.stroke, self.fill)
-        } else {
-            (self.stroke, self.fill)
-        };
+        let (stroke, fill) = highlighted_color(self.stroke, self.fill, highlight);
 
         let rect = transform.rect_from_values(
             &self.point_at(self.argument - self.box_width / 2.0, self.spread.quartile1),
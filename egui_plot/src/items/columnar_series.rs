@@ -1,4 +1,5 @@
 #![allow(rustdoc::missing_crate_level_docs)]
+use super::geom_helpers::iter_runs;
 use crate::transform::PlotBounds;
 use core::fmt;
 use core::ops::{Bound, RangeBounds};
@@ -11,6 +12,8 @@ use core::ops::{Bound, RangeBounds};
 pub struct ColumnarSeries<'a> {
     xs: &'a [f64],
     ys: &'a [f64],
+    /// The caller promised `xs` is sorted ascending, see [`Self::assume_sorted`].
+    sorted: bool,
 }
 
 impl<'a> ColumnarSeries<'a> {
@@ -27,7 +30,11 @@ impl<'a> ColumnarSeries<'a> {
             xs.len(),
             ys.len()
         );
-        Self { xs, ys }
+        Self {
+            xs,
+            ys,
+            sorted: false,
+        }
     }
 
     /// Construct a series by **truncating to the shorter** of `xs` and `ys`.
@@ -39,11 +46,33 @@ impl<'a> ColumnarSeries<'a> {
         Self {
             xs: &xs[..n],
             ys: &ys[..n],
+            sorted: false,
         }
     }
 
     /// An always-valid empty series.
-    pub const EMPTY: ColumnarSeries<'static> = ColumnarSeries { xs: &[], ys: &[] };
+    pub const EMPTY: ColumnarSeries<'static> = ColumnarSeries {
+        xs: &[],
+        ys: &[],
+        sorted: true,
+    };
+
+    /// Promise that `xs` is sorted ascending (e.g. timestamps), so lookups can binary-search.
+    ///
+    /// Items built from this series ([`crate::Line::from_series`], [`crate::Scatter::series`])
+    /// pick the promise up for their tooltip lookups. It is not checked: with unsorted `xs`,
+    /// lookups return garbage.
+    #[inline]
+    pub fn assume_sorted(mut self) -> Self {
+        self.sorted = true;
+        self
+    }
+
+    /// Whether [`Self::assume_sorted`] was called.
+    #[inline]
+    pub fn is_assumed_sorted(&self) -> bool {
+        self.sorted
+    }
 
     /// Borrow the X slice.
     #[inline]
@@ -123,8 +152,175 @@ impl<'a> ColumnarSeries<'a> {
             ColumnarSeries {
                 xs: &self.xs[start..end],
                 ys: &self.ys[start..end],
+                sorted: self.sorted,
+            }
+        }
+    }
+
+    /// Index of the sample whose X is nearest to `x`, by binary search (`O(log n)`).
+    ///
+    /// `xs` **must be sorted ascending**, the result is garbage otherwise. Ties go to the
+    /// lower index. `None` for an empty series or a NaN `x`.
+    pub fn index_at_x(&self, x: f64) -> Option<usize> {
+        if self.is_empty() || x.is_nan() {
+            return None;
+        }
+        let j = self.xs.partition_point(|v| *v < x);
+        if j == 0 {
+            return Some(0);
+        }
+        if j == self.len() {
+            return Some(j - 1);
+        }
+        Some(if x - self.xs[j - 1] <= self.xs[j] - x {
+            j - 1
+        } else {
+            j
+        })
+    }
+
+    /// Y at `x`, linearly interpolated between the two samples bracketing it
+    /// (binary search, `O(log n)`).
+    ///
+    /// `xs` **must be sorted ascending**, the result is garbage otherwise. `None` outside
+    /// `xs`' range; NaN next to a non-finite Y.
+    pub fn value_at_x(&self, x: f64) -> Option<f64> {
+        let n = self.len();
+        if n == 0 || !(self.xs[0] <= x && x <= self.xs[n - 1]) {
+            return None;
+        }
+        let j = self.xs.partition_point(|v| *v < x);
+        if self.xs[j] == x || j == 0 {
+            return Some(self.ys[j]);
+        }
+        let (x0, x1) = (self.xs[j - 1], self.xs[j]);
+        let t = (x - x0) / (x1 - x0);
+        Some(self.ys[j - 1] + t * (self.ys[j] - self.ys[j - 1]))
+    }
+
+    /// Index range of the samples whose X lies within `[x_min, x_max]`.
+    ///
+    /// Uses a binary search, so `xs` **must be sorted ascending**; the result is
+    /// meaningless otherwise. Slice the series with [`Self::slice`] to get the samples.
+    pub fn visible_range(&self, x_min: f64, x_max: f64) -> core::ops::Range<usize> {
+        let start = self.xs.partition_point(|x| *x < x_min);
+        let end = self.xs.partition_point(|x| *x <= x_max).max(start);
+        start..end
+    }
+
+    /// Min/max decimation: split the samples by index into `target_px_buckets` groups and
+    /// keep the lowest and highest Y of each, so spikes survive. Returns `[x, y]` pairs in
+    /// sample order.
+    ///
+    /// Deterministic; the first and last samples are always kept, and a non-finite sample
+    /// is kept per group that has one so gaps stay gaps. Series with at most two samples per
+    /// group (or `target_px_buckets == 0`) are returned whole.
+    pub fn decimate_minmax(&self, target_px_buckets: usize) -> Vec<[f64; 2]> {
+        self.decimate_minmax_indices(target_px_buckets)
+            .into_iter()
+            .map(|i| [self.xs[i], self.ys[i]])
+            .collect()
+    }
+
+    /// The sample indices kept by [`Self::decimate_minmax`], ascending.
+    pub(crate) fn decimate_minmax_indices(&self, buckets: usize) -> Vec<usize> {
+        let n = self.len();
+        if buckets == 0 || n <= 2 * buckets {
+            return (0..n).collect();
+        }
+        let mut out = Vec::with_capacity(3 * buckets + 2);
+        out.push(0);
+        for b in 0..buckets {
+            let (mut lo, mut hi, mut gap) = (None::<usize>, None::<usize>, None);
+            for i in b * n / buckets..(b + 1) * n / buckets {
+                let y = self.ys[i];
+                if !self.xs[i].is_finite() || !y.is_finite() {
+                    gap.get_or_insert(i);
+                    continue;
+                }
+                if lo.is_none_or(|j| y < self.ys[j]) {
+                    lo = Some(i);
+                }
+                if hi.is_none_or(|j| y > self.ys[j]) {
+                    hi = Some(i);
+                }
             }
+            let mut picked: Vec<usize> = lo.into_iter().chain(hi).chain(gap).collect();
+            picked.sort_unstable();
+            out.extend(picked);
         }
+        out.push(n - 1);
+        out.dedup();
+        out
+    }
+
+    /// Indices to draw for a plot `width_px` wide showing `x_range`, decimated with
+    /// [`Self::decimate_minmax`] to about two samples per pixel. `None` if the visible
+    /// samples already fit, in which case everything is drawn as usual.
+    ///
+    /// With `sorted` X only the visible samples (plus one on each side) are considered.
+    pub(crate) fn downsample_indices(
+        &self,
+        sorted: bool,
+        (x_min, x_max): (f64, f64),
+        width_px: f32,
+    ) -> Option<Vec<usize>> {
+        let range = if sorted {
+            let r = self.visible_range(x_min, x_max);
+            r.start.saturating_sub(1)..(r.end + 1).min(self.len())
+        } else {
+            0..self.len()
+        };
+        let buckets = width_px.ceil().max(1.0) as usize;
+        if range.len() <= 2 * buckets {
+            return None;
+        }
+        let offset = range.start;
+        let indices = self
+            .slice(range)
+            .decimate_minmax_indices(buckets)
+            .into_iter()
+            .map(|i| i + offset)
+            .collect();
+        Some(indices)
+    }
+
+    /// Largest-Triangle-Three-Buckets downsampling to about `threshold` samples, keeping the
+    /// ones that matter most to the shape of the line. Returns owned `(xs, ys)`; wrap them
+    /// in [`ColumnarSeries::new`] to plot them.
+    ///
+    /// - `threshold >= len()` returns the series unchanged.
+    /// - `threshold < 3` returns only the first and last samples.
+    /// - Non-finite samples split the series into runs that are downsampled separately
+    ///   (sharing `threshold` by length), with one non-finite sample kept between runs so
+    ///   the gaps survive.
+    pub fn lttb(&self, threshold: usize) -> (Vec<f64>, Vec<f64>) {
+        let n = self.len();
+        let indices: Vec<usize> = if threshold >= n {
+            (0..n).collect()
+        } else if threshold < 3 {
+            vec![0, n - 1]
+        } else {
+            let finite = |i: usize| self.xs[i].is_finite() && self.ys[i].is_finite();
+            let total = (0..n).filter(|&i| finite(i)).count().max(1);
+            let mut out = Vec::with_capacity(threshold + 8);
+            let mut prev_end = None;
+            for (first, last) in iter_runs(n, finite) {
+                if let Some(end) = prev_end {
+                    out.push(end + 1); // the first sample of the gap
+                }
+                let len = last + 1 - first;
+                let share = (threshold * len).div_ceil(total).max(2);
+                out.extend(
+                    lttb_indices(&self.xs[first..=last], &self.ys[first..=last], share)
+                        .into_iter()
+                        .map(|i| i + first),
+                );
+                prev_end = Some(last);
+            }
+            out
+        };
+        indices.iter().map(|&i| (self.xs[i], self.ys[i])).unzip()
     }
 
     /// Estimate numeric bounds over all finite points in the series.
@@ -149,6 +345,223 @@ impl<'a> ColumnarSeries<'a> {
     }
 }
 
+/// A zero-copy series of `(x, y)` stored as `f32`, e.g. logger data kept at half the memory.
+///
+/// Same surface as [`ColumnarSeries`]; values are widened to `f64` on access. Plot it with
+/// [`crate::Line::from_columnar`] or [`crate::Scatter::columnar`].
+#[derive(Copy, Clone, Debug)]
+pub struct ColumnarSeriesF32<'a> {
+    xs: &'a [f32],
+    ys: &'a [f32],
+}
+
+impl<'a> ColumnarSeriesF32<'a> {
+    /// Construct from borrowed `xs` and `ys`.
+    ///
+    /// # Panics
+    /// Panics if `xs.len() != ys.len()`.
+    #[inline]
+    pub fn new(xs: &'a [f32], ys: &'a [f32]) -> Self {
+        assert!(
+            xs.len() == ys.len(),
+            "ColumnarSeriesF32::new: xs and ys must have equal length (got {} vs {})",
+            xs.len(),
+            ys.len()
+        );
+        Self { xs, ys }
+    }
+
+    /// Borrow the X slice.
+    #[inline]
+    pub fn xs(&self) -> &'a [f32] {
+        self.xs
+    }
+
+    /// Borrow the Y slice.
+    #[inline]
+    pub fn ys(&self) -> &'a [f32] {
+        self.ys
+    }
+
+    /// Number of samples.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Is the series empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Get the `(x, y)` at `index` as `f64`, if in-bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<(f64, f64)> {
+        (index < self.len()).then(|| (f64::from(self.xs[index]), f64::from(self.ys[index])))
+    }
+
+    /// Iterate over the `(x, y)` pairs as `f64`.
+    #[allow(clippy::iter_without_into_iter)]
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (f64, f64)> + 'a {
+        self.xs
+            .iter()
+            .zip(self.ys)
+            .map(|(&x, &y)| (f64::from(x), f64::from(y)))
+    }
+
+    /// A subseries by element index range, clamped like [`ColumnarSeries::slice`].
+    pub fn slice<R>(&self, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Unbounded => 0,
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.saturating_add(1),
+        }
+        .min(len);
+        let end = match range.end_bound() {
+            Bound::Unbounded => len,
+            Bound::Included(&i) => i.saturating_add(1),
+            Bound::Excluded(&i) => i,
+        }
+        .min(len)
+        .max(start);
+        Self {
+            xs: &self.xs[start..end],
+            ys: &self.ys[start..end],
+        }
+    }
+
+    /// Bounds over all finite points, accumulated in `f64`. Non-finite values are ignored.
+    pub fn bounds(&self) -> PlotBounds {
+        let mut b = PlotBounds::NOTHING;
+        for (x, y) in self.iter() {
+            if x.is_finite() {
+                b.extend_with_x(x);
+            }
+            if y.is_finite() {
+                b.extend_with_y(y);
+            }
+        }
+        b
+    }
+}
+
+/// Columnar `(x, y)` data that [`crate::Line`] and [`crate::Scatter`] can plot, whatever its
+/// storage type.
+pub trait AsColumnar<'a> {
+    /// Borrow the data as `f64` columns without copying, if that is how it is stored.
+    fn as_f64(&self) -> Option<ColumnarSeries<'a>>;
+
+    /// The columns widened to `f64`.
+    fn to_f64(&self) -> (Vec<f64>, Vec<f64>);
+}
+
+impl<'a> AsColumnar<'a> for ColumnarSeries<'a> {
+    fn as_f64(&self) -> Option<Self> {
+        Some(*self)
+    }
+
+    fn to_f64(&self) -> (Vec<f64>, Vec<f64>) {
+        (self.xs.to_vec(), self.ys.to_vec())
+    }
+}
+
+impl<'a> AsColumnar<'a> for ColumnarSeriesF32<'a> {
+    fn as_f64(&self) -> Option<ColumnarSeries<'a>> {
+        None
+    }
+
+    fn to_f64(&self) -> (Vec<f64>, Vec<f64>) {
+        (
+            self.xs.iter().map(|&x| f64::from(x)).collect(),
+            self.ys.iter().map(|&y| f64::from(y)).collect(),
+        )
+    }
+}
+
+/// The columns of an item: borrowed `f64` data, or a widened copy of other storage.
+pub(crate) enum Columns<'a> {
+    Borrowed(ColumnarSeries<'a>),
+    Owned(Vec<f64>, Vec<f64>),
+}
+
+impl<'a> Columns<'a> {
+    pub(crate) fn from_data(data: &impl AsColumnar<'a>) -> Self {
+        if let Some(series) = data.as_f64() {
+            Self::Borrowed(series)
+        } else {
+            let (xs, ys) = data.to_f64();
+            Self::Owned(xs, ys)
+        }
+    }
+
+    /// View as a [`ColumnarSeries`].
+    pub(crate) fn view(&self) -> ColumnarSeries<'_> {
+        match self {
+            Self::Borrowed(series) => *series,
+            Self::Owned(xs, ys) => ColumnarSeries::new(xs, ys),
+        }
+    }
+}
+
+/// How a [`crate::Line`] reduces its samples before drawing, see [`crate::Line::simplify`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Simplify {
+    /// Draw every sample.
+    #[default]
+    None,
+
+    /// Largest-Triangle-Three-Buckets down to about this many samples,
+    /// see [`ColumnarSeries::lttb`].
+    Lttb(usize),
+}
+
+/// Indices of `m` samples picked by LTTB from the finite `xs`/`ys`, first and last included.
+fn lttb_indices(xs: &[f64], ys: &[f64], m: usize) -> Vec<usize> {
+    let n = xs.len();
+    if m >= n {
+        return (0..n).collect();
+    }
+    if m < 3 {
+        return vec![0, n - 1];
+    }
+    // The first and last samples are kept; the others are split into `m - 2` buckets.
+    let every = (n - 2) as f64 / (m - 2) as f64;
+    let bucket = |b: usize| {
+        let start = (b as f64 * every) as usize + 1;
+        let end = (((b + 1) as f64 * every) as usize + 1).min(n - 1);
+        start..end.max(start + 1).min(n - 1)
+    };
+    let mut out = Vec::with_capacity(m);
+    out.push(0);
+    let mut a = 0;
+    for b in 0..m - 2 {
+        // The third triangle vertex is the average of the next bucket (or the last sample).
+        let next = if b + 3 <= m { bucket(b + 1) } else { n - 1..n };
+        let count = next.len().max(1) as f64;
+        let avg_x = xs[next.clone()].iter().sum::<f64>() / count;
+        let avg_y = ys[next].iter().sum::<f64>() / count;
+
+        let area = |i: usize| {
+            ((xs[a] - avg_x) * (ys[i] - ys[a]) - (xs[a] - xs[i]) * (avg_y - ys[a])).abs()
+        };
+        let picked = bucket(b)
+            .max_by(|&i, &j| area(i).total_cmp(&area(j)).then(j.cmp(&i)))
+            .unwrap_or(a);
+        if picked != a {
+            out.push(picked);
+            a = picked;
+        }
+    }
+    out.push(n - 1);
+    out
+}
+
 /// Iterator over `(x, y)` pairs in a [`ColumnarSeries`].
 pub struct ColumnarSeriesIter<'a> {
     xs: &'a [f64],
@@ -213,3 +626,114 @@ impl<'a> From<(&'a [f64], &'a [f64])> for ColumnarSeries<'a> {
         Self::new(tup.0, tup.1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimate_minmax() {
+        let n = 1_000_000;
+        let xs: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let mut ys: Vec<f64> = xs.iter().map(|x| (x * 0.001).sin()).collect();
+        ys[123_457] = 50.0; // a one-sample spike
+        ys[700_001] = f64::NAN; // a gap
+        let series = ColumnarSeries::new(&xs, &ys);
+
+        let points = series.decimate_minmax(800);
+        assert!(
+            points.len() <= 3 * 800 + 2,
+            "{n} -> {} points",
+            points.len()
+        );
+        assert_eq!(points.first(), Some(&[0.0, ys[0]]));
+        assert_eq!(points.last(), Some(&[xs[n - 1], ys[n - 1]]));
+        assert!(points.contains(&[123_457.0, 50.0]));
+        assert!(points.iter().any(|p| p[1].is_nan()));
+        assert!(points.windows(2).all(|w| w[0][0] < w[1][0]));
+        // Bitwise, since the kept gap is NaN.
+        let bits = |points: &[[f64; 2]]| -> Vec<[u64; 2]> {
+            points.iter().map(|p| p.map(f64::to_bits)).collect()
+        };
+        assert_eq!(
+            bits(&series.decimate_minmax(800)),
+            bits(&points),
+            "deterministic"
+        );
+
+        // Few samples per bucket: nothing to drop.
+        assert_eq!(series.slice(..100).decimate_minmax(800).len(), 100);
+    }
+
+    #[test]
+    fn test_lttb() {
+        let xs: Vec<f64> = (0..1000).map(f64::from).collect();
+        let mut ys: Vec<f64> = xs.iter().map(|x| (x * 0.05).sin()).collect();
+        ys[400] = 30.0;
+        let series = ColumnarSeries::new(&xs, &ys);
+
+        let (lx, ly) = series.lttb(100);
+        assert_eq!(lx.len(), 100);
+        assert_eq!((lx[0], lx[99]), (0.0, 999.0));
+        assert!(ly.contains(&30.0), "the spike is kept");
+        assert!(lx.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(series.lttb(1000).0.len(), 1000);
+        assert_eq!(series.lttb(2), (vec![0.0, 999.0], vec![ys[0], ys[999]]));
+
+        // A NaN splits the series; the gap is kept between the two downsampled runs.
+        ys[500] = f64::NAN;
+        let (lx, ly) = ColumnarSeries::new(&xs, &ys).lttb(100);
+        let gap = ly.iter().position(|y| y.is_nan()).unwrap();
+        assert_eq!(lx[gap], 500.0);
+        assert_eq!((lx[gap - 1], lx[gap + 1]), (499.0, 501.0));
+    }
+
+    #[test]
+    fn test_columnar_lookup_at_x() {
+        let xs = [0.0, 1.0, 2.0, 4.0];
+        let ys = [10.0, 20.0, 30.0, 50.0];
+        let series = ColumnarSeries::new(&xs, &ys).assume_sorted();
+        assert!(series.is_assumed_sorted());
+
+        // Exact hits.
+        assert_eq!(series.index_at_x(2.0), Some(2));
+        assert_eq!(series.value_at_x(2.0), Some(30.0));
+        assert_eq!(series.value_at_x(0.0), Some(10.0));
+
+        // Between samples: nearest index, interpolated value.
+        assert_eq!(series.index_at_x(3.2), Some(3));
+        assert_eq!(series.index_at_x(2.9), Some(2));
+        assert_eq!(series.value_at_x(3.0), Some(40.0));
+
+        // Outside the data.
+        assert_eq!(series.index_at_x(-5.0), Some(0));
+        assert_eq!(series.index_at_x(9.0), Some(3));
+        assert_eq!(series.value_at_x(4.5), None);
+        assert_eq!(ColumnarSeries::EMPTY.index_at_x(1.0), None);
+    }
+
+    #[test]
+    fn test_columnar_series_f32() {
+        let xs = [0.0_f32, 1.0, 2.0, 3.0];
+        let ys = [f32::MAX, -1.5, f32::NAN, 2.5];
+        let series = ColumnarSeriesF32::new(&xs, &ys);
+        assert_eq!(series.get(1), Some((1.0, -1.5)));
+        assert_eq!(series.get(4), None);
+        assert_eq!(series.iter().len(), 4);
+
+        // Accumulated in f64: the f32 maximum does not overflow, NaN is skipped.
+        let b = series.bounds();
+        assert_eq!(b.min(), [0.0, -1.5]);
+        assert_eq!(b.max(), [3.0, f64::from(f32::MAX)]);
+
+        let tail = series.slice(2..);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail.get(1), Some((3.0, 2.5)));
+
+        assert!(series.as_f64().is_none());
+        let (wide_xs, wide_ys) = series.to_f64();
+        assert_eq!(wide_xs, [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(wide_ys[3], 2.5);
+    }
+}
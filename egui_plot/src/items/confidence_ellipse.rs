@@ -0,0 +1,222 @@
+//! Covariance ("confidence") ellipse for a 2D cluster of points.
+//!
+//! The ellipse is computed in plot space and tessellated in screen space, with a segment count
+//! that follows its on-screen size, so it stays smooth at any zoom level.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Shape, Stroke, Ui};
+
+use super::{PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
+use crate::{PlotBounds, PlotTransform};
+
+/// A rotated ellipse `sigma` standard deviations around a mean, from a 2×2 covariance matrix.
+pub struct ConfidenceEllipse {
+    base: PlotItemBase,
+
+    mean: PlotPoint,
+
+    /// Symmetric covariance matrix `[[var_x, cov_xy], [cov_xy, var_y]]`.
+    covariance: [[f64; 2]; 2],
+
+    sigma: f64,
+
+    /// Outline stroke. A transparent color means the color is auto-assigned.
+    pub(crate) stroke: Stroke,
+
+    fill_alpha: f32,
+
+    include_in_bounds: bool,
+}
+
+impl ConfidenceEllipse {
+    /// An ellipse around `mean` for the covariance matrix `[[var_x, cov_xy], [cov_xy, var_y]]`.
+    ///
+    /// Only the upper triangle is read, so the matrix is always treated as symmetric.
+    pub fn new(
+        name: impl Into<String>,
+        mean: impl Into<PlotPoint>,
+        covariance: [[f64; 2]; 2],
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            mean: mean.into(),
+            covariance,
+            sigma: 2.0,
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            fill_alpha: 0.0,
+            include_in_bounds: true,
+        }
+    }
+
+    /// An ellipse for the sample mean and (unbiased) sample covariance of `points`.
+    ///
+    /// Non-finite points are skipped. With fewer than two points the ellipse is degenerate
+    /// and draws nothing.
+    pub fn from_points(
+        name: impl Into<String>,
+        points: impl IntoIterator<Item = impl Into<PlotPoint>>,
+    ) -> Self {
+        let points: Vec<PlotPoint> = points
+            .into_iter()
+            .map(Into::into)
+            .filter(|p: &PlotPoint| p.x.is_finite() && p.y.is_finite())
+            .collect();
+        let n = points.len() as f64;
+        let mean = PlotPoint::new(
+            points.iter().map(|p| p.x).sum::<f64>() / n,
+            points.iter().map(|p| p.y).sum::<f64>() / n,
+        );
+        let mut covariance = [[0.0; 2]; 2];
+        if points.len() >= 2 {
+            for p in &points {
+                let (dx, dy) = (p.x - mean.x, p.y - mean.y);
+                covariance[0][0] += dx * dx;
+                covariance[0][1] += dx * dy;
+                covariance[1][1] += dy * dy;
+            }
+            for row in &mut covariance {
+                for v in row {
+                    *v /= n - 1.0;
+                }
+            }
+        } else {
+            covariance = [[f64::NAN; 2]; 2];
+        }
+        covariance[1][0] = covariance[0][1];
+        Self::new(name, mean, covariance)
+    }
+
+    /// How many standard deviations the ellipse spans. Default: `2.0`.
+    #[inline]
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    /// Outline color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Outline width. Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Opacity of the fill, in the outline color. Default: `0.0` (outline only).
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Whether the ellipse counts towards the automatic plot bounds. Default: `true`.
+    #[inline]
+    pub fn include_in_bounds(mut self, on: bool) -> Self {
+        self.include_in_bounds = on;
+        self
+    }
+
+    builder_methods_for_base!();
+
+    /// Semi-axis lengths and rotation (radians) of the ellipse, or `None` if it is degenerate.
+    fn axes(&self) -> Option<(f64, f64, f64)> {
+        let [[a, b], [_, c]] = self.covariance;
+        let mid = 0.5 * (a + c);
+        let r = (0.25 * (a - c) * (a - c) + b * b).sqrt();
+        let (l1, l2) = (mid + r, (mid - r).max(0.0));
+        let axes = (self.sigma * l1.sqrt(), self.sigma * l2.sqrt());
+        let angle = 0.5 * (2.0 * b).atan2(a - c);
+        (axes.0.is_finite() && axes.1.is_finite() && axes.0 > 0.0 && self.mean.x.is_finite())
+            .then_some((axes.0, axes.1, angle))
+    }
+}
+
+impl PlotItem for ConfidenceEllipse {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Some((r1, r2, angle)) = self.axes() else {
+            return;
+        };
+        let (sin, cos) = angle.sin_cos();
+        let at = |t: f64| {
+            let (u, v) = (r1 * t.cos(), r2 * t.sin());
+            transform.position_from_point(&PlotPoint::new(
+                self.mean.x + u * cos - v * sin,
+                self.mean.y + u * sin + v * cos,
+            ))
+        };
+
+        // Enough segments for ~4 px each along the on-screen outline.
+        let center = transform.position_from_point(&self.mean);
+        let quarter = std::f64::consts::FRAC_PI_2;
+        let radius_px = center.distance(at(0.0)) + center.distance(at(quarter));
+        let n = ((std::f32::consts::PI * radius_px / 4.0) as usize).clamp(16, 512);
+        let points: Vec<_> = (0..n)
+            .map(|i| at(std::f64::consts::TAU * i as f64 / n as f64))
+            .collect();
+
+        let mut stroke = self.stroke;
+        stroke.width *= 1.0 + self.base.highlight_amount();
+        if self.fill_alpha > 0.0 {
+            let fill = stroke.color.gamma_multiply(self.fill_alpha);
+            shapes.push(Shape::convex_polygon(points.clone(), fill, Stroke::NONE));
+        }
+        shapes.push(Shape::closed_line(points, stroke));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if !self.include_in_bounds || self.axes().is_none() {
+            return PlotBounds::NOTHING;
+        }
+        // The extent along each axis is `sigma` times that axis' standard deviation.
+        let half_w = self.sigma * self.covariance[0][0].max(0.0).sqrt();
+        let half_h = self.sigma * self.covariance[1][1].max(0.0).sqrt();
+        PlotBounds::from_min_max(
+            [self.mean.x - half_w, self.mean.y - half_h],
+            [self.mean.x + half_w, self.mean.y + half_h],
+        )
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confidence_ellipse_bounds() {
+        let points = [[-1.0, 0.0], [1.0, 0.0], [0.0, -2.0], [0.0, 2.0]];
+        let ellipse = ConfidenceEllipse::from_points("", points).sigma(1.0);
+        // var_x = 2/3, var_y = 8/3, no correlation.
+        let b = ellipse.bounds();
+        assert!((b.max[0] - (2.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert!((b.min[1] + (8.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert!(
+            !ConfidenceEllipse::from_points("", [[1.0, 1.0]])
+                .bounds()
+                .is_valid()
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! A point handle the user can drag around the plot.
+//!
+//! While grabbed, the plot doesn't pan; each frame of the drag emits
+//! [`crate::PlotEvent::PointDragged`] with the new position, which the application
+//! should store and feed back into the item on the next frame.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Shape, Stroke, Ui, epaint::CircleShape};
+
+use super::{PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
+use crate::{PlotBounds, PlotTransform};
+
+/// The draggable part of an item, see [`PlotItem::drag_handle_mut`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DragHandle {
+    /// Current handle position in plot coordinates.
+    pub pos: PlotPoint,
+
+    /// Grab radius in screen pixels (at least the egui interact radius is used).
+    pub radius: f32,
+
+    /// Snap to the nearest data sample of other items while dragging.
+    pub snap_to_data: bool,
+}
+
+/// A draggable annotation point.
+///
+/// It does not contribute to the automatic plot bounds, so dragging it towards an edge
+/// doesn't make the view run away.
+pub struct DraggablePoint {
+    base: PlotItemBase,
+    handle: DragHandle,
+    color: Color32,
+}
+
+impl DraggablePoint {
+    /// Create a handle at `pos`. `id` identifies it in [`crate::PlotEvent::PointDragged`].
+    pub fn new(id: impl std::hash::Hash, pos: impl Into<PlotPoint>) -> Self {
+        let mut base = PlotItemBase::new(String::new());
+        base.id = Id::new(id);
+        Self {
+            base,
+            handle: DragHandle {
+                pos: pos.into(),
+                radius: 5.0,
+                snap_to_data: false,
+            },
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Handle radius in pixels. Default: `5.0`.
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.handle.radius = radius;
+        self
+    }
+
+    /// Handle color. Default is `Color32::TRANSPARENT` which means the text color is used.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Snap to the nearest data sample of other items while dragging. Default: `false`.
+    #[inline]
+    pub fn snap_to_data(mut self, snap: bool) -> Self {
+        self.handle.snap_to_data = snap;
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for DraggablePoint {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let center = transform.position_from_point(&self.handle.pos);
+        if !center.is_finite() {
+            return;
+        }
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.visuals().text_color()
+        } else {
+            self.color
+        };
+        let mut radius = self.handle.radius;
+        radius *= 1.0 + 0.5 * self.base.highlight_amount();
+        shapes.push(Shape::Circle(CircleShape {
+            center,
+            radius,
+            fill: color.gamma_multiply(0.5),
+            stroke: Stroke::new(1.5, color),
+        }));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        PlotBounds::NOTHING
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn drag_handle_mut(&mut self) -> Option<&mut DragHandle> {
+        Some(&mut self.handle)
+    }
+}
@@ -1,6 +1,119 @@
-use egui::{Color32, Pos2, Shape, Stroke, Vec2};
+use crate::{PlotPoint, items::StepMode};
+use egui::{Color32, Mesh, Pos2, Shape, Stroke, Vec2};
 use std::f32::consts::PI;
 
+/// Drop points closer than `min_px` to the previously kept point.
+///
+/// The first and last points are always kept. Non-finite points are passed through.
+pub fn simplify_px(points: impl Iterator<Item = Pos2>, min_px: f32) -> impl Iterator<Item = Pos2> {
+    let min_sq = min_px * min_px;
+    let mut points = points.peekable();
+    let mut last: Option<Pos2> = None;
+    std::iter::from_fn(move || {
+        loop {
+            let p = points.next()?;
+            let keep = match last {
+                None => true,
+                Some(l) => {
+                    let d = l.distance_sq(p);
+                    points.peek().is_none() || d >= min_sq || d.is_nan()
+                }
+            };
+            if keep {
+                last = Some(p);
+                return Some(p);
+            }
+        }
+    })
+}
+
+/// Expand consecutive samples into a staircase, in plot space.
+///
+/// The corners are inserted between the samples, which are all kept. [`StepMode::None`]
+/// passes the points through.
+pub fn step_points(
+    points: impl Iterator<Item = PlotPoint>,
+    mode: StepMode,
+) -> impl Iterator<Item = PlotPoint> {
+    let mut prev: Option<PlotPoint> = None;
+    points.flat_map(move |p| {
+        let corners = match (prev.replace(p), mode) {
+            (None, _) | (_, StepMode::None) => [None, None],
+            (Some(a), StepMode::Before) => [Some(PlotPoint::new(a.x, p.y)), None],
+            (Some(a), StepMode::After) => [Some(PlotPoint::new(p.x, a.y)), None],
+            (Some(a), StepMode::Center) => {
+                let x = 0.5 * (a.x + p.x);
+                [Some(PlotPoint::new(x, a.y)), Some(PlotPoint::new(x, p.y))]
+            }
+        };
+        corners.into_iter().flatten().chain(std::iter::once(p))
+    })
+}
+
+/// Append a polyline to `mesh` as one quad per segment, each blending the colors of its ends.
+///
+/// `colors` pairs with `points`; zero-length segments are skipped.
+pub fn push_gradient_polyline(mesh: &mut Mesh, points: &[Pos2], colors: &[Color32], width: f32) {
+    let half = 0.5 * width;
+    for (p, c) in points.windows(2).zip(colors.windows(2)) {
+        let (a, b) = (p[0], p[1]);
+        let d = b - a;
+        let len = d.length();
+        if !(len > 0.0 && len.is_finite()) {
+            continue;
+        }
+        let n = (d / len).rot90() * half;
+        let i = mesh.vertices.len() as u32;
+        mesh.colored_vertex(a + n, c[0]);
+        mesh.colored_vertex(a - n, c[0]);
+        mesh.colored_vertex(b + n, c[1]);
+        mesh.colored_vertex(b - n, c[1]);
+        mesh.add_triangle(i, i + 1, i + 2);
+        mesh.add_triangle(i + 1, i + 2, i + 3);
+    }
+}
+
+/// Maximal runs of consecutive indices in `0..len` for which `keep` holds,
+/// as inclusive `(first, last)` pairs in order.
+///
+/// A run may be a single index; callers decide whether that draws anything.
+pub fn iter_runs(len: usize, keep: impl Fn(usize) -> bool) -> impl Iterator<Item = (usize, usize)> {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < len && !keep(i) {
+            i += 1;
+        }
+        if i >= len {
+            return None;
+        }
+        let first = i;
+        while i + 1 < len && keep(i + 1) {
+            i += 1;
+        }
+        i += 1;
+        Some((first, i - 1))
+    })
+}
+
+/// The `low` and `high` quantiles (fractions in `0.0..=1.0`) of `values`, by nearest rank.
+///
+/// Reorders `values`. `None` if it is empty.
+pub fn quantile_range(values: &mut [f64], low: f64, high: f64) -> Option<(f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+    let last = values.len() - 1;
+    let rank = |q: f64| ((q.clamp(0.0, 1.0) * last as f64).round() as usize).min(last);
+    let (lo, hi) = (rank(low.min(high)), rank(low.max(high)));
+    let (_, &mut y_lo, right) = values.select_nth_unstable_by(lo, f64::total_cmp);
+    let y_hi = if hi == lo {
+        y_lo
+    } else {
+        *right.select_nth_unstable_by(hi - lo - 1, f64::total_cmp).1
+    };
+    Some((y_lo, y_hi))
+}
+
 #[inline]
 pub fn regular_ngon(n: usize, r: f32, angle_rad: f32) -> Vec<Pos2> {
     let n = n.max(3);
@@ -40,3 +153,42 @@ pub fn push_polygon_at(
         out.push(Shape::closed_line(pts, Stroke::new(stroke.width, color)));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_runs_edge_gaps() {
+        let runs = |keep: &[bool]| iter_runs(keep.len(), |i| keep[i]).collect::<Vec<_>>();
+        assert_eq!(runs(&[false, true, true, false]), vec![(1, 2)]);
+        assert_eq!(runs(&[true, false, true]), vec![(0, 0), (2, 2)]);
+        assert_eq!(runs(&[true, false, false, true]), vec![(0, 0), (3, 3)]);
+        assert_eq!(runs(&[true, true, false, true, true]), vec![(0, 1), (3, 4)]);
+        assert!(runs(&[false, false]).is_empty());
+        assert!(runs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_step_points() {
+        let pts = [PlotPoint::new(0.0, 1.0), PlotPoint::new(2.0, 3.0)];
+        let xy = |mode| {
+            step_points(pts.into_iter(), mode)
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(xy(StepMode::None), vec![(0.0, 1.0), (2.0, 3.0)]);
+        assert_eq!(
+            xy(StepMode::After),
+            vec![(0.0, 1.0), (2.0, 1.0), (2.0, 3.0)]
+        );
+        assert_eq!(
+            xy(StepMode::Before),
+            vec![(0.0, 1.0), (0.0, 3.0), (2.0, 3.0)]
+        );
+        assert_eq!(
+            xy(StepMode::Center),
+            vec![(0.0, 1.0), (1.0, 1.0), (1.0, 3.0), (2.0, 3.0)]
+        );
+    }
+}
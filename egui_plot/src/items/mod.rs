@@ -1,33 +1,38 @@
 //! Contains items that can be added to a plot.
 #![allow(clippy::type_complexity)] // TODO(emilk): simplify some of the callback types with type aliases
 
-use std::{ops::RangeInclusive, sync::Arc};
+use std::{cell::OnceCell, ops::RangeInclusive, sync::Arc};
 
 use egui::{
     Align2, Color32, CornerRadius, Id, ImageOptions, Mesh, NumExt as _, PopupAnchor, Pos2, Rect,
     Rgba, Shape, Stroke, TextStyle, TextureId, Ui, Vec2, WidgetText,
     emath::Rot2,
     epaint::{CircleShape, PathStroke, TextShape},
-    pos2, vec2,
+    lerp, pos2, vec2,
 };
 
-use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use super::{Cursor, Interval, LabelFormatter, NumberFormat, PlotBounds, PlotTransform, Segment};
 
 use crate::items::scatter::MarkerColor;
 pub use crate::items::tooltip::HitPoint;
 pub use crate::items::tooltip::PinnedPoints;
-pub use crate::items::tooltip::TooltipOptions;
+pub use crate::items::tooltip::{
+    BandStats, HitOrder, PickMode, PinOverflow, TooltipFormatter, TooltipOptions,
+};
+pub(crate) use crate::items::tooltip::{pick_nearest, pick_nearest_indexed, selection_stats};
 pub use band::Band;
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
-pub use columnar_series::ColumnarSeries;
+pub(crate) use columnar_series::Columns;
+pub use columnar_series::{AsColumnar, ColumnarSeries, ColumnarSeriesF32, Simplify};
 use emath::Float as _;
 use rect_elem::{RectElement, highlighted_color};
 pub use scatter::Marker;
 pub use scatter::Scatter;
 pub use scatter::ScatterEncodings;
+pub use stacked_area::StackedArea;
 pub use values::{
-    ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
+    ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints, StepMode,
 };
 mod band;
 mod bar;
@@ -36,16 +41,23 @@ mod columnar_series;
 pub(crate) mod geom_helpers;
 mod rect_elem;
 mod scatter;
-mod tooltip;
+mod stacked_area;
+pub(crate) mod tooltip;
 mod values;
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PlotItemBase {
     name: String,
     id: Id,
     highlight: bool,
+    /// Partial highlight in `0..=1` while a highlight animates in or out.
+    highlight_amount: f32,
     allow_hover: bool,
+    /// Opacity multiplier applied when drawing, e.g. to dim non-hovered items.
+    opacity: f32,
+    /// Plotted against the secondary (right) Y axis.
+    on_right_axis: bool,
 }
 
 impl PlotItemBase {
@@ -55,7 +67,19 @@ impl PlotItemBase {
             name,
             id,
             highlight: false,
+            highlight_amount: 0.0,
             allow_hover: true,
+            opacity: 1.0,
+            on_right_axis: false,
+        }
+    }
+
+    /// How far the highlight has eased in, `0..=1`. `1.0` when fully highlighted.
+    pub(crate) fn highlight_amount(&self) -> f32 {
+        if self.highlight {
+            1.0
+        } else {
+            self.highlight_amount
         }
     }
 }
@@ -88,6 +112,17 @@ macro_rules! builder_methods_for_base {
             self
         }
 
+        /// Plot this item against the secondary Y axis, see [`crate::Plot::right_y_axis`].
+        /// Default: `false`.
+        ///
+        /// Its Y values are then in the units of that axis, while X is shared. Without a
+        /// secondary axis the item is plotted against the primary one.
+        #[inline]
+        pub fn on_right_axis(mut self, right: bool) -> Self {
+            self.base_mut().on_right_axis = right;
+            self
+        }
+
         /// Sets the id of this plot item.
         ///
         /// By default the id is determined from the name, but it can be explicitly set to a different value.
@@ -99,6 +134,14 @@ macro_rules! builder_methods_for_base {
     };
 }
 
+// Declared after `builder_methods_for_base!` so the items can use the macro.
+mod confidence_ellipse;
+mod draggable_point;
+mod rug;
+pub use confidence_ellipse::ConfidenceEllipse;
+pub use draggable_point::{DragHandle, DraggablePoint};
+pub use rug::Rug;
+
 /// Container to pass-through several parameters related to plot visualization
 pub struct PlotConfig<'a> {
     pub ui: &'a Ui,
@@ -128,11 +171,37 @@ pub trait PlotItem {
         self.base().highlight
     }
 
+    /// Highlight partially, `t` in `0..=1`, e.g. while the highlight animates.
+    ///
+    /// `t >= 1.0` is a full [`Self::highlight`]; in between, strokes, markers and fills
+    /// are drawn part of the way towards their highlighted look.
+    fn set_highlight_amount(&mut self, t: f32) {
+        if t >= 1.0 {
+            self.highlight();
+        } else {
+            self.base_mut().highlight_amount = t.max(0.0);
+        }
+    }
+
+    /// Multiply the opacity of everything this item draws this frame, `0..=1`.
+    fn set_opacity(&mut self, opacity: f32) {
+        self.base_mut().opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    fn opacity(&self) -> f32 {
+        self.base().opacity
+    }
+
     /// Can the user hover this item?
     fn allow_hover(&self) -> bool {
         self.base().allow_hover
     }
 
+    /// Is this item plotted against the secondary (right) Y axis?
+    fn on_right_axis(&self) -> bool {
+        self.base().on_right_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_>;
 
     fn bounds(&self) -> PlotBounds;
@@ -145,6 +214,41 @@ pub trait PlotItem {
         self.base().id
     }
 
+    /// Vertical offset baked into this item's geometry (e.g. a waterfall trace).
+    ///
+    /// Tooltips subtract it so they report the original data values.
+    fn y_offset(&self) -> f64 {
+        0.0
+    }
+
+    /// `true` if the X values of a [`PlotGeometry::PointsXY`] geometry are sorted ascending,
+    /// so lookups may binary-search.
+    ///
+    /// The default checks on every call; items with columnar data remember the answer.
+    fn sorted_x(&self) -> bool {
+        match self.geometry() {
+            PlotGeometry::PointsXY { xs, .. } => xs.is_sorted(),
+            _ => false,
+        }
+    }
+
+    /// The `[xs, y_min, y_max]` samples of a shaded envelope, if this item is one (see [`Band`]).
+    fn envelope(&self) -> Option<[&[f64]; 3]> {
+        None
+    }
+
+    /// Screen radius of the marker this item draws at sample `index`, `0.0` if none.
+    ///
+    /// A click within it of the sample hits it (see [`crate::PlotEvent::ItemClicked`]).
+    fn marker_radius(&self, _index: usize) -> f32 {
+        0.0
+    }
+
+    /// The handle the user can drag, if this item is draggable (see [`DraggablePoint`]).
+    fn drag_handle_mut(&mut self) -> Option<&mut DragHandle> {
+        None
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
@@ -162,7 +266,7 @@ pub trait PlotItem {
             PlotGeometry::Rects => {
                 panic!("If the PlotItem is made of rects, it should implement find_closest()")
             }
-            PlotGeometry::PointsXY { xs, ys } => {
+            PlotGeometry::PointsXY { xs, ys, .. } => {
                 let n = xs.len().min(ys.len());
                 (0..n)
                     .map(|index| {
@@ -247,7 +351,7 @@ pub trait PlotItem {
                 }
                 &[value]
             }
-            PlotGeometry::PointsXY { xs, ys } => {
+            PlotGeometry::PointsXY { xs, ys, .. } => {
                 let x = xs[elem.index];
                 let y = ys[elem.index];
                 let value = PlotPoint { x, y };
@@ -353,7 +457,7 @@ impl PlotItem for HLine {
         style.style_line(
             points,
             PathStroke::new(stroke.width, stroke.color),
-            base.highlight,
+            base.highlight_amount(),
             shapes,
         );
     }
@@ -451,7 +555,7 @@ impl PlotItem for VLine {
         style.style_line(
             points,
             PathStroke::new(stroke.width, stroke.color),
-            base.highlight,
+            base.highlight_amount(),
             shapes,
         );
     }
@@ -489,19 +593,47 @@ pub struct LineBlocks<'a> {
 /// A series of values forming a path.
 pub struct Line<'a> {
     base: PlotItemBase,
-    pub(super) columnar: Option<ColumnarSeries<'a>>,
+    pub(super) columnar: Option<Columns<'a>>,
     pub(super) series: Option<PlotPoints<'a>>,
 
     pub(super) stroke: Stroke,
     pub(super) fill: Option<f32>,
     pub(super) fill_alpha: f32,
+    pub(super) fill_color: Option<Color32>,
     pub(super) gradient_color: Option<Arc<dyn Fn(PlotPoint) -> Color32 + Send + Sync>>,
     pub(super) gradient_fill: bool,
+    pub(super) colors: Option<&'a [Color32]>,
     pub(super) style: LineStyle,
+    pub(super) step: StepMode,
     // segmentation
     pub(super) blocks_xy: Option<LineBlocks<'a>>,
 
     pub(super) markers: Option<Marker>,
+    /// Vertical offset already baked into the data (see [`crate::PlotUi::waterfall`]).
+    pub(super) y_offset: f64,
+    /// Minimum screen distance between stroke vertices; `0.0` keeps every sample.
+    pub(super) simplify_px: f32,
+    /// The caller promises the X values are sorted ascending.
+    pub(super) assume_sorted_x: bool,
+    /// Whether the columnar X values are sorted, checked at most once per item.
+    pub(super) sorted_x: OnceCell<bool>,
+    /// Marker drawn at the last finite sample ("current value" indicator).
+    pub(super) mark_last: Option<MarkerShape>,
+    pub(super) mark_last_label: bool,
+    /// Formats the [`Self::mark_last_label`]; taken from the plot by [`crate::PlotUi::line`].
+    pub(super) number_format: NumberFormat,
+    /// Y quantiles used for the auto-bounds instead of min/max.
+    pub(super) autoscale_quantile: Option<(f64, f64)>,
+    /// Per-sample visibility; `false` samples are not drawn but can still be hovered.
+    pub(super) mask: Option<&'a [bool]>,
+    /// Leave the masked-out samples out of the auto-bounds too.
+    pub(super) mask_bounds: bool,
+    /// Dashed connector drawn across gaps between runs.
+    pub(super) gap_bridge: Option<Stroke>,
+    /// Min/max-decimate the visible samples to the plot width before drawing.
+    pub(super) downsample: bool,
+    /// Sample reduction applied before drawing.
+    pub(super) simplify: Simplify,
 }
 impl Line<'_> {
     pub fn markers(mut self, m: Marker) -> Self {
@@ -540,20 +672,48 @@ impl<'a> Line<'a> {
     pub fn new_xy(name: impl Into<String>, xs: &'a [f64], ys: &'a [f64]) -> Self {
         Self::from_series(name, ColumnarSeries::new(xs, ys))
     }
+    /// A line over any [`AsColumnar`] data, e.g. [`ColumnarSeriesF32`].
+    ///
+    /// `f64` data is borrowed as in [`Self::from_series`]; other storage is widened to `f64`
+    /// once, here.
+    pub fn from_columnar(name: impl Into<String>, data: &impl AsColumnar<'a>) -> Self {
+        if let Some(series) = data.as_f64() {
+            return Self::from_series(name, series);
+        }
+        let mut line = Self::from_series(name, ColumnarSeries::EMPTY);
+        line.columnar = Some(Columns::from_data(data));
+        line
+    }
     #[inline]
     pub fn from_series(name: impl Into<String>, series: ColumnarSeries<'a>) -> Self {
         Self {
             base: PlotItemBase::new(name.into()),
-            columnar: Some(series),
+            columnar: Some(Columns::Borrowed(series)),
             series: None,
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
             fill_alpha: DEFAULT_FILL_ALPHA,
+            fill_color: None,
             gradient_color: None,
             gradient_fill: false,
+            colors: None,
             style: LineStyle::Solid,
+            step: StepMode::None,
             blocks_xy: None,
             markers: Some(Marker::default()),
+            y_offset: 0.0,
+            simplify_px: 0.0,
+            assume_sorted_x: series.is_assumed_sorted(),
+            sorted_x: OnceCell::new(),
+            mark_last: None,
+            mark_last_label: false,
+            number_format: NumberFormat::default(),
+            autoscale_quantile: None,
+            mask: None,
+            mask_bounds: false,
+            gap_bridge: None,
+            downsample: false,
+            simplify: Simplify::None,
         }
     }
 }
@@ -577,14 +737,30 @@ impl<'a> Line<'a> {
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
             fill_alpha: DEFAULT_FILL_ALPHA,
+            fill_color: None,
             gradient_color: None,
             gradient_fill: false,
+            colors: None,
             style: LineStyle::Solid,
+            step: StepMode::None,
             blocks_xy: Some(LineBlocks {
                 xs: xs_blocks,
                 ys: ys_blocks,
             }),
             markers: Some(Marker::default()),
+            y_offset: 0.0,
+            simplify_px: 0.0,
+            assume_sorted_x: false,
+            sorted_x: OnceCell::new(),
+            mark_last: None,
+            mark_last_label: false,
+            number_format: NumberFormat::default(),
+            autoscale_quantile: None,
+            mask: None,
+            mask_bounds: false,
+            gap_bridge: None,
+            downsample: false,
+            simplify: Simplify::None,
         }
     }
     pub fn new(name: impl Into<String>, series: impl Into<PlotPoints<'a>>) -> Self {
@@ -595,14 +771,77 @@ impl<'a> Line<'a> {
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
             fill_alpha: DEFAULT_FILL_ALPHA,
+            fill_color: None,
             gradient_color: None,
             gradient_fill: false,
+            colors: None,
             style: LineStyle::Solid,
+            step: StepMode::None,
             blocks_xy: None,
             markers: Some(Marker::default()),
+            y_offset: 0.0,
+            simplify_px: 0.0,
+            assume_sorted_x: false,
+            sorted_x: OnceCell::new(),
+            mark_last: None,
+            mark_last_label: false,
+            number_format: NumberFormat::default(),
+            autoscale_quantile: None,
+            mask: None,
+            mask_bounds: false,
+            gap_bridge: None,
+            downsample: false,
+            simplify: Simplify::None,
         }
     }
 
+    /// Plot complex samples in the complex plane: real part on X, imaginary part on Y.
+    ///
+    /// The points are copied into an owned series.
+    #[cfg(feature = "num-complex")]
+    pub fn from_complex_re_im(
+        name: impl Into<String>,
+        values: &[num_complex::Complex<f64>],
+    ) -> Self {
+        let points = values.iter().map(|z| PlotPoint::new(z.re, z.im)).collect();
+        Self::new(name, PlotPoints::Owned(points))
+    }
+
+    /// Plot the magnitude `|z|` of complex samples against `xs`.
+    ///
+    /// Extra samples in the longer slice are ignored. The magnitudes are copied into an
+    /// owned series.
+    #[cfg(feature = "num-complex")]
+    pub fn from_complex_mag(
+        name: impl Into<String>,
+        xs: &[f64],
+        values: &[num_complex::Complex<f64>],
+    ) -> Self {
+        let points = xs
+            .iter()
+            .zip(values)
+            .map(|(&x, z)| PlotPoint::new(x, z.norm()))
+            .collect();
+        Self::new(name, PlotPoints::Owned(points))
+    }
+
+    /// Plot the phase `arg(z)` (radians, in `(-π, π]`) of complex samples against `xs`.
+    ///
+    /// See [`Self::from_complex_mag`].
+    #[cfg(feature = "num-complex")]
+    pub fn from_complex_phase(
+        name: impl Into<String>,
+        xs: &[f64],
+        values: &[num_complex::Complex<f64>],
+    ) -> Self {
+        let points = xs
+            .iter()
+            .zip(values)
+            .map(|(&x, z)| PlotPoint::new(x, z.arg()))
+            .collect();
+        Self::new(name, PlotPoints::Owned(points))
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -627,6 +866,18 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Color each vertex, e.g. by a scalar along a trajectory. Each segment is drawn as a
+    /// gradient between the colors of its two ends.
+    ///
+    /// Points past the end of `colors` reuse its last color; an empty slice keeps the single
+    /// [`Self::color`]. The colored stroke is always solid, and [`Self::downsample`] and
+    /// [`Self::simplify`] are skipped so colors stay with their points.
+    #[inline]
+    pub fn colors(mut self, colors: &'a [Color32]) -> Self {
+        self.colors = (!colors.is_empty()).then_some(colors);
+        self
+    }
+
     /// Stroke width. A high value means the plot thickens.
     #[inline]
     pub fn width(mut self, width: impl Into<f32>) -> Self {
@@ -642,6 +893,9 @@ impl<'a> Line<'a> {
     }
 
     /// Fill the area between this line and a given horizontal reference line.
+    ///
+    /// The fill is drawn beneath the stroke, one area per contiguous run (gaps are not bridged),
+    /// and follows the line to either side of the reference where they cross.
     #[inline]
     pub fn fill(mut self, y_reference: impl Into<f32>) -> Self {
         self.fill = Some(y_reference.into());
@@ -655,6 +909,14 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Use this color for the [`Self::fill`] area instead of the line color at
+    /// [`Self::fill_alpha`]. The color is used as given, so include the transparency you want.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
+
     /// Set the line's style. Default is `LineStyle::Solid`.
     #[inline]
     pub fn style(mut self, style: LineStyle) -> Self {
@@ -662,6 +924,118 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Draw the line as a staircase, for digital or sample-and-hold signals.
+    /// Default is [`StepMode::None`].
+    ///
+    /// Only the drawing (stroke and fill) is stepped; markers, bounds and the tooltip use the
+    /// real samples.
+    #[inline]
+    pub fn step(mut self, step: StepMode) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Skip stroke vertices closer than `min_px` screen pixels to the previously drawn one.
+    ///
+    /// A cheap simplification for dense, meandering paths. Only the stroke is affected:
+    /// fill, markers and the tooltip keep full resolution. Default: `0.0` (off).
+    #[inline]
+    pub fn simplify_px(mut self, min_px: f32) -> Self {
+        self.simplify_px = min_px;
+        self
+    }
+
+    /// Promise that the X values are sorted ascending, so hover lookups and
+    /// [`crate::PlotUi::visible_data`] can binary-search without verifying the order first.
+    ///
+    /// Applies to columnar data ([`Self::from_series`] and friends). Lying about the order
+    /// gives wrong tooltip hits. Default: `false`.
+    #[inline]
+    pub fn assume_sorted_x(mut self, sorted: bool) -> Self {
+        self.assume_sorted_x = sorted;
+        self
+    }
+
+    /// Draw a marker at the last finite sample, e.g. the current value of a live stream.
+    ///
+    /// Follows the data as it grows. Default: `None`.
+    #[inline]
+    pub fn mark_last(mut self, shape: Option<MarkerShape>) -> Self {
+        self.mark_last = shape;
+        self
+    }
+
+    /// Label the [`Self::mark_last`] marker with its Y value, in the plot's number format
+    /// (see [`crate::Plot::decimal_separator`]). Default: `false`.
+    #[inline]
+    pub fn mark_last_label(mut self, on: bool) -> Self {
+        self.mark_last_label = on;
+        self
+    }
+
+    /// Contribute the `(low, high)` quantiles of the Y values to the auto-bounds instead of the
+    /// full min/max, e.g. `Some((0.01, 0.99))`, so a few outliers don't blow up the autoscale.
+    ///
+    /// Quantiles are fractions in `0.0..=1.0`; X bounds are unaffected. Costs a linear-time
+    /// selection over the samples each frame. Default: `None` (min/max).
+    #[inline]
+    pub fn autoscale_quantile(mut self, quantiles: Option<(f64, f64)>) -> Self {
+        self.autoscale_quantile = quantiles;
+        self
+    }
+
+    /// Hide the samples whose `mask` entry is `false` without touching the data: the line
+    /// breaks around them, but the tooltip and hover still see their real values.
+    ///
+    /// Samples past the end of the mask are visible. Ignored by [`Self::new_xy_blocks`] lines.
+    #[inline]
+    pub fn with_mask(mut self, mask: &'a [bool]) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Leave the samples hidden by [`Self::with_mask`] out of the auto-bounds as well.
+    /// Default: `false`.
+    #[inline]
+    pub fn mask_bounds(mut self, on: bool) -> Self {
+        self.mask_bounds = on;
+        self
+    }
+
+    /// Bridge the gaps of a broken line (missing or masked-out samples) with a dashed
+    /// connector from the end of one run to the start of the next, so the trend stays easy
+    /// to follow. Breaks of a segmented X axis are not bridged.
+    ///
+    /// A transparent stroke color uses the line color. Default: `None` (gaps stay open).
+    #[inline]
+    pub fn gap_bridge(mut self, stroke: Option<Stroke>) -> Self {
+        self.gap_bridge = stroke;
+        self
+    }
+
+    /// When there are more visible samples than the plot has pixels, draw only the lowest
+    /// and highest sample of each pixel column (see [`ColumnarSeries::decimate_minmax`]).
+    /// Spikes and gaps are preserved. Default: `false`.
+    ///
+    /// Applies to [`ColumnarSeries`]-backed lines without a mask or blocks. Markers are
+    /// drawn at the kept samples only.
+    #[inline]
+    pub fn downsample(mut self, on: bool) -> Self {
+        self.downsample = on;
+        self
+    }
+
+    /// Reduce the samples before drawing, e.g. `Simplify::Lttb(2_000)` for a long time
+    /// series that must keep its visual shape. Takes precedence over [`Self::downsample`].
+    ///
+    /// Like [`Self::downsample`], applies to [`ColumnarSeries`]-backed lines without a mask.
+    /// The tooltip and bounds keep full resolution. Default: [`Simplify::None`].
+    #[inline]
+    pub fn simplify(mut self, simplify: Simplify) -> Self {
+        self.simplify = simplify;
+        self
+    }
+
     builder_methods_for_base!();
 }
 
@@ -672,9 +1046,59 @@ fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
         .then_some(((y * (p1.x - p2.x)) - (p1.x * p2.y - p1.y * p2.x)) / (p1.y - p2.y))
 }
 
-impl PlotItem for Line<'_> {
+/// Screen y of a fill baseline, kept just outside the frame when the baseline is off-screen.
+///
+/// Far-away baselines would otherwise produce huge mesh coordinates; the part of the fill
+/// beyond the frame is clipped away anyway.
+fn fill_baseline_px(transform: &PlotTransform, y_reference: f32) -> f32 {
+    let frame = transform.frame();
+    let y = transform
+        .position_from_point(&PlotPoint::new(0.0, y_reference))
+        .y;
+    y.clamp(frame.top() - 1.0, frame.bottom() + 1.0)
+}
+
+/// The stroke of a [`Line::colors`] line through the `(index, sample)` pairs: each segment is a
+/// two-color gradient, stepped like the plain stroke.
+///
+/// Indices past the end of `colors` reuse its last color. Step corners take the color of the
+/// sample whose value they carry.
+fn colored_stroke(
+    samples: impl Iterator<Item = (usize, PlotPoint)>,
+    colors: &[Color32],
+    step: StepMode,
+    transform: &PlotTransform,
+    width: f32,
+) -> Shape {
+    let color_at = |i: usize| colors.get(i).or(colors.last()).copied().unwrap_or_default();
+    let mut points: Vec<Pos2> = Vec::new();
+    let mut point_colors: Vec<Color32> = Vec::new();
+    let mut prev: Option<(PlotPoint, Color32)> = None;
+    for (i, p) in samples {
+        let c = color_at(i);
+        if let Some((a, ca)) = prev {
+            let mut corners = geom_helpers::step_points([a, p].into_iter(), step)
+                .skip(1)
+                .peekable();
+            while let Some(corner) = corners.next() {
+                let holds_a = corners.peek().is_some() && corner.y == a.y;
+                points.push(transform.position_from_point(&corner));
+                point_colors.push(if holds_a { ca } else { c });
+            }
+        } else {
+            points.push(transform.position_from_point(&p));
+            point_colors.push(c);
+        }
+        prev = Some((p, c));
+    }
+    let mut mesh = Mesh::default();
+    geom_helpers::push_gradient_polyline(&mut mesh, &points, &point_colors, width);
+    Shape::mesh(mesh)
+}
+
+impl Line<'_> {
     #[allow(clippy::too_many_lines)]
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    fn line_shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             base,
             columnar,
@@ -685,9 +1109,11 @@ impl PlotItem for Line<'_> {
             gradient_color,
             gradient_fill,
             style,
+            step,
             blocks_xy,
             ..
         } = self;
+        let columnar = columnar.as_ref().map(Columns::view);
 
         let mut fill = *fill;
 
@@ -700,10 +1126,12 @@ impl PlotItem for Line<'_> {
                 move |_r: Rect, p: Pos2| -> Color32 { callback(local_tf.value_from_position(p)) };
             final_stroke = PathStroke::new_uv(stroke.width, wrapped);
         }
+        let highlight = base.highlight_amount();
+        let colored_width = (1.0 + highlight) * stroke.width;
 
         // small local helper to draw a single marker at a screen position with a resolved color
         let draw_one_marker =
-            |marker: &Marker, pos: Pos2, color: Color32, highlight: bool, out: &mut Vec<Shape>| {
+            |marker: &Marker, pos: Pos2, color: Color32, highlight: f32, out: &mut Vec<Shape>| {
                 let sqrt_3 = 3f32.sqrt();
                 let frac_sqrt_3_2 = sqrt_3 / 2.0;
                 let frac_1_sqrt_2 = 1.0 / 2f32.sqrt();
@@ -720,9 +1148,7 @@ impl PlotItem for Line<'_> {
                     )
                 };
 
-                if highlight {
-                    radius *= 2f32.sqrt();
-                }
+                radius *= lerp(1.0..=2f32.sqrt(), highlight);
 
                 let tf = |dx: f32, dy: f32| -> Pos2 { pos + radius * vec2(dx, dy) };
 
@@ -829,35 +1255,42 @@ impl PlotItem for Line<'_> {
             };
 
         if let Some(blocks) = blocks_xy {
-            let mut draw_one_block = |xs: &[f64], ys: &[f64]| {
+            let mut draw_one_block = |first: usize, xs: &[f64], ys: &[f64]| {
                 let len = xs.len().min(ys.len());
 
                 let get_pos = |i: usize| {
                     let v = PlotPoint { x: xs[i], y: ys[i] };
                     transform.position_from_point(&v)
                 };
+                let stepped = || {
+                    geom_helpers::step_points(
+                        (0..len).map(move |i| PlotPoint::new(xs[i], ys[i])),
+                        *step,
+                    )
+                    .map(move |p| transform.position_from_point(&p))
+                };
 
                 if len >= 2 {
                     if let Some(y_ref) = fill {
                         let mut fill_alpha = *self_fill_alpha;
-                        if base.highlight {
-                            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
-                        }
-                        let y_line = transform.position_from_point(&PlotPoint::new(0.0, y_ref)).y;
+                        fill_alpha = lerp(fill_alpha..=(2.0 * fill_alpha).at_most(1.0), highlight);
+                        let y_line = fill_baseline_px(transform, y_ref);
 
-                        let mut fill_color: Color32 = Rgba::from(stroke.color)
-                            .to_opaque()
-                            .multiply(fill_alpha)
-                            .into();
+                        let mut fill_color: Color32 = self.fill_color.unwrap_or_else(|| {
+                            Rgba::from(stroke.color)
+                                .to_opaque()
+                                .multiply(fill_alpha)
+                                .into()
+                        });
 
                         let mut mesh = Mesh::default();
                         let expected_intersections = 20;
                         mesh.reserve_triangles(len.saturating_sub(1) * 2);
                         mesh.reserve_vertices(len * 2 + expected_intersections);
 
-                        let mut p0 = get_pos(0);
-                        for i in 0..(len - 1) {
-                            let p1 = get_pos(i + 1);
+                        let mut pts = stepped();
+                        let mut p0 = pts.next().unwrap_or_else(|| get_pos(0));
+                        for p1 in pts {
                             #[allow(clippy::excessive_nesting)]
                             if *gradient_fill {
                                 if let Some(grad) = gradient_color.as_ref() {
@@ -900,12 +1333,20 @@ impl PlotItem for Line<'_> {
                     let draw_stroke = final_stroke.width > 0.0
                         && final_stroke.color
                             != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
-                    if draw_stroke {
+                    if let Some(colors) = self.colors {
+                        shapes.push(colored_stroke(
+                            (0..len).map(|i| (first + i, PlotPoint::new(xs[i], ys[i]))),
+                            colors,
+                            *step,
+                            transform,
+                            colored_width,
+                        ));
+                    } else if draw_stroke {
                         let mut scratch: Vec<Pos2> = Vec::new();
                         style.style_line_iter(
-                            (0..len).map(get_pos),
+                            geom_helpers::simplify_px(stepped(), self.simplify_px),
                             final_stroke.clone(),
-                            base.highlight,
+                            highlight,
                             shapes,
                             &mut scratch,
                         );
@@ -926,14 +1367,16 @@ impl PlotItem for Line<'_> {
                                 pp,
                                 gradient_color.as_ref(),
                             );
-                            draw_one_marker(marker, pos, color, base.highlight, shapes);
+                            draw_one_marker(marker, pos, color, highlight, shapes);
                         }
                     }
                 }
             };
 
+            let mut first = 0;
             for (xs, ys) in blocks.xs.iter().zip(&blocks.ys) {
-                draw_one_block(xs, ys);
+                draw_one_block(first, xs, ys);
+                first += xs.len().min(ys.len());
             }
             return;
         }
@@ -943,7 +1386,26 @@ impl PlotItem for Line<'_> {
             Legacy { pts: &'a [PlotPoint] },
             Empty,
         }
-        let src = if let Some(cs) = columnar {
+        let bounds = transform.bounds();
+        let decimated: Option<(Vec<f64>, Vec<f64>)> = columnar
+            .filter(|_| self.mask.is_none() && self.colors.is_none())
+            .and_then(|cs| {
+                if let Simplify::Lttb(threshold) = self.simplify {
+                    if threshold < cs.len() {
+                        return Some(cs.lttb(threshold));
+                    }
+                }
+                if !self.downsample {
+                    return None;
+                }
+                let x_range = (bounds.min()[0], bounds.max()[0]);
+                let indices =
+                    cs.downsample_indices(self.sorted_x(), x_range, transform.frame().width())?;
+                Some(indices.iter().map(|&i| (cs.xs()[i], cs.ys()[i])).unzip())
+            });
+        let src = if let Some((xs, ys)) = &decimated {
+            Src::Col { xs, ys }
+        } else if let Some(cs) = columnar {
             Src::Col {
                 xs: cs.xs(),
                 ys: cs.ys(),
@@ -984,8 +1446,43 @@ impl PlotItem for Line<'_> {
         if len < 2 {
             fill = None;
         }
-        // segmented part here
-        if let Some(bx) = transform.segment_xaxis() {
+        let sample = |i: usize| -> PlotPoint {
+            match src {
+                Src::Col { xs, ys } => PlotPoint { x: xs[i], y: ys[i] },
+                Src::Legacy { pts } => pts[i],
+                Src::Empty => unreachable!(),
+            }
+        };
+        // screen positions of `i0..=i1`, with the step corners inserted
+        let stepped = |i0: usize, i1: usize| {
+            geom_helpers::step_points((i0..=i1).map(sample), *step)
+                .map(move |p| transform.position_from_point(&p))
+        };
+        let visible = |i: usize| self.mask.is_none_or(|m| m.get(i).copied().unwrap_or(true));
+        // dashed connector across the gap between the run ending at `end` and the next one
+        let draw_bridge = |end: Option<usize>, start: usize, shapes: &mut Vec<Shape>| {
+            let (Some(bridge), Some(end)) = (self.gap_bridge, end) else {
+                return;
+            };
+            let color = if bridge.color == Color32::TRANSPARENT {
+                stroke.color
+            } else {
+                bridge.color
+            };
+            shapes.extend(Shape::dashed_line(
+                &[get_pos(end), get_pos(start)],
+                Stroke::new(bridge.width, color),
+                4.0 * bridge.width.max(1.0),
+                3.0 * bridge.width.max(1.0),
+            ));
+        };
+        // segmented part here; a mask is drawn the same way, as runs inside one whole segment
+        let whole = [Segment::linear(Interval::all())];
+        let segments = match transform.segment_xaxis() {
+            Some(bx) => Some(&bx.segments[..]),
+            None => self.mask.map(|_| &whole[..]),
+        };
+        if let Some(segments) = segments {
             // 1) helper: draw the fill for a run
             let draw_fill_for_run = |i0: usize, i1: usize, shapes: &mut Vec<Shape>| {
                 if i1 < i0 {
@@ -998,28 +1495,25 @@ impl PlotItem for Line<'_> {
 
                 if let Some(y_reference) = fill {
                     let mut fill_alpha = *self_fill_alpha;
-                    if base.highlight {
-                        fill_alpha = (2.0 * fill_alpha).at_most(1.0);
-                    }
+                    fill_alpha = lerp(fill_alpha..=(2.0 * fill_alpha).at_most(1.0), highlight);
 
-                    let y_line = transform
-                        .position_from_point(&PlotPoint::new(0.0, y_reference))
-                        .y;
+                    let y_line = fill_baseline_px(transform, y_reference);
 
-                    let mut fill_color: Color32 = Rgba::from(stroke.color)
-                        .to_opaque()
-                        .multiply(fill_alpha)
-                        .into();
+                    let mut fill_color: Color32 = self.fill_color.unwrap_or_else(|| {
+                        Rgba::from(stroke.color)
+                            .to_opaque()
+                            .multiply(fill_alpha)
+                            .into()
+                    });
 
                     let mut mesh = Mesh::default();
                     let expected_intersections = 20;
                     mesh.reserve_triangles(chunk_len.saturating_sub(1) * 2);
                     mesh.reserve_vertices(chunk_len * 2 + expected_intersections);
 
-                    let mut p0 = get_pos(i0);
-                    for idx in i0..i1 {
-                        let p1 = get_pos(idx + 1);
-
+                    let mut pts = stepped(i0, i1);
+                    let mut p0 = pts.next().unwrap_or_else(|| get_pos(i0));
+                    for p1 in pts {
                         if *gradient_fill {
                             if let Some(grad) = gradient_color.as_ref() {
                                 fill_color = Rgba::from(grad(transform.value_from_position(p1)))
@@ -1069,12 +1563,20 @@ impl PlotItem for Line<'_> {
                 let draw_stroke = final_stroke.width > 0.0
                     && final_stroke.color != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
 
-                if draw_stroke {
+                if let Some(colors) = self.colors {
+                    shapes.push(colored_stroke(
+                        (i0..=i1).map(|i| (i, sample(i))),
+                        colors,
+                        *step,
+                        transform,
+                        colored_width,
+                    ));
+                } else if draw_stroke {
                     let mut scratch: Vec<Pos2> = Vec::new();
                     style.style_line_iter(
-                        (i0..=i1).map(&get_pos),
+                        geom_helpers::simplify_px(stepped(i0, i1), self.simplify_px),
                         final_stroke.clone(),
-                        base.highlight,
+                        highlight,
                         shapes,
                         &mut scratch,
                     );
@@ -1104,7 +1606,7 @@ impl PlotItem for Line<'_> {
                                 pp,
                                 gradient_color.as_ref(),
                             );
-                            draw_one_marker(marker, pos, color, base.highlight, shapes);
+                            draw_one_marker(marker, pos, color, highlight, shapes);
                         }
                     }
                     Src::Legacy { pts } => {
@@ -1116,7 +1618,7 @@ impl PlotItem for Line<'_> {
                                 pp,
                                 gradient_color.as_ref(),
                             );
-                            draw_one_marker(marker, pos, color, base.highlight, shapes);
+                            draw_one_marker(marker, pos, color, highlight, shapes);
                         }
                     }
                     Src::Empty => {}
@@ -1134,38 +1636,16 @@ impl PlotItem for Line<'_> {
             };
 
             // 5) walk segments
-            for seg in &bx.segments {
-                let mut run_start: Option<usize> = None;
-                let mut last_in: usize = 0;
-
-                for i in 0..len {
-                    let (x_val, finite_ok) = match src {
-                        Src::Col { xs, ys } => {
-                            let x = xs[i];
-                            let y = ys[i];
-                            (x, x.is_finite() && y.is_finite())
-                        }
-                        Src::Legacy { pts } => {
-                            let p = pts[i];
-                            (p.x, p.x.is_finite() && p.y.is_finite())
-                        }
-                        Src::Empty => unreachable!(),
-                    };
-
-                    let inside = finite_ok && seg.contains(x_val);
-
-                    if inside {
-                        if run_start.is_none() {
-                            run_start = Some(i);
-                        }
-                        last_in = i;
-                    } else if let Some(s0) = run_start.take() {
-                        draw_run(s0, last_in, shapes);
-                    }
-                }
-
-                if let Some(s0) = run_start.take() {
-                    draw_run(s0, last_in, shapes);
+            for seg in segments {
+                let inside = |i: usize| {
+                    let p = sample(i);
+                    p.x.is_finite() && p.y.is_finite() && seg.interval.contains(p.x) && visible(i)
+                };
+                let mut prev_end: Option<usize> = None;
+                for (i0, i1) in geom_helpers::iter_runs(len, inside) {
+                    draw_bridge(prev_end, i0, shapes);
+                    draw_run(i0, i1, shapes);
+                    prev_end = Some(i1);
                 }
             }
 
@@ -1173,77 +1653,98 @@ impl PlotItem for Line<'_> {
         }
         //return;
 
-        if let Some(y_reference) = fill {
-            let mut fill_alpha = *self_fill_alpha;
-            if base.highlight {
-                fill_alpha = (2.0 * fill_alpha).at_most(1.0);
-            }
-            let y_line = transform
-                .position_from_point(&PlotPoint::new(0.0, y_reference))
-                .y;
-
-            let mut fill_color: Color32 = Rgba::from(stroke.color)
-                .to_opaque()
-                .multiply(fill_alpha)
-                .into();
-
-            let mut mesh = Mesh::default();
-            let expected_intersections = 20;
-            mesh.reserve_triangles(len.saturating_sub(1) * 2);
-            mesh.reserve_vertices(len * 2 + expected_intersections);
-
-            let mut p0 = get_pos(0);
-            for i in 0..(len - 1) {
-                let p1 = get_pos(i + 1);
-
-                if *gradient_fill {
-                    if let Some(grad) = gradient_color.as_ref() {
-                        fill_color = Rgba::from(grad(transform.value_from_position(p1)))
-                            .to_opaque()
-                            .multiply(fill_alpha)
-                            .into();
+        // Missing (non-finite) samples break the line: each run between them is filled and
+        // stroked on its own, so no path or mesh gets a non-finite vertex.
+        let finite = |i: usize| {
+            let p = sample(i);
+            p.x.is_finite() && p.y.is_finite()
+        };
+        let mut runs = geom_helpers::iter_runs(len, finite).peekable();
+        if runs.peek().is_none() {
+            return;
+        }
+        let draw_stroke = final_stroke.width > 0.0
+            && final_stroke.color != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
+        let mut prev_end: Option<usize> = None;
+        for (first, last) in runs {
+            draw_bridge(prev_end, first, shapes);
+            prev_end = Some(last);
+            if let Some(y_reference) = fill.filter(|_| last > first) {
+                let mut fill_alpha = *self_fill_alpha;
+                fill_alpha = lerp(fill_alpha..=(2.0 * fill_alpha).at_most(1.0), highlight);
+                let y_line = fill_baseline_px(transform, y_reference);
+
+                let mut fill_color: Color32 = self.fill_color.unwrap_or_else(|| {
+                    Rgba::from(stroke.color)
+                        .to_opaque()
+                        .multiply(fill_alpha)
+                        .into()
+                });
+
+                let chunk_len = last + 1 - first;
+                let mut mesh = Mesh::default();
+                let expected_intersections = 20;
+                mesh.reserve_triangles(chunk_len.saturating_sub(1) * 2);
+                mesh.reserve_vertices(chunk_len * 2 + expected_intersections);
+
+                let mut pts = stepped(first, last);
+                let mut p0 = pts.next().unwrap_or_else(|| get_pos(first));
+                for p1 in pts {
+                    if *gradient_fill {
+                        if let Some(grad) = gradient_color.as_ref() {
+                            fill_color = Rgba::from(grad(transform.value_from_position(p1)))
+                                .to_opaque()
+                                .multiply(fill_alpha)
+                                .into();
+                        }
                     }
-                }
 
-                let base_idx = mesh.vertices.len() as u32;
-                mesh.colored_vertex(p0, fill_color);
-                mesh.colored_vertex(pos2(p0.x, y_line), fill_color);
-
-                if let Some(xi) = y_intersection(&p0, &p1, y_line) {
-                    let xp = pos2(xi, y_line);
-                    mesh.colored_vertex(xp, fill_color);
-                    mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
-                    mesh.colored_vertex(pos2(p1.x, y_line), fill_color);
-                    mesh.colored_vertex(p1, fill_color);
-                    mesh.add_triangle(base_idx + 2, base_idx + 3, base_idx + 4);
-                } else {
-                    mesh.colored_vertex(p1, fill_color);
-                    mesh.colored_vertex(pos2(p1.x, y_line), fill_color);
-                    mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
-                    mesh.add_triangle(base_idx + 1, base_idx + 2, base_idx + 3);
-                }
+                    let base_idx = mesh.vertices.len() as u32;
+                    mesh.colored_vertex(p0, fill_color);
+                    mesh.colored_vertex(pos2(p0.x, y_line), fill_color);
+
+                    if let Some(xi) = y_intersection(&p0, &p1, y_line) {
+                        let xp = pos2(xi, y_line);
+                        mesh.colored_vertex(xp, fill_color);
+                        mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
+                        mesh.colored_vertex(pos2(p1.x, y_line), fill_color);
+                        mesh.colored_vertex(p1, fill_color);
+                        mesh.add_triangle(base_idx + 2, base_idx + 3, base_idx + 4);
+                    } else {
+                        mesh.colored_vertex(p1, fill_color);
+                        mesh.colored_vertex(pos2(p1.x, y_line), fill_color);
+                        mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
+                        mesh.add_triangle(base_idx + 1, base_idx + 2, base_idx + 3);
+                    }
 
-                p0 = p1;
-            }
+                    p0 = p1;
+                }
 
-            let last = get_pos(len - 1);
-            mesh.colored_vertex(last, fill_color);
-            mesh.colored_vertex(pos2(last.x, y_line), fill_color);
+                let last = get_pos(last);
+                mesh.colored_vertex(last, fill_color);
+                mesh.colored_vertex(pos2(last.x, y_line), fill_color);
 
-            shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
-        }
+                shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
+            }
 
-        let draw_stroke = final_stroke.width > 0.0
-            && final_stroke.color != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
-        if draw_stroke {
-            let mut scratch: Vec<Pos2> = Vec::new();
-            style.style_line_iter(
-                (0..len).map(get_pos),
-                final_stroke,
-                base.highlight,
-                shapes,
-                &mut scratch,
-            );
+            if let Some(colors) = self.colors {
+                shapes.push(colored_stroke(
+                    (first..=last).map(|i| (i, sample(i))),
+                    colors,
+                    *step,
+                    transform,
+                    colored_width,
+                ));
+            } else if draw_stroke {
+                let mut scratch: Vec<Pos2> = Vec::new();
+                style.style_line_iter(
+                    geom_helpers::simplify_px(stepped(first, last), self.simplify_px),
+                    final_stroke.clone(),
+                    highlight,
+                    shapes,
+                    &mut scratch,
+                );
+            }
         }
 
         if let Some(marker) = &self.markers {
@@ -1264,7 +1765,7 @@ impl PlotItem for Line<'_> {
                             pp,
                             gradient_color.as_ref(),
                         );
-                        draw_one_marker(marker, pos, color, base.highlight, shapes);
+                        draw_one_marker(marker, pos, color, highlight, shapes);
                     }
                 }
                 Src::Legacy { pts } => {
@@ -1276,7 +1777,7 @@ impl PlotItem for Line<'_> {
                             pp,
                             gradient_color.as_ref(),
                         );
-                        draw_one_marker(marker, pos, color, base.highlight, shapes);
+                        draw_one_marker(marker, pos, color, highlight, shapes);
                     }
                 }
 
@@ -1285,6 +1786,72 @@ impl PlotItem for Line<'_> {
         }
     }
 
+    /// The [`Self::mark_last`] marker and its label.
+    fn last_sample_shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Some(shape) = self.mark_last else {
+            return;
+        };
+        let finite = |p: &PlotPoint| p.x.is_finite() && p.y.is_finite();
+        let last = match self.geometry() {
+            PlotGeometry::Points(points) => points.iter().rev().copied().find(finite),
+            PlotGeometry::PointsXY { xs, ys, .. } => xs
+                .iter()
+                .zip(ys)
+                .rev()
+                .map(|(&x, &y)| PlotPoint { x, y })
+                .find(finite),
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => xs_blocks.iter().zip(&ys_blocks).rev().find_map(|(xs, ys)| {
+                xs.iter()
+                    .zip(ys.iter())
+                    .rev()
+                    .map(|(&x, &y)| PlotPoint { x, y })
+                    .find(finite)
+            }),
+            PlotGeometry::Rects | PlotGeometry::None => None,
+        };
+        let Some(last) = last else {
+            return;
+        };
+
+        let color = if self.stroke.color == Color32::TRANSPARENT {
+            ui.visuals().text_color()
+        } else {
+            self.stroke.color
+        };
+        let radius = (2.0 * self.stroke.width).at_least(3.0);
+        let mut marker = Points::new("", vec![[last.x, last.y]])
+            .shape(shape)
+            .radius(radius)
+            .color(color)
+            .filled(true);
+        marker.base.highlight = self.base.highlight;
+        marker.base.highlight_amount = self.base.highlight_amount;
+        marker.shapes(ui, transform, shapes);
+
+        if self.mark_last_label {
+            let text = self
+                .number_format
+                .localize(&emath::format_with_decimals_in_range(
+                    last.y - self.y_offset,
+                    0..=6,
+                ));
+            let pos = transform.position_from_point(&last) + vec2(radius + 4.0, 0.0);
+            let font_id = TextStyle::Small.resolve(ui.style());
+            shapes
+                .push(ui.fonts(|f| Shape::text(f, pos, Align2::LEFT_CENTER, text, font_id, color)));
+        }
+    }
+}
+
+impl PlotItem for Line<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.line_shapes(ui, transform, shapes);
+        self.last_sample_shapes(ui, transform, shapes);
+    }
+
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
         if let Some(series) = &mut self.series {
             series.generate_points(x_range);
@@ -1309,7 +1876,7 @@ impl PlotItem for Line<'_> {
                 xs_blocks: b.xs.clone(),
                 ys_blocks: b.ys.clone(),
             }
-        } else if let Some(cs) = &self.columnar {
+        } else if let Some(cs) = self.columnar.as_ref().map(Columns::view) {
             PlotGeometry::PointsXY {
                 xs: cs.xs(),
                 ys: cs.ys(),
@@ -1322,7 +1889,7 @@ impl PlotItem for Line<'_> {
     }
 
     fn bounds(&self) -> PlotBounds {
-        if let Some(b) = &self.blocks_xy {
+        let mut bounds = if let Some(b) = &self.blocks_xy {
             let mut out = PlotBounds::NOTHING;
             for (xs, ys) in b.xs.iter().zip(&b.ys) {
                 let cs = ColumnarSeries::new_truncating(xs, ys);
@@ -1332,15 +1899,73 @@ impl PlotItem for Line<'_> {
                 out.extend_with_y(b.min()[1]);
                 out.extend_with_y(b.max()[1]);
             }
-            return out;
-        }
-        if let Some(cs) = &self.columnar {
-            cs.bounds()
+            out
+        } else if let Some(mask) = self.mask.filter(|_| self.mask_bounds) {
+            let mut out = PlotBounds::NOTHING;
+            let mut extend = |i: usize, p: PlotPoint| {
+                if mask.get(i).copied().unwrap_or(true) && p.x.is_finite() && p.y.is_finite() {
+                    out.extend_with(&p);
+                }
+            };
+            match self.geometry() {
+                PlotGeometry::Points(points) => {
+                    for (i, &p) in points.iter().enumerate() {
+                        extend(i, p);
+                    }
+                }
+                PlotGeometry::PointsXY { xs, ys, .. } => {
+                    for (i, (&x, &y)) in xs.iter().zip(ys).enumerate() {
+                        extend(i, PlotPoint::new(x, y));
+                    }
+                }
+                PlotGeometry::BlocksXY { .. } | PlotGeometry::Rects | PlotGeometry::None => {}
+            }
+            out
+        } else if let Some(cs) = &self.columnar {
+            cs.view().bounds()
         } else if let Some(series) = &self.series {
             series.bounds()
         } else {
             PlotBounds::NOTHING
+        };
+
+        if let Some((low, high)) = self.autoscale_quantile {
+            let mut ys: Vec<f64> = match self.geometry() {
+                PlotGeometry::Points(points) => points.iter().map(|p| p.y).collect(),
+                PlotGeometry::PointsXY { ys, .. } => ys.to_vec(),
+                PlotGeometry::BlocksXY { ys_blocks, .. } => ys_blocks.concat(),
+                PlotGeometry::Rects | PlotGeometry::None => Vec::new(),
+            };
+            if let Some(mask) = self
+                .mask
+                .filter(|_| self.mask_bounds && self.blocks_xy.is_none())
+            {
+                let mut i = 0;
+                ys.retain(|_| {
+                    i += 1;
+                    mask.get(i - 1).copied().unwrap_or(true)
+                });
+            }
+            ys.retain(|y| y.is_finite());
+            if let Some((y_min, y_max)) = geom_helpers::quantile_range(&mut ys, low, high) {
+                bounds.min[1] = y_min;
+                bounds.max[1] = y_max;
+            }
         }
+        bounds
+    }
+
+    fn y_offset(&self) -> f64 {
+        self.y_offset
+    }
+
+    fn sorted_x(&self) -> bool {
+        self.assume_sorted_x
+            || *self.sorted_x.get_or_init(|| {
+                self.columnar
+                    .as_ref()
+                    .is_some_and(|cs| cs.view().xs().is_sorted())
+            })
     }
 }
 
@@ -1424,7 +2049,7 @@ impl PlotItem for Polygon<'_> {
         style.style_line(
             values_tf,
             PathStroke::new(stroke.width, stroke.color),
-            base.highlight,
+            base.highlight_amount(),
             shapes,
         );
     }
@@ -1512,11 +2137,12 @@ impl PlotItem for Text {
 
         shapes.push(TextShape::new(rect.min, galley, color).into());
 
-        if self.base.highlight {
+        let highlight = self.base.highlight_amount();
+        if highlight > 0.0 {
             shapes.push(Shape::rect_stroke(
                 rect.expand(1.0),
                 1.0,
-                Stroke::new(0.5, color),
+                Stroke::new(0.5, color.gamma_multiply(highlight)),
                 egui::StrokeKind::Outside,
             ));
         }
@@ -1648,10 +2274,9 @@ impl PlotItem for Points<'_> {
             (Color32::TRANSPARENT, default_stroke)
         };
 
-        if base.highlight {
-            radius *= 2f32.sqrt();
-            stem_stroke.width *= 2.0;
-        }
+        let highlight = base.highlight_amount();
+        radius *= lerp(1.0..=2f32.sqrt(), highlight);
+        stem_stroke.width *= 1.0 + highlight;
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
@@ -1777,6 +2402,10 @@ impl PlotItem for Points<'_> {
     fn base_mut(&mut self) -> &mut PlotItemBase {
         &mut self.base
     }
+
+    fn marker_radius(&self, _index: usize) -> f32 {
+        self.radius
+    }
 }
 
 /// A set of arrows.
@@ -1830,7 +2459,7 @@ impl PlotItem for Arrows<'_> {
             base,
             ..
         } = self;
-        let stroke = Stroke::new(if base.highlight { 2.0 } else { 1.0 }, *color);
+        let stroke = Stroke::new(1.0 + base.highlight_amount(), *color);
         origins
             .points()
             .iter()
@@ -1994,7 +2623,8 @@ impl PlotItem for PlotImage {
             },
             &(*texture_id, image_screen_rect.size()).into(),
         );
-        if base.highlight {
+        let highlight = base.highlight_amount();
+        if highlight > 0.0 {
             let center = image_screen_rect.center();
             let rotation = Rot2::from_angle(screen_rotation);
             let outline = [
@@ -2008,7 +2638,10 @@ impl PlotItem for PlotImage {
             .collect();
             shapes.push(Shape::closed_line(
                 outline,
-                Stroke::new(1.0, ui.visuals().strong_text_color()),
+                Stroke::new(
+                    1.0,
+                    ui.visuals().strong_text_color().gamma_multiply(highlight),
+                ),
             ));
         }
     }
@@ -2156,7 +2789,7 @@ impl BarChart {
 impl PlotItem for BarChart {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         for b in &self.bars {
-            b.add_shapes(transform, self.base.highlight, shapes);
+            b.add_shapes(transform, self.base.highlight_amount(), shapes);
         }
     }
 
@@ -2195,7 +2828,7 @@ impl PlotItem for BarChart {
     ) {
         let bar = &self.bars[elem.index];
 
-        bar.add_shapes(plot.transform, true, shapes);
+        bar.add_shapes(plot.transform, 1.0, shapes);
         bar.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
@@ -2283,7 +2916,7 @@ impl BoxPlot {
 impl PlotItem for BoxPlot {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         for b in &self.boxes {
-            b.add_shapes(transform, self.base.highlight, shapes);
+            b.add_shapes(transform, self.base.highlight_amount(), shapes);
         }
     }
 
@@ -2322,7 +2955,7 @@ impl PlotItem for BoxPlot {
     ) {
         let box_plot = &self.boxes[elem.index];
 
-        box_plot.add_shapes(plot.transform, true, shapes);
+        box_plot.add_shapes(plot.transform, 1.0, shapes);
         box_plot.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
@@ -2518,3 +3151,168 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_autoscale_quantile() {
+        let xs: Vec<f64> = (0..101).map(f64::from).collect();
+        let mut ys = xs.clone();
+        ys[50] = 1e6;
+        let line = Line::new_xy("", &xs, &ys);
+        assert_eq!(line.bounds().max[1], 1e6);
+        let line = Line::new_xy("", &xs, &ys).autoscale_quantile(Some((0.01, 0.99)));
+        let bounds = line.bounds();
+        assert_eq!(bounds.min[1], 1.0);
+        assert_eq!(bounds.max[1], 100.0);
+        assert_eq!(bounds.max[0], 100.0);
+    }
+
+    #[test]
+    fn test_line_mask_bounds() {
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let ys = [1.0, 50.0, 2.0, 3.0];
+        let mask = [true, false];
+        let line = Line::new_xy("", &xs, &ys).with_mask(&mask);
+        assert_eq!(line.bounds().max[1], 50.0);
+        let line = Line::new_xy("", &xs, &ys)
+            .with_mask(&mask)
+            .mask_bounds(true);
+        assert_eq!(line.bounds().max[1], 3.0);
+        assert_eq!(line.bounds().max[0], 3.0);
+    }
+
+    #[test]
+    fn test_line_gaps_at_first_and_last_sample() {
+        use crate::{SegmentedAxis, run_headless_frame};
+
+        let xs: Vec<f64> = (0..=10).map(f64::from).collect();
+        let mut ys = vec![1.0; xs.len()];
+        ys[0] = f64::NAN;
+        ys[10] = f64::NAN;
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 2.0]),
+            false,
+        );
+        // The stroke paths drawn for `ys` under `transform`, as screen X of their ends.
+        let path_ends = |transform: &PlotTransform| {
+            let mut shapes = Vec::new();
+            run_headless_frame(&egui::Context::default(), Vec::new(), |ui| {
+                Line::new_xy("", &xs, &ys)
+                    .color(Color32::RED)
+                    .fill(0.0)
+                    .shapes(ui, transform, &mut shapes);
+            });
+            let mut ends = Vec::new();
+            for shape in &shapes {
+                match shape {
+                    Shape::Path(path) => {
+                        assert!(
+                            path.points
+                                .iter()
+                                .all(|p| p.x.is_finite() && p.y.is_finite())
+                        );
+                        ends.push((path.points[0].x, path.points[path.points.len() - 1].x));
+                    }
+                    Shape::Mesh(mesh) => {
+                        assert!(mesh.vertices.iter().all(|v| v.pos.x.is_finite()));
+                    }
+                    _ => {}
+                }
+            }
+            ends
+        };
+
+        assert_eq!(path_ends(&transform), [(10.0, 90.0)]);
+
+        let mut segmented = transform.clone();
+        segmented.set_segment_xaxis(Some(SegmentedAxis::new(
+            vec![Interval::new(0.0, 4.0), Interval::new(6.0, 10.0)],
+            0.0,
+        )));
+        assert_eq!(path_ends(&segmented), [(12.5, 50.0), (50.0, 87.5)]);
+    }
+
+    #[test]
+    fn test_line_breaks_at_interior_gap() {
+        use crate::run_headless_frame;
+
+        let xs: Vec<f64> = (0..=10).map(f64::from).collect();
+        let mut ys = vec![1.0; xs.len()];
+        ys[5] = f64::NAN;
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 2.0]),
+            false,
+        );
+        let mut shapes = Vec::new();
+        run_headless_frame(&egui::Context::default(), Vec::new(), |ui| {
+            Line::new_xy("", &xs, &ys)
+                .color(Color32::RED)
+                .fill(0.0)
+                .shapes(ui, &transform, &mut shapes);
+        });
+
+        let mut ends = Vec::new();
+        let mut meshes = 0;
+        for shape in &shapes {
+            match shape {
+                Shape::Path(path) => {
+                    assert!(path.points.iter().all(|p| p.is_finite()));
+                    ends.push((path.points[0].x, path.points[path.points.len() - 1].x));
+                }
+                Shape::Mesh(mesh) => {
+                    assert!(mesh.vertices.iter().all(|v| v.pos.is_finite()));
+                    meshes += 1;
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(ends, [(0.0, 40.0), (60.0, 100.0)]);
+        assert_eq!(meshes, 2, "one fill per run");
+    }
+
+    #[test]
+    fn test_line_gap_bridge() {
+        use crate::run_headless_frame;
+
+        let xs: Vec<f64> = (0..=10).map(f64::from).collect();
+        let mut ys = vec![1.0; xs.len()];
+        ys[5] = f64::NAN;
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 2.0]),
+            false,
+        );
+        let mut shapes = Vec::new();
+        run_headless_frame(&egui::Context::default(), Vec::new(), |ui| {
+            Line::new_xy("", &xs, &ys)
+                .color(Color32::RED)
+                .gap_bridge(Some(Stroke::new(1.0, Color32::BLUE)))
+                .shapes(ui, &transform, &mut shapes);
+        });
+
+        // The dashes run from the last sample before the gap (x = 4) to the first after it.
+        let dashes: Vec<[Pos2; 2]> = shapes
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::LineSegment { points, stroke } if stroke.color == Color32::BLUE => {
+                    Some(*points)
+                }
+                _ => None,
+            })
+            .collect();
+        assert!(dashes.len() > 1, "dashed, not solid");
+        assert_eq!(dashes[0][0], pos2(40.0, 50.0));
+        assert!((dashes[dashes.len() - 1][1].x - 60.0).abs() < 3.0);
+        assert!(
+            dashes
+                .iter()
+                .flatten()
+                .all(|p| (40.0..=60.0).contains(&p.x) && p.y == 50.0)
+        );
+    }
+}
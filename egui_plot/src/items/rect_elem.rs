@@ -56,18 +56,19 @@ pub(super) trait RectElement {
 // ----------------------------------------------------------------------------
 // Helper functions
 
-pub(super) fn highlighted_color(mut stroke: Stroke, fill: Color32) -> (Stroke, Color32) {
-    stroke.width *= 2.0;
+/// Stroke and fill eased towards their highlighted look by the highlight amount `t` in `0..=1`.
+pub(super) fn highlighted_color(mut stroke: Stroke, fill: Color32, t: f32) -> (Stroke, Color32) {
+    stroke.width *= 1.0 + t;
 
-    let mut fill = Rgba::from(fill);
-    if fill.is_additive() {
+    let normal = Rgba::from(fill);
+    let highlighted = if normal.is_additive() {
         // Make slightly brighter
-        fill = 1.3 * fill;
+        1.3 * normal
     } else {
         // Make more opaque:
-        let fill_alpha = (2.0 * fill.a()).at_most(1.0);
-        fill = fill.to_opaque().multiply(fill_alpha);
-    }
+        let fill_alpha = (2.0 * normal.a()).at_most(1.0);
+        normal.to_opaque().multiply(fill_alpha)
+    };
 
-    (stroke, fill.into())
+    (stroke, (normal * (1.0 - t) + highlighted * t).into())
 }
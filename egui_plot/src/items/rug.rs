@@ -0,0 +1,133 @@
+//! Rug plot item: short ticks along a plot edge, one per sample position.
+//!
+//! A lightweight density indicator, typically used next to a histogram or scatter.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Shape, Stroke, Ui, pos2};
+
+use super::{PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
+use crate::{Axis, PlotBounds, PlotTransform};
+
+/// Short marks anchored at a frame edge, one per position.
+///
+/// With [`Axis::X`] the ticks are vertical and sit on the bottom edge of the plot;
+/// with [`Axis::Y`] they are horizontal and sit on the left edge.
+pub struct Rug<'a> {
+    base: PlotItemBase,
+
+    /// Sample positions along `axis`, in plot coordinates.
+    pub(crate) positions: &'a [f64],
+
+    /// Which axis the positions are measured on.
+    axis: Axis,
+
+    /// Tick length, in screen pixels.
+    length: f32,
+
+    /// Tick stroke. A transparent color means the color is auto-assigned.
+    pub(crate) stroke: Stroke,
+}
+
+impl<'a> Rug<'a> {
+    pub fn new(name: impl Into<String>, positions: &'a [f64], axis: Axis) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            positions,
+            axis,
+            length: 8.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+        }
+    }
+
+    /// Tick length in pixels. Default: `8.0`.
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Tick color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Tick stroke width. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for Rug<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let frame = transform.frame();
+        let mut stroke = self.stroke;
+        stroke.width *= 1.0 + self.base.highlight_amount();
+
+        for &v in self.positions {
+            if !v.is_finite() {
+                continue;
+            }
+            match self.axis {
+                Axis::X => {
+                    let x = transform.position_from_point(&PlotPoint::new(v, 0.0)).x;
+                    if x < frame.left() || x > frame.right() {
+                        continue;
+                    }
+                    shapes.push(Shape::line_segment(
+                        [
+                            pos2(x, frame.bottom()),
+                            pos2(x, frame.bottom() - self.length),
+                        ],
+                        stroke,
+                    ));
+                }
+                Axis::Y => {
+                    let y = transform.position_from_point(&PlotPoint::new(0.0, v)).y;
+                    if y < frame.top() || y > frame.bottom() {
+                        continue;
+                    }
+                    shapes.push(Shape::line_segment(
+                        [pos2(frame.left(), y), pos2(frame.left() + self.length, y)],
+                        stroke,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut b = PlotBounds::NOTHING;
+        for &v in self.positions.iter().filter(|v| v.is_finite()) {
+            match self.axis {
+                Axis::X => b.extend_with_x(v),
+                Axis::Y => b.extend_with_y(v),
+            }
+        }
+        b
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
@@ -1,13 +1,17 @@
 //! scatter.rs – Zero-copy scatter plot API.
 
+use std::cell::OnceCell;
+
 use crate::{
-    MarkerShape, PlotBounds, PlotPoint, PlotTransform,
+    ColorMap, MarkerShape, PlotBounds, PlotPoint, PlotTransform,
     items::{
-        ColumnarSeries, PlotGeometry, PlotItem, PlotItemBase,
+        AsColumnar, ColumnarSeries, Columns, PlotGeometry, PlotItem, PlotItemBase,
         geom_helpers::{push_polygon_at, regular_ngon, star_ngon},
     },
 };
-use egui::{Color32, Pos2, Shape, Stroke, StrokeKind, Ui, Vec2, epaint::CircleShape, pos2, vec2};
+use egui::{
+    Color32, Id, Pos2, Shape, Stroke, StrokeKind, Ui, Vec2, epaint::CircleShape, pos2, vec2,
+};
 
 /// Per-series uniform marker style (presentation only).
 #[derive(Clone, Debug)]
@@ -57,6 +61,14 @@ impl Marker {
         self
     }
 }
+/// Scalars colored through a [`ColorMap`], see [`Scatter::color_values`].
+struct ColorValues<'a> {
+    values: &'a [f64],
+    map: ColorMap,
+    /// Finite min and max of `values`.
+    range: (f64, f64),
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ScatterEncodings<'a> {
     pub per_point_colors: Option<&'a [Color32]>,
@@ -65,20 +77,37 @@ pub struct ScatterEncodings<'a> {
 
 pub struct Scatter<'a> {
     base: PlotItemBase,
-    series: ColumnarSeries<'a>,
+    series: Columns<'a>,
     marker: Marker,
     enc: ScatterEncodings<'a>,
+    radii: Option<&'a [f64]>,
+    color_values: Option<ColorValues<'a>>,
+    nan_color: Color32,
     stems_y: Option<f32>,
+    skip_non_finite: bool,
+    connect: Option<Stroke>,
+    assume_sorted_x: bool,
+    /// Whether the X values are sorted, checked at most once per item.
+    sorted_x: OnceCell<bool>,
+    downsample: bool,
 }
 
 impl<'a> Scatter<'a> {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             base: PlotItemBase::new(name.into()),
-            series: ColumnarSeries::EMPTY,
+            series: Columns::Borrowed(ColumnarSeries::EMPTY),
             marker: Marker::default(),
             enc: ScatterEncodings::default(),
+            radii: None,
+            color_values: None,
+            nan_color: Color32::GRAY,
             stems_y: None,
+            skip_non_finite: true,
+            connect: None,
+            assume_sorted_x: false,
+            sorted_x: OnceCell::new(),
+            downsample: false,
         }
     }
 
@@ -87,9 +116,32 @@ impl<'a> Scatter<'a> {
         Self::new(name).series(series)
     }
 
+    /// Sets the id of this scatter.
+    ///
+    /// By default the id is determined from the name. Set it explicitly when several series
+    /// share a display name but must be told apart (legend, tooltip highlighting).
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base.id = id.into();
+        self
+    }
+
     #[inline]
     pub fn series(mut self, series: ColumnarSeries<'a>) -> Self {
-        self.series = series;
+        self.assume_sorted_x |= series.is_assumed_sorted();
+        self.series = Columns::Borrowed(series);
+        self
+    }
+
+    /// Plot any [`AsColumnar`] data, e.g. [`crate::ColumnarSeriesF32`].
+    ///
+    /// `f64` data is borrowed as in [`Self::series`]; other storage is widened to `f64` once,
+    /// here.
+    pub fn columnar(mut self, data: &impl AsColumnar<'a>) -> Self {
+        if let Some(series) = data.as_f64() {
+            return self.series(series);
+        }
+        self.series = Columns::from_data(data);
         self
     }
 
@@ -142,12 +194,83 @@ impl<'a> Scatter<'a> {
         self
     }
 
+    /// Marker radius of each point, in points, e.g. for a bubble chart.
+    ///
+    /// Points past the end of `radii` reuse its last value. Takes precedence over
+    /// [`Self::radius`] but not over [`Self::per_point_radii`]. Bounds are unaffected.
+    #[inline]
+    pub fn radii(mut self, radii: &'a [f64]) -> Self {
+        self.radii = Some(radii);
+        self
+    }
+
+    /// Color each point by its value through `map`, normalized to the min and max of `values`.
+    ///
+    /// Explicit [`Self::per_point_colors`] take precedence; points past the end of `values`
+    /// keep the marker color, and NaN values use [`Self::nan_color`].
+    pub fn color_values(mut self, values: &'a [f64], map: ColorMap) -> Self {
+        let range = values
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v), hi.max(v))
+            });
+        self.color_values = Some(ColorValues { values, map, range });
+        self
+    }
+
+    /// Color of points whose [`Self::color_values`] value is NaN. Default: gray.
+    #[inline]
+    pub fn nan_color(mut self, color: Color32) -> Self {
+        self.nan_color = color;
+        self
+    }
+
     #[inline]
     pub fn stems(mut self, y_reference: f32) -> Self {
         self.stems_y = Some(y_reference);
         self
     }
 
+    /// Skip samples with a non-finite (NaN/inf) coordinate. Default: `true`.
+    ///
+    /// When `false`, samples with a finite X but non-finite Y are drawn as hollow markers
+    /// pinned to the bottom edge of the plot, so missing values stay visible.
+    /// Samples with a non-finite X are always skipped.
+    #[inline]
+    pub fn skip_non_finite(mut self, skip: bool) -> Self {
+        self.skip_non_finite = skip;
+        self
+    }
+
+    /// Draw a polyline through the points in data order, beneath the markers.
+    ///
+    /// The line breaks at samples with a non-finite coordinate. A transparent stroke color
+    /// means the marker color is used. Default: `None`.
+    #[inline]
+    pub fn connect(mut self, stroke: Option<Stroke>) -> Self {
+        self.connect = stroke;
+        self
+    }
+
+    /// Promise that the X values are sorted ascending, so hover lookups can binary-search
+    /// without verifying the order first. Default: `false`.
+    #[inline]
+    pub fn assume_sorted_x(mut self, sorted: bool) -> Self {
+        self.assume_sorted_x = sorted;
+        self
+    }
+
+    /// When there are more visible points than the plot has pixels, draw only the lowest
+    /// and highest point of each pixel column (see [`ColumnarSeries::decimate_minmax`]).
+    /// Default: `false`.
+    #[inline]
+    pub fn downsample(mut self, on: bool) -> Self {
+        self.downsample = on;
+        self
+    }
+
     #[inline]
     fn resolve_color(&self, idx: usize, auto: Color32) -> Color32 {
         if let Some(colors) = self.enc.per_point_colors {
@@ -155,6 +278,16 @@ impl<'a> Scatter<'a> {
                 return colors[idx];
             }
         }
+        if let Some(cv) = &self.color_values {
+            if let Some(&v) = cv.values.get(idx) {
+                if v.is_nan() {
+                    return self.nan_color;
+                }
+                let (lo, hi) = cv.range;
+                let t = if hi > lo { (v - lo) / (hi - lo) } else { 0.5 };
+                return cv.map.sample(t as f32);
+            }
+        }
         self.marker.color.unwrap_or(auto)
     }
 
@@ -165,6 +298,9 @@ impl<'a> Scatter<'a> {
                 return r[idx];
             }
         }
+        if let Some(&r) = self.radii.and_then(|radii| radii.get(idx).or(radii.last())) {
+            return r as f32;
+        }
         self.marker.radius
     }
 }
@@ -172,11 +308,23 @@ impl<'a> Scatter<'a> {
 impl PlotItem for Scatter<'_> {
     #[allow(clippy::too_many_lines)]
     fn shapes(&self, ui: &Ui, transform: &PlotTransform, out: &mut Vec<Shape>) {
-        let n = self.series.len();
+        let series = self.series.view();
+        let n = series.len();
         if n == 0 {
             return;
         }
 
+        let decimated = self
+            .downsample
+            .then(|| {
+                let bounds = transform.bounds();
+                let x_range = (bounds.min()[0], bounds.max()[0]);
+                series.downsample_indices(self.sorted_x(), x_range, transform.frame().width())
+            })
+            .flatten();
+        let count = decimated.as_ref().map_or(n, Vec::len);
+        let index = |k: usize| decimated.as_ref().map_or(k, |d| d[k]);
+
         let auto_color = self
             .marker
             .color
@@ -186,8 +334,45 @@ impl PlotItem for Scatter<'_> {
             .stems_y
             .map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
-        for i in 0..n {
-            let (x, y) = self.series.get(i).unwrap_or_default();
+        if let Some(mut stroke) = self.connect {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = auto_color;
+            }
+            let mut run: Vec<Pos2> = Vec::new();
+            for i in (0..count).map(index) {
+                let (x, y) = series.get(i).unwrap_or((f64::NAN, f64::NAN));
+                if x.is_finite() && y.is_finite() {
+                    run.push(transform.position_from_point(&PlotPoint::new(x, y)));
+                } else if run.len() > 1 {
+                    out.push(Shape::line(std::mem::take(&mut run), stroke));
+                } else {
+                    run.clear();
+                }
+            }
+            if run.len() > 1 {
+                out.push(Shape::line(run, stroke));
+            }
+        }
+
+        for i in (0..count).map(index) {
+            let (x, y) = series.get(i).unwrap_or_default();
+            if !x.is_finite() {
+                continue;
+            }
+            if !y.is_finite() {
+                if !self.skip_non_finite {
+                    let x_screen = transform.position_from_point(&PlotPoint::new(x, 0.0)).x;
+                    out.push(Shape::circle_stroke(
+                        Pos2::new(x_screen, transform.frame().bottom()),
+                        self.resolve_radius(i),
+                        Stroke::new(
+                            self.marker.stroke.width.max(1.0),
+                            self.resolve_color(i, auto_color),
+                        ),
+                    ));
+                }
+                continue;
+            }
             let pos = transform.position_from_point(&PlotPoint::new(x, y));
 
             if let Some(y_screen) = stems_y_screen {
@@ -427,14 +612,22 @@ impl PlotItem for Scatter<'_> {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
+        let series = self.series.view();
         PlotGeometry::PointsXY {
-            xs: self.series.xs(),
-            ys: self.series.ys(),
+            xs: series.xs(),
+            ys: series.ys(),
         }
     }
 
+    fn sorted_x(&self) -> bool {
+        self.assume_sorted_x
+            || *self
+                .sorted_x
+                .get_or_init(|| self.series.view().xs().is_sorted())
+    }
+
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        self.series.view().bounds()
     }
 
     fn base(&self) -> &PlotItemBase {
@@ -443,4 +636,8 @@ impl PlotItem for Scatter<'_> {
     fn base_mut(&mut self) -> &mut PlotItemBase {
         &mut self.base
     }
+
+    fn marker_radius(&self, index: usize) -> f32 {
+        self.resolve_radius(index)
+    }
 }
@@ -0,0 +1,100 @@
+//! Stacked area chart: layers filled one on top of the other over shared X samples.
+
+use egui::Color32;
+
+use super::Band;
+
+/// Layers stacked on top of each other over shared `xs`, each filled from the top of the
+/// layers below it to its own cumulative top.
+///
+/// Add it with [`crate::PlotUi::stacked_area`]. Every layer becomes its own [`Band`], so the
+/// legend lists the layers separately and the bounds cover the cumulative top.
+///
+/// A NaN in a layer (or a layer shorter than `xs`) counts as zero at that X: the layer is
+/// flat there and the layers above keep stacking. A NaN in `xs` breaks every layer.
+#[derive(Clone, Debug)]
+pub struct StackedArea<'a> {
+    xs: &'a [f64],
+    layers: Vec<(String, &'a [f64], Color32)>,
+}
+
+impl<'a> StackedArea<'a> {
+    /// Stack `layers` of `(name, ys, color)` bottom to top over `xs`.
+    ///
+    /// A transparent color picks the next automatic plot color.
+    pub fn new<S: Into<String>>(
+        xs: &'a [f64],
+        layers: impl IntoIterator<Item = (S, &'a [f64], Color32)>,
+    ) -> Self {
+        Self {
+            xs,
+            layers: layers
+                .into_iter()
+                .map(|(name, ys, color)| (name.into(), ys, color))
+                .collect(),
+        }
+    }
+
+    /// The cumulative top of each layer, bottom layer first, one value per X.
+    pub fn cumulative_tops(&self) -> Vec<Vec<f64>> {
+        let mut top = vec![0.0; self.xs.len()];
+        self.layers
+            .iter()
+            .map(|(_, ys, _)| {
+                for (i, t) in top.iter_mut().enumerate() {
+                    let y = ys.get(i).copied().unwrap_or(0.0);
+                    if !y.is_nan() {
+                        *t += y;
+                    }
+                }
+                top.clone()
+            })
+            .collect()
+    }
+
+    /// One [`Band`] per layer, from the previous cumulative top to its own.
+    pub fn into_bands(self) -> Vec<Band> {
+        let mut bottom = vec![0.0; self.xs.len()];
+        self.cumulative_tops()
+            .into_iter()
+            .zip(&self.layers)
+            .map(|(top, (name, _, color))| {
+                let band = Band::with_name(name.clone())
+                    .with_color(*color)
+                    .with_series(self.xs, &bottom, &top);
+                bottom = top;
+                band
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlotBounds, PlotItem as _};
+
+    #[test]
+    fn test_stacked_area_cumulative_tops() {
+        let xs = [0.0, 1.0, 2.0];
+        let a = [1.0, f64::NAN, 2.0];
+        let b = [0.5, 3.0];
+        let area = StackedArea::new(
+            &xs,
+            [("a", &a[..], Color32::RED), ("b", &b[..], Color32::BLUE)],
+        );
+        // NaN and missing samples count as zero.
+        assert_eq!(
+            area.cumulative_tops(),
+            vec![vec![1.0, 0.0, 2.0], vec![1.5, 3.0, 2.0]]
+        );
+
+        let bands = area.into_bands();
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[1].name(), "b");
+        assert_eq!(
+            bands[1].bounds(),
+            PlotBounds::from_min_max([0.0, 0.0], [2.0, 3.0])
+        );
+    }
+}
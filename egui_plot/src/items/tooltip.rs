@@ -36,36 +36,61 @@
 //!
 //! ## Notes
 //! - Pins are stored in **egui temp memory**.
-//!   They are **not persisted** across application restarts.
-//! - Series highlighting currently matches by **series name**. Prefer unique names.
+//!   They are **not persisted** across application restarts by themselves; with the `serde`
+//!   feature, save [`PlotUi::export_pins`] yourself and restore it with [`PlotUi::import_pins`].
+//! - Series highlighting matches by **item id**. The id defaults to a hash of the
+//!   series name; series sharing an id are told apart by their insertion order, see
+//!   [`HitPoint::series_id`].
 
+use std::sync::Arc;
+
+use ahash::AHashSet;
 use egui::{
-    self, Align2, Area, Color32, Frame, Grid, Id, Order, Pos2, Rect, RichText, Stroke, TextStyle,
+    self, Align2, Area, Color32, Frame, Grid, Id, Order, PointerButton, Pos2, Rect, RichText,
+    Stroke, TextStyle,
 };
 
-use crate::{PlotPoint, PlotUi, items::PlotGeometry};
+use crate::{
+    NumberFormat, PlotBounds, PlotPoint, PlotTransform, PlotUi,
+    action::{PlotAction, PlotEvent, SeriesStats},
+    items::{PlotGeometry, PlotItem},
+};
 
 /// One selected  anchor per series, found inside the vertical band.
 ///
 /// Built once per frame for all participating series. Each row stores:
-/// - the **series name** (used for display and highlight matching),
+/// - the **series name** (used for display),
+/// - the **series id** (used for highlight matching),
 /// - **display color** (used for markers),
 /// - the picked **plot value** `(x,y)`,
 /// - its **screen position** (for drawing),
 /// - and `screen_dx` = horizontal pixel distance to the pointer (for sorting).
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
 pub struct HitPoint {
-    /// Series display name (should be unique/stable; used for highlight matching).
+    /// Series display name.
     pub series_name: String,
+    /// Id of the plot item this hit belongs to (used for highlight matching).
+    ///
+    /// Defaults to the id derived from the series name, unless set explicitly with `.id(..)`.
+    /// In the band tooltip, the first item with a given id keeps it and later items sharing
+    /// it get `id.with(n)` for their `n`-th repeat, so two lines with the same name are
+    /// still highlighted separately.
+    pub series_id: Id,
     /// Marker color used when drawing the on-canvas anchor.
     pub color: Color32,
     /// Picked plot-space value `(x, y)` for this series.
     pub value: PlotPoint,
     /// Screen-space position where the marker is drawn.
     pub screen_pos: Pos2,
-    /// Horizontal distance in pixels from (current frame's) `pointer.x`.
-    /// Used  for sorting.
+    /// Horizontal distance in pixels from (current frame's) `pointer.x`, or the 2D distance
+    /// to the pointer with [`PickMode::Euclidean`]. For a hit added by
+    /// [`TooltipOptions::include_sparse`], measured to the nearer sample it was interpolated
+    /// from. Used  for sorting.
     pub screen_dx: f32, // |screen_x - pointer_x|
+    /// `value` was interpolated between two samples rather than taken from one.
+    pub interpolated: bool,
 }
 
 /// A pinned selection: the full set of `HitRow`s plus the exact plot-space X.
@@ -74,6 +99,7 @@ pub struct HitPoint {
 /// in egui *temp* memory and redrawn every frame (rails + markers). Press **`U`**
 /// to remove the last pin, or **`Delete`** to clear all..
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PinnedPoints {
     /// Cloned hits from the moment the pin was taken (plot-space values).
     pub hits: Vec<HitPoint>,
@@ -81,6 +107,92 @@ pub struct PinnedPoints {
     pub plot_x: f64,
 }
 
+impl PinnedPoints {
+    /// One `(series_name, x, y)` row per hit, in hit order.
+    pub fn to_csv_rows(&self) -> Vec<(String, f64, f64)> {
+        self.hits
+            .iter()
+            .map(|h| (h.series_name.clone(), h.value.x, h.value.y))
+            .collect()
+    }
+}
+
+/// CSV of all `pins`, with a `pin_index,series,x,y` header.
+///
+/// Rows are sorted by pin index, then series name, so the output is stable.
+pub(crate) fn pins_csv(pins: &[PinnedPoints]) -> String {
+    let mut csv = String::from("pin_index,series,x,y\n");
+    for (index, pin) in pins.iter().enumerate() {
+        let mut rows = pin.to_csv_rows();
+        rows.sort_by(|a, b| a.0.cmp(&b.0));
+        for (name, x, y) in rows {
+            let name = if name.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", name.replace('"', "\"\""))
+            } else {
+                name
+            };
+            csv += &format!("{index},{name},{x},{y}\n");
+        }
+    }
+    csv
+}
+
+/// Summary of one [`crate::Band`] envelope inside the vertical band around the pointer,
+/// see [`PlotUi::show_band_stats_tooltip`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandStats {
+    /// Band display name.
+    pub series_name: String,
+    /// Lowest lower-envelope value in the band.
+    pub y_min: f64,
+    /// Mean of the envelope midpoints `(y_min + y_max) / 2`.
+    pub y_mean: f64,
+    /// Highest upper-envelope value in the band.
+    pub y_max: f64,
+    /// Number of samples in the band.
+    pub n: usize,
+}
+
+/// Formats one coordinate for the tooltip table, pins panel and coordinate readout.
+pub type TooltipFormatter = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Row order of the hits in the tooltip, see [`TooltipOptions::hit_order`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HitOrder {
+    /// Nearest to the pointer first; rows move as the pointer does.
+    #[default]
+    ByDistance,
+
+    /// Alphabetical by series name.
+    ByName,
+
+    /// The order the items were added to the plot.
+    InsertionOrder,
+}
+
+/// How the band tooltip picks the sample of each series, see [`TooltipOptions::pick_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PickMode {
+    /// Closest along X, interpolated between samples for ordered series.
+    #[default]
+    ByX,
+
+    /// Closest sample in 2D screen distance, without interpolation.
+    /// Suits scatter plots where series overlap in X.
+    Euclidean,
+}
+
+/// What the pin key does once [`TooltipOptions::max_pins`] is reached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PinOverflow {
+    /// Drop the oldest pin to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Keep the existing pins and don't add the new one.
+    Ignore,
+}
+
 /// Visual/behavioral settings for the band tooltip.
 ///
 /// Use [`TooltipOptions::default()`] and adjust via builder-ish methods.
@@ -96,13 +208,61 @@ pub struct TooltipOptions {
     pub guide_stroke: Stroke,
     /// Radius of the on-canvas hit markers (in pixels).
     pub marker_radius: f32,
-    /// Highlight hovered lines this frame (matched by series name).
+    /// Highlight hovered lines this frame (matched by item id).
     pub highlight_hovered_lines: bool,
+    /// Opacity of the items without a hit while something is hovered. `None` leaves them as is.
+    pub dim_others: Option<f32>,
     /// Show a small panel listing the current pins at the top-right.
     pub show_pins_panel: bool,
 
     /// Half-width of the vertical selection, in screen pixels.
     pub radius_px: f32,
+
+    /// Append per-series min/max/mean of the pinned Y values to the pins panel.
+    pub pins_show_stats: bool,
+
+    /// Tooltip popup width in points. `None` uses `style.spacing.tooltip_width`.
+    pub width: Option<f32>,
+
+    /// Duration in seconds of the hover highlight fade. `None` highlights instantly.
+    pub highlight_animation: Option<f32>,
+
+    /// A plain primary click locks the crosshair at the clicked position; clicking again
+    /// (or double-clicking) releases it.
+    pub lockable_crosshair: bool,
+
+    /// Formats X values in the tooltip and pins panel. `None` uses the plot's number format.
+    pub x_formatter: Option<TooltipFormatter>,
+
+    /// Formats Y values in the tooltip and pins panel. `None` uses the plot's number format.
+    pub y_formatter: Option<TooltipFormatter>,
+
+    /// Series without a sample in the band still get a hit, interpolated at the pointer X.
+    pub include_sparse: bool,
+
+    /// Row order of the hits.
+    pub hit_order: HitOrder,
+
+    /// Distance used to pick each series' sample.
+    pub pick_mode: PickMode,
+
+    /// Interpolate ordered series linearly at the pointer X instead of snapping to a sample.
+    pub interpolate: bool,
+
+    /// Decimals of the X values in the default tooltip table.
+    pub x_decimals: usize,
+
+    /// Decimals of the Y values in the default tooltip table.
+    pub y_decimals: usize,
+
+    /// Most pins kept at once. `None` means unlimited.
+    pub max_pins: Option<usize>,
+
+    /// What pinning does once `max_pins` is reached.
+    pub pin_overflow: PinOverflow,
+
+    /// How close (in screen pixels) a click must be to a pin rail to remove that pin.
+    pub pin_pick_px: f32,
 }
 impl Default for TooltipOptions {
     fn default() -> Self {
@@ -113,8 +273,24 @@ impl Default for TooltipOptions {
             guide_stroke: Stroke::new(1.0, Color32::WHITE),
             marker_radius: 3.5,
             highlight_hovered_lines: true,
+            dim_others: None,
             show_pins_panel: true,
             radius_px: 50.0,
+            pins_show_stats: false,
+            width: None,
+            highlight_animation: None,
+            lockable_crosshair: false,
+            x_formatter: None,
+            y_formatter: None,
+            include_sparse: false,
+            hit_order: HitOrder::ByDistance,
+            pick_mode: PickMode::ByX,
+            interpolate: true,
+            x_decimals: 3,
+            y_decimals: 3,
+            max_pins: None,
+            pin_overflow: PinOverflow::DropOldest,
+            pin_pick_px: 4.0,
         }
     }
 }
@@ -126,12 +302,158 @@ impl TooltipOptions {
         self.highlight_hovered_lines = on;
         self
     }
+    /// Fade the series without a hit to `alpha` (e.g. `Some(0.2)`) while the tooltip shows,
+    /// so the hovered ones stand out in a crowded plot. Default: `None`.
+    #[inline]
+    pub fn dim_others(mut self, alpha: Option<f32>) -> Self {
+        self.dim_others = alpha;
+        self
+    }
     /// Toggle whether to display the floating pins panel in the plot corner.
     #[inline]
     pub fn show_pins_panel(mut self, on: bool) -> Self {
         self.show_pins_panel = on;
         self
     }
+    /// Toggle a per-series statistics section (min, max, mean of pinned Y) in the pins panel.
+    #[inline]
+    pub fn pins_show_stats(mut self, on: bool) -> Self {
+        self.pins_show_stats = on;
+        self
+    }
+    /// Override the tooltip width, e.g. for wide multi-column content.
+    /// `None` (the default) uses the style's tooltip width.
+    #[inline]
+    pub fn width(mut self, width: Option<f32>) -> Self {
+        self.width = width;
+        self
+    }
+    /// Ease the hover highlight in and out over `duration` seconds (e.g. `Some(0.15)`).
+    /// Stroke widths, marker sizes and fill alphas grow gradually, and [`Self::dim_others`]
+    /// fades the other series. `None` (the default) switches instantly.
+    #[inline]
+    pub fn highlight_animation(mut self, duration: Option<f32>) -> Self {
+        self.highlight_animation = duration;
+        self
+    }
+    /// Let a click lock the crosshair (guide, band and hits) in place so the values can be
+    /// read while the pointer moves away. Another click releases it. Default: `false`.
+    ///
+    /// Only a primary click without modifiers toggles the lock; a double-click releases it.
+    #[inline]
+    pub fn lockable_crosshair(mut self, on: bool) -> Self {
+        self.lockable_crosshair = on;
+        self
+    }
+    /// Format X values in the tooltip independently of the axis, e.g. a full timestamp
+    /// while the axis shows dates only. Also used by the [`crate::Plot::coordinates_formatter`]
+    /// readout while the tooltip is shown.
+    #[inline]
+    pub fn x_formatter(mut self, fmt: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        self.x_formatter = Some(Arc::new(fmt));
+        self
+    }
+    /// Format Y values in the tooltip independently of the axis. Also used by the coordinate
+    /// readout, like [`Self::x_formatter`].
+    #[inline]
+    pub fn y_formatter(mut self, fmt: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        self.y_formatter = Some(Arc::new(fmt));
+        self
+    }
+
+    /// Give series that have no sample inside the band a hit too, linearly interpolated
+    /// between the samples bracketing the pointer X and flagged [`HitPoint::interpolated`].
+    /// Series that don't span the pointer X (or have a NaN neighbor) are still left out.
+    /// Default: `false`.
+    #[inline]
+    pub fn include_sparse(mut self, on: bool) -> Self {
+        self.include_sparse = on;
+        self
+    }
+
+    /// Keep the tooltip rows in a stable order instead of nearest-first, so a series can be
+    /// tracked while the pointer moves. The nearest series is shown in bold either way.
+    /// Default: [`HitOrder::ByDistance`].
+    #[inline]
+    pub fn hit_order(mut self, order: HitOrder) -> Self {
+        self.hit_order = order;
+        self
+    }
+
+    /// Pick the sample nearest the pointer in 2D ([`PickMode::Euclidean`]) instead of along X.
+    /// [`HitPoint::screen_dx`] then holds that 2D distance, and `radius_px` bounds it.
+    /// Default: [`PickMode::ByX`].
+    #[inline]
+    pub fn pick_mode(mut self, mode: PickMode) -> Self {
+        self.pick_mode = mode;
+        self
+    }
+
+    /// Place the anchor of line-like series exactly at the pointer X, interpolating Y
+    /// linearly between the two samples around it and flagging [`HitPoint::interpolated`].
+    ///
+    /// Snaps to the nearest sample instead when off, outside the data X range, next to a
+    /// NaN sample, for non-monotonic X, and with [`PickMode::Euclidean`]. Default: `true`.
+    #[inline]
+    pub fn interpolate(mut self, on: bool) -> Self {
+        self.interpolate = on;
+        self
+    }
+
+    /// Number of decimals for X values in the default tooltip table. Default: `3`.
+    ///
+    /// Ignored when an [`Self::x_formatter`] is set.
+    #[inline]
+    pub fn x_decimals(mut self, decimals: usize) -> Self {
+        self.x_decimals = decimals;
+        self
+    }
+
+    /// Number of decimals for Y values in the default tooltip table. Default: `3`.
+    ///
+    /// Ignored when an [`Self::y_formatter`] is set.
+    #[inline]
+    pub fn y_decimals(mut self, decimals: usize) -> Self {
+        self.y_decimals = decimals;
+        self
+    }
+
+    /// Keep at most `max` pins, see [`Self::pin_overflow`] for what happens beyond that.
+    /// Default: `None` (unlimited).
+    #[inline]
+    pub fn max_pins(mut self, max: Option<usize>) -> Self {
+        self.max_pins = max;
+        self
+    }
+
+    /// Whether pinning past [`Self::max_pins`] evicts the oldest pin or is ignored; either
+    /// way the pin key reports a [`PlotEvent::PinOverflow`]. Default: [`PinOverflow::DropOldest`].
+    #[inline]
+    pub fn pin_overflow(mut self, overflow: PinOverflow) -> Self {
+        self.pin_overflow = overflow;
+        self
+    }
+
+    /// Clicking within `px` screen pixels of a pin rail removes that pin. Default: `4.0`.
+    #[inline]
+    pub fn pin_pick_px(mut self, px: f32) -> Self {
+        self.pin_pick_px = px;
+        self
+    }
+
+    fn format_x(&self, x: f64, number_format: NumberFormat, decimals: usize) -> String {
+        match &self.x_formatter {
+            Some(fmt) => fmt(x),
+            None => number_format.format(x, decimals),
+        }
+    }
+
+    fn format_y(&self, y: f64, number_format: NumberFormat, decimals: usize) -> String {
+        match &self.y_formatter {
+            Some(fmt) => fmt(y),
+            None => number_format.format(y, decimals),
+        }
+    }
 }
 
 /// Temp-memory storage for pins
@@ -158,10 +480,206 @@ fn save_pins(ctx: &egui::Context, base: Id, v: Vec<PinnedPoints>) {
     ctx.data_mut(|d| d.insert_temp(pins_mem_id(base), v));
 }
 
+/// Append `pin`, honoring [`TooltipOptions::max_pins`] and [`TooltipOptions::pin_overflow`].
+///
+/// Returns `None` if there was room, else the number of oldest pins evicted to make room
+/// (`0` when `pin` was dropped instead).
+fn push_pin(
+    pins: &mut Vec<PinnedPoints>,
+    pin: PinnedPoints,
+    options: &TooltipOptions,
+) -> Option<usize> {
+    let max = options.max_pins.unwrap_or(usize::MAX);
+    if pins.len() < max {
+        pins.push(pin);
+        return None;
+    }
+    let mut evicted = 0;
+    if options.pin_overflow == PinOverflow::DropOldest {
+        evicted = pins.len() + 1 - max.max(1);
+        pins.drain(..evicted);
+        if pins.len() < max {
+            pins.push(pin);
+        }
+    }
+    Some(evicted)
+}
+
+/// Temp-memory key for the locked crosshair position of this plot.
+fn crosshair_lock_id(base: Id) -> Id {
+    base.with("band_crosshair_lock")
+}
+
 impl PlotUi<'_> {
+    /// Highlight the items in `hovered` (if enabled), easing in and out when animated,
+    /// and dim the others (if enabled).
+    ///
+    /// Also called with an empty set when nothing is hovered, so highlights can fade out.
+    fn highlight_hovered(&mut self, options: &TooltipOptions, hovered: &AHashSet<Id>) {
+        let ctx = self.ctx().clone();
+        let anim_id = self.response.id.with("highlight_animation");
+        let ids = series_ids(self.actions.iter_items().map(|item| item.id()));
+        for (item, id) in self.actions.iter_items_mut().zip(ids) {
+            let is_hovered = hovered.contains(&id);
+            if let Some(alpha) = options.dim_others {
+                let dimmed = !hovered.is_empty() && !is_hovered;
+                if let Some(duration) = options.highlight_animation {
+                    let t = ctx.animate_bool_with_time(anim_id.with((id, "dim")), dimmed, duration);
+                    item.set_opacity(egui::lerp(1.0..=alpha, t));
+                } else if dimmed {
+                    item.set_opacity(alpha);
+                }
+            }
+            if options.highlight_hovered_lines {
+                if let Some(duration) = options.highlight_animation {
+                    let t = ctx.animate_bool_with_time(anim_id.with(id), is_hovered, duration);
+                    item.set_highlight_amount(t);
+                } else if is_hovered {
+                    item.highlight();
+                }
+            }
+        }
+    }
+
     /// Default UI with custom options
     pub fn show_tooltip_with_options(&mut self, options: &TooltipOptions) {
-        self.show_tooltip_across_series_with(options, default_tooltip_ui);
+        let number_format = self.number_format;
+        self.show_tooltip_across_series_with(options, |ui, hits, pins| {
+            default_tooltip_ui(ui, hits, pins, number_format, options);
+        });
+    }
+
+    /// Hit-test a screen position against all hoverable items.
+    ///
+    /// Unlike the band tooltip (closest sample per series along X), this does a full 2D
+    /// nearest search and returns the single closest sample across all items,
+    /// if it lies within `radius_px` of `screen`. Useful for click-to-select.
+    pub fn pick(&self, screen: Pos2, radius_px: f32) -> Option<HitPoint> {
+        pick_nearest(
+            self.actions.iter_items().map(|item| &**item),
+            self.transform(),
+            screen,
+            radius_px,
+            self.ctx().style().visuals.text_color(),
+        )
+    }
+
+    /// The sample of the series `name` whose marker is under the pointer, as its index and
+    /// value.
+    ///
+    /// Each marker's drawn radius is the pick radius (see [`crate::Scatter::radius`]).
+    /// `None` when the pointer is outside the plot or over no marker of that series.
+    pub fn hovered_scatter_point(&self, name: &str) -> Option<(usize, PlotPoint)> {
+        if !self.response.contains_pointer() {
+            return None;
+        }
+        // Same frame-delayed correction as `pointer_coordinate`.
+        let pointer = self.ctx().input(|i| i.pointer.latest_pos())? - self.response.drag_delta();
+        if !self.transform().frame().contains(pointer) {
+            return None;
+        }
+        let (hit, index) = pick_nearest_indexed(
+            self.actions
+                .iter_items()
+                .map(|item| &**item)
+                .filter(|item| item.name() == name),
+            self.transform(),
+            pointer,
+            0.0,
+            Color32::TRANSPARENT,
+        )?;
+        Some((index, hit.value))
+    }
+
+    /// Make the band tooltip of this frame scan at `point` as if the pointer were there.
+    ///
+    /// Meant for UI tests (e.g. with `egui_kittest`) that assert the tooltip hits without
+    /// driving real pointer events; the tooltip is anchored at `point`. A locked crosshair
+    /// still takes precedence.
+    pub fn simulate_hover(&mut self, point: PlotPoint) {
+        self.simulated_hover = Some(point);
+    }
+
+    /// Pin the band hits at plot X `x`, as the pin key would at that position with `options`,
+    /// including [`TooltipOptions::max_pins`].
+    ///
+    /// Only the items added so far are scanned. Unlike the pin key, this emits no
+    /// [`PlotEvent`]s.
+    pub fn add_pin_at(&self, x: f64, options: &TooltipOptions) {
+        let frame = *self.transform().frame();
+        let pointer_screen = Pos2::new(self.transform().position_from_point_x(x), frame.center().y);
+        let hits = self.band_hits(pointer_screen, options);
+        let mut pins = load_pins(self.ctx(), self.response.id);
+        push_pin(&mut pins, PinnedPoints { hits, plot_x: x }, options);
+        save_pins(self.ctx(), self.response.id, pins);
+    }
+
+    /// A copy of this plot's pins, oldest first, e.g. to serialize them into an app's own config.
+    ///
+    /// Pins are in plot space, so they restore correctly whatever the zoom at that time.
+    pub fn export_pins(&self) -> Vec<PinnedPoints> {
+        load_pins(self.ctx(), self.response.id)
+    }
+
+    /// This plot's pins as CSV (`pin_index,series,x,y`), e.g. to paste into a spreadsheet.
+    ///
+    /// Rows are sorted by pin index, then series name. Names containing a comma, quote or
+    /// newline are quoted.
+    pub fn pins_to_csv(&self) -> String {
+        pins_csv(&load_pins(self.ctx(), self.response.id))
+    }
+
+    /// Replace this plot's pins, e.g. with a list saved from [`Self::export_pins`].
+    pub fn import_pins(&self, pins: Vec<PinnedPoints>) {
+        save_pins(self.ctx(), self.response.id, pins);
+    }
+
+    /// Show a tooltip summarizing every [`crate::Band`] within `radius_px` of the pointer X:
+    /// lowest, mean and highest envelope value, and the number of samples.
+    ///
+    /// Only the items added so far are summarized.
+    pub fn show_band_stats_tooltip(&self, radius_px: f32) {
+        let number_format = self.number_format;
+        self.show_band_stats_tooltip_with(radius_px, |ui, stats| {
+            default_band_stats_ui(ui, stats, number_format);
+        });
+    }
+
+    /// Like [`Self::show_band_stats_tooltip`], with a custom tooltip body.
+    ///
+    /// Nothing is shown while no band has a sample near the pointer.
+    pub fn show_band_stats_tooltip_with(
+        &self,
+        radius_px: f32,
+        ui_builder: impl FnOnce(&mut egui::Ui, &[BandStats]),
+    ) {
+        let ctx = self.ctx().clone();
+        let transform = self.transform();
+        let pointer_x = match self.simulated_hover {
+            Some(point) => Some(transform.position_from_point_x(point.x)),
+            None if self.response.hovered() => ctx.input(|i| i.pointer.latest_pos()).map(|p| p.x),
+            None => None,
+        };
+        let Some(pointer_x) = pointer_x else {
+            return;
+        };
+        let stats = band_stats(
+            self.actions.iter_items().map(|item| &**item),
+            transform,
+            pointer_x,
+            radius_px,
+        );
+        if stats.is_empty() {
+            return;
+        }
+        egui::Tooltip::always_open(
+            ctx,
+            self.response.layer_id,
+            self.response.id.with("band_stats_tooltip"),
+            egui::PopupAnchor::Pointer,
+        )
+        .gap(10.0)
+        .show(|ui| ui_builder(ui, &stats));
     }
 
     /// Provide options and a closure to build the **tooltip body UI**.
@@ -193,8 +711,31 @@ impl PlotUi<'_> {
         let frame = transform.frame();
 
         let nav = *self.navigation_config();
+        self.readout_formatters = [options.x_formatter.clone(), options.y_formatter.clone()];
         // Draw existing pins (rails + markers) on a foreground layer:
         let mut pins = load_pins(&ctx, self.response.id);
+
+        // A click on a rail removes that pin, and is consumed: it doesn't also toggle the
+        // crosshair lock or activate the item under it.
+        if nav.pinning_enabled && self.response.clicked() && !self.click_consumed {
+            let click_x = self.response.interact_pointer_pos().map(|pos| pos.x);
+            let nearest = pins
+                .iter()
+                .enumerate()
+                .filter_map(|(index, pin)| {
+                    let dx = (transform.position_from_point_x(pin.plot_x) - click_x?).abs();
+                    (dx <= options.pin_pick_px).then_some((index, dx))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((index, _)) = nearest {
+                pins.remove(index);
+                save_pins(&ctx, self.response.id, pins.clone());
+                self.actions
+                    .push(PlotAction::EmitEvent(PlotEvent::PinRemoved { index }));
+                self.click_consumed = true;
+            }
+        }
+
         draw_pins_overlay(
             &ctx,
             &pins,
@@ -205,11 +746,45 @@ impl PlotUi<'_> {
         );
 
         if options.show_pins_panel && !pins.is_empty() {
-            show_pins_panel(&ctx, *frame, &pins);
+            show_pins_panel(&ctx, *frame, &pins, options, self.number_format);
+        }
+
+        // A locked crosshair stands in for the live pointer:
+        let lock_id = crosshair_lock_id(self.response.id);
+        let mut locked: Option<PlotPoint> = None;
+        if options.lockable_crosshair {
+            locked = ctx
+                .data(|d| d.get_temp::<Option<PlotPoint>>(lock_id))
+                .flatten();
+            // Only a plain primary click toggles the lock; modified clicks belong to other
+            // interactions, and a double-click (a view reset) releases it.
+            let plain_click = self.response.clicked_by(PointerButton::Primary)
+                && ctx.input(|i| i.modifiers.is_none())
+                && !self.click_consumed;
+            if self.response.double_clicked() {
+                locked = None;
+                ctx.data_mut(|d| d.insert_temp(lock_id, locked));
+            } else if plain_click {
+                locked = match locked {
+                    Some(_) => None,
+                    None => self
+                        .response
+                        .interact_pointer_pos()
+                        .map(|pos| transform.value_from_position(pos)),
+                };
+                ctx.data_mut(|d| d.insert_temp(lock_id, locked));
+            }
+        } else {
+            ctx.data_mut(|d| d.remove::<Option<PlotPoint>>(lock_id));
         }
 
         // Need a pointer to build the band/selection:
-        let Some(pointer_screen) = ctx.input(|i| i.pointer.latest_pos()) else {
+        let Some(pointer_screen) = locked
+            .or(self.simulated_hover)
+            .map(|p| transform.position_from_point(&p))
+            .or_else(|| ctx.input(|i| i.pointer.latest_pos()))
+        else {
+            self.highlight_hovered(options, &AHashSet::default());
             return;
         };
 
@@ -218,19 +793,149 @@ impl PlotUi<'_> {
         let band_min_x = (pointer_screen.x - r).max(frame.left());
         let band_max_x = (pointer_screen.x + r).min(frame.right());
         if band_max_x <= band_min_x {
+            self.highlight_hovered(options, &AHashSet::default());
             return;
         }
+        let mut hits = self.band_hits(pointer_screen, options);
+
+        if hits.is_empty() {
+            self.highlight_hovered(options, &AHashSet::default());
+            if self.response.hovered() && nav.pinning_enabled {
+                ctx.input(|i| {
+                    if let Some(k) = nav.pin_remove_key {
+                        if i.key_pressed(k) {
+                            pins.pop();
+                        }
+                    }
+                    if let Some(k) = nav.pins_clear_key {
+                        if i.key_pressed(k) {
+                            pins.clear();
+                        }
+                    }
+                });
+                save_pins(&ctx, self.response.id, pins);
+            }
+            return;
+        }
+
+        match options.hit_order {
+            HitOrder::ByDistance => hits.sort_by(|a, b| {
+                a.screen_dx
+                    .partial_cmp(&b.screen_dx)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.series_name.cmp(&b.series_name))
+            }),
+            HitOrder::ByName => hits.sort_by(|a, b| a.series_name.cmp(&b.series_name)),
+            HitOrder::InsertionOrder => {} // hits are collected in item order
+        }
+
+        let ids: AHashSet<Id> = hits.iter().map(|h| h.series_id).collect();
+        self.highlight_hovered(options, &ids);
+
+        if self.response.hovered() && nav.pinning_enabled {
+            let mut overflow = None;
+            ctx.input(|i| {
+                if let Some(k) = nav.pin_add_key {
+                    if i.key_pressed(k) {
+                        let pointer_plot = transform.value_from_position(pointer_screen);
+                        let pin = PinnedPoints {
+                            hits: hits.clone(),
+                            plot_x: pointer_plot.x,
+                        };
+                        overflow = push_pin(&mut pins, pin, options);
+                    }
+                }
+                if let Some(k) = nav.pin_remove_key {
+                    if i.key_pressed(k) {
+                        pins.pop();
+                    }
+                }
+                if let Some(k) = nav.pins_clear_key {
+                    if i.key_pressed(k) {
+                        pins.clear();
+                    }
+                }
+            });
+            save_pins(&ctx, self.response.id, pins.clone());
+            if let Some(evicted) = overflow {
+                self.actions
+                    .push(PlotAction::EmitEvent(PlotEvent::PinOverflow { evicted }));
+            }
+        }
+
+        {
+            let painter = egui::Painter::new(ctx.clone(), self.response.layer_id, *frame);
+
+            if options.draw_band_fill {
+                let band_rect = Rect::from_min_max(
+                    Pos2::new(band_min_x, frame.top()),
+                    Pos2::new(band_max_x, frame.bottom()),
+                );
+                painter.rect_filled(band_rect, 0.0, options.band_fill);
+            }
+            if options.draw_vertical_guide {
+                painter.line_segment(
+                    [
+                        Pos2::new(pointer_screen.x, frame.top()),
+                        Pos2::new(pointer_screen.x, frame.bottom()),
+                    ],
+                    options.guide_stroke,
+                );
+            }
+            draw_moving_markers(&ctx, *frame, &hits, &visuals, options.marker_radius);
+
+            for h in &hits {
+                painter.circle_filled(h.screen_pos, options.marker_radius, h.color);
+                painter.circle_stroke(
+                    h.screen_pos,
+                    options.marker_radius,
+                    Stroke::new(1.0, visuals.window_stroke().color),
+                );
+            }
+        }
+
+        let mut tooltip = egui::Tooltip::always_open(
+            ctx.clone(),
+            self.response.layer_id,
+            self.response.id.with("band_tooltip"),
+            if locked.is_some() || self.simulated_hover.is_some() {
+                egui::PopupAnchor::Position(pointer_screen)
+            } else {
+                egui::PopupAnchor::Pointer
+            },
+        );
+        let tooltip_width = options
+            .width
+            .unwrap_or_else(|| ctx.style().spacing.tooltip_width);
+        tooltip.popup = tooltip.popup.width(tooltip_width);
+
+        tooltip.gap(10.0).show(|ui| {
+            ui.set_max_width(tooltip_width);
+            ui_builder(ui, &hits, &pins);
+        });
+    }
+
+    /// Per-series closest sample (or interpolated value) inside the vertical band around
+    /// `pointer_screen`.
+    fn band_hits(&self, pointer_screen: Pos2, options: &TooltipOptions) -> Vec<HitPoint> {
+        let visuals = self.ctx().style().visuals.clone();
+        let primary = self.transform();
+        let secondary = primary.secondary();
         let radius_px = options.radius_px;
 
         // Collect per-series closest point inside the band:
         let mut hits: Vec<HitPoint> = Vec::new();
-        let pointer_plot = transform.value_from_position(pointer_screen);
-        let mut best_value_pointsxy: Option<PlotPoint> = None;
+        let pointer_plot = primary.value_from_position(pointer_screen);
 
-        for item in self.actions.iter_items() {
+        let ids = series_ids(self.actions.iter_items().map(|item| item.id()));
+        for (item, series_id) in self.actions.iter_items().zip(ids) {
             if !item.allow_hover() {
                 continue;
             }
+            let transform = match &secondary {
+                Some(secondary) if item.on_right_axis() => secondary,
+                _ => primary,
+            };
 
             let base_color = {
                 let c = item.color();
@@ -242,64 +947,51 @@ impl PlotUi<'_> {
             };
 
             let (mut best_ix, mut best_dx, mut best_pos) = (None, f32::INFINITY, Pos2::ZERO);
+            let mut best_value_pointsxy: Option<PlotPoint> = None;
             let mut best_value_blocksxy: Option<PlotPoint> = None;
+            let mut interpolated = false;
+            let euclidean = options.pick_mode == PickMode::Euclidean;
+            let nearest = |samples: &mut dyn Iterator<Item = PlotPoint>| {
+                nearest_sample(
+                    samples,
+                    transform,
+                    pointer_screen,
+                    radius_px,
+                    options.pick_mode,
+                )
+            };
             match item.geometry() {
                 PlotGeometry::Points(points) => {
-                    for (ix, v) in points.iter().enumerate() {
-                        let p = transform.position_from_point(v);
-                        let dx = (p.x - pointer_screen.x).abs();
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(ix);
-                            best_dx = dx;
-                            best_pos = p;
-                        }
+                    if let Some((ix, _, dist, p)) = nearest(&mut points.iter().copied()) {
+                        best_ix = Some(ix);
+                        best_dx = dist;
+                        best_pos = p;
                     }
                 }
 
                 PlotGeometry::PointsXY { xs, ys } => {
                     let n = xs.len().min(ys.len());
-                    if n == 0 {
-                        // nothing
-                    } else if n == 1 {
-                        // single point
-                        let value = PlotPoint { x: xs[0], y: ys[0] };
-                        let p = transform.position_from_point(&value);
-                        let dx = (p.x - pointer_screen.x).abs();
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(0);
-                            best_dx = dx;
+                    let (xs, ys) = (&xs[..n], &ys[..n]);
+                    // Interpolate only along ordered X; a 2D pick, single points and unordered
+                    // samples snap to the nearest sample.
+                    let lerp = (options.interpolate && !euclidean && n >= 2 && item.sorted_x())
+                        .then(|| interpolate_sorted(xs, ys, pointer_plot.x))
+                        .flatten();
+                    if let Some((i, value)) = lerp {
+                        let py = transform.position_from_point(&value).y;
+                        best_ix = Some(i);
+                        best_dx = 0.0;
+                        best_pos = Pos2::new(pointer_screen.x, py);
+                        best_value_pointsxy = Some(value);
+                        interpolated = true;
+                    } else {
+                        let samples = &mut xs.iter().zip(ys).map(|(&x, &y)| PlotPoint { x, y });
+                        if let Some((ix, value, dist, p)) = nearest(samples) {
+                            best_ix = Some(ix);
+                            best_dx = dist;
                             best_pos = p;
                             best_value_pointsxy = Some(value);
                         }
-                    } else {
-                        //
-                        if pointer_plot.x >= xs[0] && pointer_plot.x <= xs[n - 1] {
-                            let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
-                            let i = j - 1;
-
-                            let (x0, y0) = (xs[i], ys[i]);
-                            let (x1, y1) = (xs[j], ys[j]);
-                            let t = if x1 > x0 {
-                                (pointer_plot.x - x0) / (x1 - x0)
-                            } else {
-                                0.0
-                            };
-                            let y = y0 + t * (y1 - y0);
-
-                            let value = PlotPoint {
-                                x: pointer_plot.x,
-                                y,
-                            };
-                            let py = transform.position_from_point(&value).y;
-                            let p = Pos2::new(pointer_screen.x, py);
-
-                            if best_dx > 0.0 || radius_px >= 0.0 {
-                                best_ix = Some(i);
-                                best_dx = 0.0;
-                                best_pos = p;
-                                best_value_pointsxy = Some(value);
-                            }
-                        }
                     }
                 }
 
@@ -307,45 +999,41 @@ impl PlotUi<'_> {
                     xs_blocks,
                     ys_blocks,
                 } => {
-                    let nb = xs_blocks.len().min(ys_blocks.len());
-                    for b in 0..nb {
-                        let xs = xs_blocks[b];
-                        let ys = ys_blocks[b];
-                        let n = xs.len().min(ys.len());
-                        if n < 2 {
-                            continue;
-                        }
+                    if options.interpolate && !euclidean {
+                        for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                            let n = xs.len().min(ys.len());
+                            let (xs, ys) = (&xs[..n], &ys[..n]);
+                            if !xs.is_sorted() {
+                                continue;
+                            }
+                            let Some((i, value)) = interpolate_sorted(xs, ys, pointer_plot.x)
+                            else {
+                                continue;
+                            };
+                            let py = transform.position_from_point(&value).y;
 
-                        if pointer_plot.x < xs[0] || pointer_plot.x > xs[n - 1] {
-                            continue;
+                            // Blocks are checked in order; at an X shared by two blocks,
+                            // the one closer in Y wins.
+                            let closer = (py - pointer_screen.y).abs()
+                                < (best_pos.y - pointer_screen.y).abs();
+                            if best_ix.is_none() || closer {
+                                best_ix = Some(i);
+                                best_dx = 0.0;
+                                best_pos = Pos2::new(pointer_screen.x, py);
+                                best_value_blocksxy = Some(value);
+                                interpolated = true;
+                            }
                         }
-
-                        let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
-                        let i = j - 1;
-
-                        let x0 = xs[i];
-                        let y0 = ys[i];
-                        let x1 = xs[j];
-                        let y1 = ys[j];
-                        let t = if x1 > x0 {
-                            (pointer_plot.x - x0) / (x1 - x0)
-                        } else {
-                            0.0
-                        };
-                        let y = y0 + t * (y1 - y0);
-
-                        let value = PlotPoint {
-                            x: pointer_plot.x,
-                            y,
-                        };
-
-                        let py = transform.position_from_point(&value).y;
-                        let p = Pos2::new(pointer_screen.x, py);
-
-                        let dx = 0.0;
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(i);
-                            best_dx = dx;
+                    }
+                    if best_ix.is_none() {
+                        let samples = &mut xs_blocks
+                            .iter()
+                            .zip(&ys_blocks)
+                            .flat_map(|(xs, ys)| xs.iter().zip(ys.iter()))
+                            .map(|(&x, &y)| PlotPoint { x, y });
+                        if let Some((ix, value, dist, p)) = nearest(samples) {
+                            best_ix = Some(ix);
+                            best_dx = dist;
                             best_pos = p;
                             best_value_blocksxy = Some(value);
                         }
@@ -355,12 +1043,59 @@ impl PlotUi<'_> {
                 PlotGeometry::Rects | PlotGeometry::None => {}
             }
 
+            if best_ix.is_none() && options.include_sparse {
+                let sparse = match item.geometry() {
+                    PlotGeometry::Points(points) => {
+                        interpolate_between(points.iter().copied(), pointer_plot.x)
+                    }
+                    PlotGeometry::PointsXY { xs, ys, .. } => interpolate_between(
+                        xs.iter().zip(ys).map(|(&x, &y)| PlotPoint { x, y }),
+                        pointer_plot.x,
+                    ),
+                    PlotGeometry::BlocksXY {
+                        xs_blocks,
+                        ys_blocks,
+                    } => interpolate_between(
+                        xs_blocks
+                            .iter()
+                            .zip(&ys_blocks)
+                            .flat_map(|(xs, ys)| xs.iter().zip(ys.iter()))
+                            .map(|(&x, &y)| PlotPoint { x, y }),
+                        pointer_plot.x,
+                    ),
+                    PlotGeometry::Rects | PlotGeometry::None => None,
+                };
+                if let Some((value, nearest)) = sparse {
+                    let py = transform.position_from_point(&value).y;
+                    // Rank it by the sample it was interpolated from, which lies outside the
+                    // band, so it sorts after the series with a sample under the pointer.
+                    let nearest = transform.position_from_point(&nearest);
+                    let screen_dx = match options.pick_mode {
+                        PickMode::ByX => (nearest.x - pointer_screen.x).abs(),
+                        PickMode::Euclidean => (nearest - pointer_screen).length(),
+                    };
+                    hits.push(HitPoint {
+                        series_name: item.name().to_owned(),
+                        series_id,
+                        color: base_color,
+                        value: PlotPoint {
+                            x: value.x,
+                            y: value.y - item.y_offset(),
+                        },
+                        screen_pos: Pos2::new(pointer_screen.x, py),
+                        screen_dx,
+                        interpolated: true,
+                    });
+                }
+                continue;
+            }
+
             let value = match item.geometry() {
                 PlotGeometry::Points(points) => {
                     let Some(ix) = best_ix else { continue };
                     points[ix]
                 }
-                PlotGeometry::PointsXY { xs, ys } => {
+                PlotGeometry::PointsXY { xs, ys, .. } => {
                     if let Some(v) = best_value_pointsxy {
                         v
                     } else {
@@ -381,121 +1116,239 @@ impl PlotUi<'_> {
                 PlotGeometry::Rects | PlotGeometry::None => continue,
             };
 
+            let value = PlotPoint {
+                x: value.x,
+                y: value.y - item.y_offset(),
+            };
+
             hits.push(HitPoint {
                 series_name: item.name().to_owned(),
+                series_id,
                 color: base_color,
                 value,
                 screen_pos: best_pos,
                 screen_dx: best_dx,
+                interpolated,
             });
         }
-
-        if hits.is_empty() {
-            if self.response.hovered() && nav.pinning_enabled {
-                ctx.input(|i| {
-                    if let Some(k) = nav.pin_remove_key {
-                        if i.key_pressed(k) {
-                            pins.pop();
-                        }
-                    }
-                    if let Some(k) = nav.pins_clear_key {
-                        if i.key_pressed(k) {
-                            pins.clear();
-                        }
-                    }
-                });
-                save_pins(&ctx, self.response.id, pins);
+        hits
+    }
+}
+
+/// [`BandStats`] of each envelope item over the samples within `radius_px` of `pointer_x`
+/// (screen space). Envelopes without a finite sample there are left out.
+pub(crate) fn band_stats<'i, 'p: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'p)>,
+    transform: &PlotTransform,
+    pointer_x: f32,
+    radius_px: f32,
+) -> Vec<BandStats> {
+    let mut out = Vec::new();
+    for item in items {
+        let Some([xs, lo, hi]) = item.envelope() else {
+            continue;
+        };
+        let mut stats = BandStats {
+            series_name: item.name().to_owned(),
+            y_min: f64::INFINITY,
+            y_mean: 0.0,
+            y_max: f64::NEG_INFINITY,
+            n: 0,
+        };
+        for ((&x, &a), &b) in xs.iter().zip(lo).zip(hi) {
+            if !(x.is_finite() && a.is_finite() && b.is_finite()) {
+                continue;
             }
-            return;
+            if (transform.position_from_point_x(x) - pointer_x).abs() > radius_px {
+                continue;
+            }
+            stats.n += 1;
+            stats.y_min = stats.y_min.min(a.min(b));
+            stats.y_max = stats.y_max.max(a.max(b));
+            stats.y_mean += 0.5 * (a + b);
         }
+        if stats.n > 0 {
+            stats.y_mean /= stats.n as f64;
+            out.push(stats);
+        }
+    }
+    out
+}
 
-        hits.sort_by(|a, b| {
-            a.screen_dx
-                .partial_cmp(&b.screen_dx)
-                .unwrap_or(std::cmp::Ordering::Equal)
-                .then_with(|| a.series_name.cmp(&b.series_name))
-        });
-
-        if options.highlight_hovered_lines {
-            let names: ahash::AHashSet<&str> =
-                hits.iter().map(|h| h.series_name.as_str()).collect();
-            for item in self.actions.iter_items_mut() {
-                if names.contains(item.name()) {
-                    item.highlight();
-                }
-            }
+/// Per-series count/min/max/mean/sum of the Y values of samples inside `bounds`.
+///
+/// Hidden-from-hover items and non-finite samples are skipped, as for [`pick_nearest`].
+pub(crate) fn selection_stats<'i, 'p: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'p)>,
+    bounds: &PlotBounds,
+) -> Vec<(String, SeriesStats)> {
+    let mut out = Vec::new();
+    for item in items {
+        if !item.allow_hover() {
+            continue;
         }
+        let dy = item.y_offset();
+        let mut stats = SeriesStats {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            mean: 0.0,
+            sum: 0.0,
+        };
+        let mut consider = |x: f64, y: f64| {
+            let inside = x >= bounds.min()[0]
+                && x <= bounds.max()[0]
+                && y >= bounds.min()[1]
+                && y <= bounds.max()[1];
+            if inside {
+                let y = y - dy;
+                stats.count += 1;
+                stats.min = stats.min.min(y);
+                stats.max = stats.max.max(y);
+                stats.sum += y;
+            }
+        };
 
-        if self.response.hovered() && nav.pinning_enabled {
-            ctx.input(|i| {
-                if let Some(k) = nav.pin_add_key {
-                    if i.key_pressed(k) {
-                        let pointer_plot = transform.value_from_position(pointer_screen);
-                        pins.push(PinnedPoints {
-                            hits: hits.clone(),
-                            plot_x: pointer_plot.x,
-                        });
-                    }
+        match item.geometry() {
+            PlotGeometry::Points(points) => {
+                for p in points {
+                    consider(p.x, p.y);
                 }
-                if let Some(k) = nav.pin_remove_key {
-                    if i.key_pressed(k) {
-                        pins.pop();
-                    }
+            }
+            PlotGeometry::PointsXY { xs, ys, .. } => {
+                for (&x, &y) in xs.iter().zip(ys) {
+                    consider(x, y);
                 }
-                if let Some(k) = nav.pins_clear_key {
-                    if i.key_pressed(k) {
-                        pins.clear();
+            }
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => {
+                for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                    for (&x, &y) in xs.iter().zip(ys.iter()) {
+                        consider(x, y);
                     }
                 }
-            });
-            save_pins(&ctx, self.response.id, pins.clone());
+            }
+            PlotGeometry::Rects | PlotGeometry::None => {}
         }
 
-        {
-            let painter = egui::Painter::new(ctx.clone(), self.response.layer_id, *frame);
+        if stats.count > 0 {
+            stats.mean = stats.sum / stats.count as f64;
+            out.push((item.name().to_owned(), stats));
+        }
+    }
+    out
+}
 
-            if options.draw_band_fill {
-                let band_rect = Rect::from_min_max(
-                    Pos2::new(band_min_x, frame.top()),
-                    Pos2::new(band_max_x, frame.bottom()),
-                );
-                painter.rect_filled(band_rect, 0.0, options.band_fill);
+/// Global 2D nearest-sample search shared by [`PlotUi::pick`] and click activation.
+///
+/// Items that don't allow hovering and samples with non-finite coordinates are skipped.
+/// `fallback_color` is used for items with an auto (transparent) color.
+pub(crate) fn pick_nearest<'i, 'p: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'p)>,
+    transform: &PlotTransform,
+    screen: Pos2,
+    radius_px: f32,
+    fallback_color: Color32,
+) -> Option<HitPoint> {
+    pick_nearest_indexed(items, transform, screen, radius_px, fallback_color).map(|(hit, _)| hit)
+}
+
+/// [`pick_nearest`], also returning the index of the sample in its item's geometry.
+///
+/// A sample's own [`PlotItem::marker_radius`] widens `radius_px` for it.
+pub(crate) fn pick_nearest_indexed<'i, 'p: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'p)>,
+    transform: &PlotTransform,
+    screen: Pos2,
+    radius_px: f32,
+    fallback_color: Color32,
+) -> Option<(HitPoint, usize)> {
+    let mut best: Option<(f32, HitPoint, usize)> = None;
+    let secondary = transform.secondary();
+
+    for item in items {
+        if !item.allow_hover() {
+            continue;
+        }
+        let transform = match &secondary {
+            Some(secondary) if item.on_right_axis() => secondary,
+            _ => transform,
+        };
+
+        let mut best_in_item: Option<(f32, PlotPoint, Pos2, usize)> = None;
+        let mut index = 0;
+        let mut consider = |x: f64, y: f64| {
+            let i = index;
+            index += 1;
+            if !x.is_finite() || !y.is_finite() {
+                return;
             }
-            if options.draw_vertical_guide {
-                painter.line_segment(
-                    [
-                        Pos2::new(pointer_screen.x, frame.top()),
-                        Pos2::new(pointer_screen.x, frame.bottom()),
-                    ],
-                    options.guide_stroke,
-                );
+            let value = PlotPoint { x, y };
+            let pos = transform.position_from_point(&value);
+            let dist_sq = pos.distance_sq(screen);
+            let radius = radius_px.max(item.marker_radius(i));
+            if dist_sq <= radius * radius && best_in_item.is_none_or(|(d, ..)| dist_sq < d) {
+                best_in_item = Some((dist_sq, value, pos, i));
             }
-            draw_moving_markers(&ctx, *frame, &hits, &visuals, options.marker_radius);
+        };
 
-            for h in &hits {
-                painter.circle_filled(h.screen_pos, options.marker_radius, h.color);
-                painter.circle_stroke(
-                    h.screen_pos,
-                    options.marker_radius,
-                    Stroke::new(1.0, visuals.window_stroke().color),
-                );
+        match item.geometry() {
+            PlotGeometry::Points(points) => {
+                for p in points {
+                    consider(p.x, p.y);
+                }
             }
+            PlotGeometry::PointsXY { xs, ys, .. } => {
+                for (&x, &y) in xs.iter().zip(ys) {
+                    consider(x, y);
+                }
+            }
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => {
+                for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                    for (&x, &y) in xs.iter().zip(ys.iter()) {
+                        consider(x, y);
+                    }
+                }
+            }
+            PlotGeometry::Rects | PlotGeometry::None => {}
         }
 
-        let mut tooltip = egui::Tooltip::always_open(
-            ctx.clone(),
-            self.response.layer_id,
-            self.response.id.with("band_tooltip"),
-            egui::PopupAnchor::Pointer,
-        );
-        let tooltip_width = ctx.style().spacing.tooltip_width;
-        tooltip.popup = tooltip.popup.width(tooltip_width);
+        let Some((dist_sq, value, pos, index)) = best_in_item else {
+            continue;
+        };
+        if best.as_ref().is_some_and(|(d, ..)| *d <= dist_sq) {
+            continue;
+        }
 
-        tooltip.gap(10.0).show(|ui| {
-            ui.set_max_width(tooltip_width);
-            ui_builder(ui, &hits, &pins);
-        });
+        let color = if item.color() == Color32::TRANSPARENT {
+            fallback_color
+        } else {
+            item.color()
+        };
+        best = Some((
+            dist_sq,
+            HitPoint {
+                series_name: item.name().to_owned(),
+                series_id: item.id(),
+                color,
+                value: PlotPoint {
+                    x: value.x,
+                    y: value.y - item.y_offset(),
+                },
+                screen_pos: pos,
+                screen_dx: (pos.x - screen.x).abs(),
+                interpolated: false,
+            },
+            index,
+        ));
     }
+
+    best.map(|(_, hit, index)| (hit, index))
 }
 
 /// Draws **all pin overlays**: a vertical rail per pin and markers at each pinned point.
@@ -554,7 +1407,13 @@ fn draw_pins_overlay(
 /// This is a *display-only* panel (not interactive), listing all pins and
 /// their captured series rows. It helps the user review pinned values without
 /// having to hover the plot again.
-fn show_pins_panel(ctx: &egui::Context, frame: Rect, pins: &[PinnedPoints]) {
+fn show_pins_panel(
+    ctx: &egui::Context,
+    frame: Rect,
+    pins: &[PinnedPoints],
+    options: &TooltipOptions,
+    number_format: NumberFormat,
+) {
     let panel_id = Id::new("egui_plot_pins_panel");
     let panel_pos = Pos2::new(frame.right() - 240.0, frame.top() + 8.0);
 
@@ -591,14 +1450,20 @@ fn show_pins_panel(ctx: &egui::Context, frame: Rect, pins: &[PinnedPoints]) {
                                     for h in &snap.hits {
                                         ui.label(RichText::new("●").color(h.color));
                                         ui.monospace(&h.series_name);
-                                        ui.monospace(format!("{:.6}", h.value.x));
-                                        ui.monospace(format!("{:.6}", h.value.y));
+                                        ui.monospace(options.format_x(h.value.x, number_format, 6));
+                                        ui.monospace(options.format_y(h.value.y, number_format, 6));
                                         ui.end_row();
                                     }
                                 });
                         });
                 }
 
+                if options.pins_show_stats && !pins.is_empty() {
+                    ui.add_space(6.0);
+                    ui.strong("Stats (y)");
+                    pins_stats_grid(ui, pins, number_format);
+                }
+
                 if pins.is_empty() {
                     ui.weak("No pins yet. Hover plot and press P.");
                 } else {
@@ -609,14 +1474,99 @@ fn show_pins_panel(ctx: &egui::Context, frame: Rect, pins: &[PinnedPoints]) {
         });
 }
 
+/// Per-series summary of pinned Y values, in order of first appearance.
+///
+/// Series that are missing from some pins are summarized over the pins they appear in;
+/// the `n` column shows how many pins contributed.
+fn pins_stats_grid(ui: &mut egui::Ui, pins: &[PinnedPoints], number_format: NumberFormat) {
+    struct SeriesStats<'a> {
+        name: &'a str,
+        color: Color32,
+        min: f64,
+        max: f64,
+        sum: f64,
+        count: usize,
+    }
+
+    let mut stats: Vec<SeriesStats<'_>> = Vec::new();
+    for h in pins.iter().flat_map(|p| &p.hits) {
+        let y = h.value.y;
+        if !y.is_finite() {
+            continue;
+        }
+        if let Some(s) = stats.iter_mut().find(|s| s.name == h.series_name) {
+            s.min = s.min.min(y);
+            s.max = s.max.max(y);
+            s.sum += y;
+            s.count += 1;
+        } else {
+            stats.push(SeriesStats {
+                name: &h.series_name,
+                color: h.color,
+                min: y,
+                max: y,
+                sum: y,
+                count: 1,
+            });
+        }
+    }
+
+    egui::Grid::new("pin_stats_grid")
+        .num_columns(6)
+        .spacing([6.0, 2.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.weak("");
+            ui.weak("series");
+            ui.weak("n");
+            ui.weak("min");
+            ui.weak("max");
+            ui.weak("mean");
+            ui.end_row();
+            for s in &stats {
+                ui.label(RichText::new("●").color(s.color));
+                ui.monospace(s.name);
+                ui.monospace(format!("{}", s.count));
+                ui.monospace(number_format.format(s.min, 4));
+                ui.monospace(number_format.format(s.max, 4));
+                ui.monospace(number_format.format(s.sum / s.count as f64, 4));
+                ui.end_row();
+            }
+        });
+}
+
 /// Default tooltip content: a compact table with a row per hit (series).
-fn default_tooltip_ui(ui: &mut egui::Ui, hits: &[HitPoint], pins: &[PinnedPoints]) {
+fn default_band_stats_ui(ui: &mut egui::Ui, stats: &[BandStats], number_format: NumberFormat) {
+    Grid::new(Id::new("egui_plot_band_stats_table"))
+        .num_columns(5)
+        .spacing([8.0, 2.0])
+        .striped(true)
+        .show(ui, |ui| {
+            for header in ["band", "min", "mean", "max", "n"] {
+                ui.weak(header);
+            }
+            ui.end_row();
+            for s in stats {
+                ui.monospace(&s.series_name);
+                ui.monospace(number_format.format(s.y_min, 3));
+                ui.monospace(number_format.format(s.y_mean, 3));
+                ui.monospace(number_format.format(s.y_max, 3));
+                ui.monospace(s.n.to_string());
+                ui.end_row();
+            }
+        });
+}
+
+fn default_tooltip_ui(
+    ui: &mut egui::Ui,
+    hits: &[HitPoint],
+    pins: &[PinnedPoints],
+    number_format: NumberFormat,
+    options: &TooltipOptions,
+) {
     ui.strong("Nearest per series (band)");
     ui.add_space(4.0);
 
-    let x_dec = 3usize;
-    let y_dec = 3usize;
-
     Grid::new(Id::new("egui_plot_band_tooltip_table"))
         .num_columns(4)
         .spacing([8.0, 2.0])
@@ -627,11 +1577,21 @@ fn default_tooltip_ui(ui: &mut egui::Ui, hits: &[HitPoint], pins: &[PinnedPoints
             ui.weak("x");
             ui.weak("y");
             ui.end_row();
-            for h in hits {
+            let nearest = hits
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.screen_dx.total_cmp(&b.screen_dx))
+                .map(|(i, _)| i);
+            for (i, h) in hits.iter().enumerate() {
                 ui.label(RichText::new("●").color(h.color));
-                ui.monospace(&h.series_name);
-                ui.monospace(format!("{:.*}", x_dec, h.value.x));
-                ui.monospace(format!("{:.*}", y_dec, h.value.y));
+                let name = RichText::new(&h.series_name).monospace();
+                ui.label(if nearest == Some(i) {
+                    name.strong()
+                } else {
+                    name
+                });
+                ui.monospace(options.format_x(h.value.x, number_format, options.x_decimals));
+                ui.monospace(options.format_y(h.value.y, number_format, options.y_decimals));
                 ui.end_row();
             }
         });
@@ -671,3 +1631,505 @@ fn draw_moving_markers(
         painter.circle_stroke(h.screen_pos, radius, outline);
     }
 }
+
+/// Per-item ids for hover matching: each id as is on its first occurrence, then
+/// `id.with(n)` on its `n`-th repeat, so items sharing an id (e.g. the same name) stay distinct.
+pub(crate) fn series_ids(ids: impl Iterator<Item = Id>) -> Vec<Id> {
+    let mut seen: ahash::AHashMap<Id, usize> = ahash::AHashMap::default();
+    ids.map(|id| {
+        let n = seen.entry(id).or_insert(0);
+        let series_id = if *n == 0 { id } else { id.with(*n) };
+        *n += 1;
+        series_id
+    })
+    .collect()
+}
+
+/// `ys` linearly interpolated at `x` between the samples bracketing it in the sorted `xs`,
+/// with the index of the left one.
+///
+/// `None` outside `xs`' range or if either bracketing Y is not finite.
+pub(crate) fn interpolate_sorted(xs: &[f64], ys: &[f64], x: f64) -> Option<(usize, PlotPoint)> {
+    let n = xs.len().min(ys.len());
+    if n < 2 || !(xs[0] <= x && x <= xs[n - 1]) {
+        return None;
+    }
+    let j = xs[..n].partition_point(|v| *v < x).clamp(1, n - 1);
+    let i = j - 1;
+    let (x0, y0, x1, y1) = (xs[i], ys[i], xs[j], ys[j]);
+    if !(y0.is_finite() && y1.is_finite()) {
+        return None;
+    }
+    let t = if x1 > x0 { (x - x0) / (x1 - x0) } else { 0.0 };
+    Some((i, PlotPoint::new(x, y0 + t * (y1 - y0))))
+}
+
+/// The finite sample nearest `pointer_screen` within `radius_px`, as
+/// `(index, value, distance, screen position)`.
+///
+/// The distance is along X or in 2D depending on `mode`. Candidates at the same distance
+/// (e.g. the two ends of a vertical step) are told apart by their Y distance to the pointer,
+/// so the pick doesn't depend on iteration order.
+pub(crate) fn nearest_sample(
+    samples: &mut dyn Iterator<Item = PlotPoint>,
+    transform: &PlotTransform,
+    pointer_screen: Pos2,
+    radius_px: f32,
+    mode: PickMode,
+) -> Option<(usize, PlotPoint, f32, Pos2)> {
+    let mut best: Option<(usize, PlotPoint, f32, Pos2)> = None;
+    for (ix, value) in samples.enumerate() {
+        if !value.x.is_finite() || !value.y.is_finite() {
+            continue;
+        }
+        let p = transform.position_from_point(&value);
+        let dist = match mode {
+            PickMode::ByX => (p.x - pointer_screen.x).abs(),
+            PickMode::Euclidean => (p - pointer_screen).length(),
+        };
+        if dist > radius_px {
+            continue;
+        }
+        let better = best.is_none_or(|(_, _, best_dist, best_pos)| {
+            dist < best_dist
+                || (dist == best_dist
+                    && (p.y - pointer_screen.y).abs() < (best_pos.y - pointer_screen.y).abs())
+        });
+        if better {
+            best = Some((ix, value, dist, p));
+        }
+    }
+    best
+}
+
+/// Linear interpolation at `x` between the nearest samples on either side, which need not
+/// be sorted, along with the one of those two samples nearer to `x`.
+///
+/// `None` if `x` isn't bracketed or a neighbor is missing (NaN).
+fn interpolate_between(
+    samples: impl Iterator<Item = PlotPoint>,
+    x: f64,
+) -> Option<(PlotPoint, PlotPoint)> {
+    let mut left: Option<PlotPoint> = None;
+    let mut right: Option<PlotPoint> = None;
+    for p in samples.filter(|p| p.x.is_finite()) {
+        if p.x <= x && left.is_none_or(|l| p.x > l.x) {
+            left = Some(p);
+        }
+        if p.x >= x && right.is_none_or(|r| p.x < r.x) {
+            right = Some(p);
+        }
+    }
+    let (l, r) = (left?, right?);
+    let t = if r.x > l.x {
+        (x - l.x) / (r.x - l.x)
+    } else {
+        0.0
+    };
+    let y = l.y + t * (r.y - l.y);
+    let nearest = if x - l.x <= r.x - x { l } else { r };
+    y.is_finite().then_some((PlotPoint { x, y }, nearest))
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Vec2;
+
+    use super::*;
+    use crate::{Band, Line, Points};
+
+    #[test]
+    fn test_band_stats() {
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, -1.0], [10.0, 2.0]),
+            false,
+        );
+        let xs = [4.0, 5.0, 6.0, 9.0];
+        let near = Band::with_name("near").with_series(&xs, &[0.0, -1.0, f64::NAN, 5.0], &[1.0; 4]);
+        let far = Band::with_name("far").with_series(&[9.0], &[0.0], &[1.0]);
+        let items: [&dyn PlotItem; 2] = [&near, &far];
+        // Pointer at x = 5, band ±10 px = ±1 unit: samples at 4 and 5 (6 is NaN).
+        let stats = band_stats(items.into_iter(), &transform, 50.0, 10.0);
+        assert_eq!(stats.len(), 1, "bands without samples in range are skipped");
+        assert_eq!(stats[0].series_name, "near");
+        assert_eq!(stats[0].n, 2);
+        assert_eq!((stats[0].y_min, stats[0].y_max), (-1.0, 1.0));
+        assert_eq!(stats[0].y_mean, 0.25);
+    }
+
+    #[test]
+    fn test_pick_mode_euclidean() {
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+            false,
+        );
+        // Pointer at (5, 5); both samples are 1 unit away in X, the second is closer in Y.
+        let pointer = transform.position_from_point(&PlotPoint::new(5.0, 5.0));
+        let samples = [PlotPoint::new(4.0, 9.0), PlotPoint::new(6.0, 6.0)];
+        let pick = |mode| {
+            nearest_sample(
+                &mut samples.iter().copied(),
+                &transform,
+                pointer,
+                50.0,
+                mode,
+            )
+            .map(|(ix, _, dist, _)| (ix, dist))
+        };
+        let (ix, dist) = pick(PickMode::Euclidean).unwrap();
+        assert_eq!(ix, 1);
+        assert!((dist - 200f32.sqrt()).abs() < 1e-3);
+        assert_eq!(pick(PickMode::ByX).unwrap().1, 10.0);
+    }
+
+    #[test]
+    fn test_pick_nearest_indexed_marker_radius() {
+        let transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+            false,
+        );
+        let points = Points::new("a", vec![[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]).radius(8.0);
+        let line = Line::new("b", vec![[0.0, 5.0], [3.0, 5.0]]);
+        let items: [&dyn PlotItem; 2] = [&points, &line];
+
+        // 6 px from the third marker: outside the 2 px interaction radius, inside the marker.
+        let click = transform.position_from_point(&PlotPoint::new(3.0, 3.6));
+        let (hit, index) =
+            pick_nearest_indexed(items.into_iter(), &transform, click, 2.0, Color32::WHITE)
+                .unwrap();
+        assert_eq!((hit.series_name.as_str(), index), ("a", 2));
+
+        let miss = transform.position_from_point(&PlotPoint::new(6.0, 8.0));
+        assert!(
+            pick_nearest_indexed(items.into_iter(), &transform, miss, 2.0, Color32::WHITE)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_interpolate_sorted_edges() {
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let ys = [0.0, 10.0, f64::NAN, 30.0];
+        assert_eq!(
+            interpolate_sorted(&xs, &ys, 0.25),
+            Some((0, PlotPoint::new(0.25, 2.5)))
+        );
+        assert_eq!(interpolate_sorted(&xs, &ys, 1.5), None);
+        assert_eq!(interpolate_sorted(&xs, &ys, 3.5), None);
+    }
+
+    #[test]
+    fn test_series_ids_split_shared_names() {
+        let (a, b) = (Id::new("sin"), Id::new("cos"));
+        let ids = series_ids([a, b, a, a].into_iter());
+        assert_eq!(ids[0], a, "the first item keeps its id");
+        assert_eq!(ids[1], b);
+        assert_ne!(ids[2], a);
+        assert_ne!(ids[3], ids[2]);
+        // Stable from frame to frame for the same insertion order.
+        assert_eq!(ids, series_ids([a, b, a, a].into_iter()));
+    }
+
+    #[test]
+    fn test_pins_csv() {
+        let hit = |name: &str, x: f64, y: f64| HitPoint {
+            series_name: name.to_owned(),
+            series_id: Id::new(name),
+            color: Color32::WHITE,
+            value: PlotPoint::new(x, y),
+            screen_pos: Pos2::ZERO,
+            screen_dx: 0.0,
+            interpolated: false,
+        };
+        let pins = [
+            PinnedPoints {
+                hits: vec![hit("sin", 1.0, 0.5), hit("cos", 1.0, -0.25)],
+                plot_x: 1.0,
+            },
+            PinnedPoints {
+                hits: vec![hit("sin", 2.5, 1.0), hit("a, b", 2.5, 3.0)],
+                plot_x: 2.5,
+            },
+        ];
+        assert_eq!(
+            pins_csv(&pins),
+            "pin_index,series,x,y\n\
+             0,cos,1,-0.25\n\
+             0,sin,1,0.5\n\
+             1,\"a, b\",2.5,3\n\
+             1,sin,2.5,1\n"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_pins_serde_round_trip() {
+        let hit = |x: f64, y: f64| HitPoint {
+            series_name: "a".to_owned(),
+            series_id: Id::new("a"),
+            color: Color32::from_rgb(10, 20, 30),
+            value: PlotPoint::new(x, y),
+            screen_pos: Pos2::new(1.0, 2.0),
+            screen_dx: 0.5,
+            interpolated: false,
+        };
+        let pins = vec![
+            PinnedPoints {
+                hits: vec![hit(1.5, 2.0)],
+                plot_x: 1.5,
+            },
+            PinnedPoints {
+                hits: vec![hit(1e9, -3.0), hit(1e9, 4.0)],
+                plot_x: 1e9,
+            },
+        ];
+        let json = serde_json::to_string(&pins).unwrap();
+        let back: Vec<PinnedPoints> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.len(), 2);
+        assert_eq!(back[1].plot_x, 1e9);
+        assert_eq!(back[1].hits.len(), 2);
+        assert_eq!(back[1].hits[0].value, PlotPoint::new(1e9, -3.0));
+        assert_eq!(back[0].hits[0].color, Color32::from_rgb(10, 20, 30));
+    }
+
+    #[test]
+    fn test_sparse_hit_ranks_after_band_hits() {
+        use crate::{Plot, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let dense: Vec<[f64; 2]> = (0..=100).map(|i| [f64::from(i) * 0.1, 0.0]).collect();
+        let mut hits = Vec::new();
+        for _ in 0..2 {
+            run_headless_frame(&ctx, Vec::new(), |ui| {
+                Plot::new("sparse").show(ui, |plot_ui| {
+                    plot_ui.line(Line::new("sparse", vec![[0.0, 0.0], [10.0, 1.0]]));
+                    plot_ui.line(Line::new("dense", dense.clone()));
+                    let pointer = plot_ui
+                        .transform()
+                        .position_from_point(&PlotPoint::new(4.0, 0.0));
+                    let options = TooltipOptions::default().include_sparse(true);
+                    hits = plot_ui.band_hits(pointer, &options);
+                });
+            });
+        }
+        let [sparse, dense] = &hits[..] else {
+            panic!("expected a hit per series, got {hits:?}");
+        };
+        assert!(sparse.interpolated);
+        assert!((sparse.value.y - 0.4).abs() < 1e-3);
+        assert!(
+            sparse.screen_dx > TooltipOptions::default().radius_px,
+            "ranked by the sample it was interpolated from"
+        );
+        assert!(dense.screen_dx < sparse.screen_dx);
+    }
+
+    #[test]
+    fn test_simulate_hover_and_add_pin_at() {
+        use crate::{Plot, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let options = TooltipOptions::default().max_pins(Some(2));
+        let (mut hovered, mut pins) = (Vec::new(), Vec::new());
+        for frame in 0..2 {
+            run_headless_frame(&ctx, Vec::new(), |ui| {
+                Plot::new("simulated").show(ui, |plot_ui| {
+                    plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0], [2.0, 4.0]]));
+                    if frame == 1 {
+                        for x in [0.0, 1.0, 2.0] {
+                            plot_ui.add_pin_at(x, &options);
+                        }
+                        pins = plot_ui.export_pins();
+                    }
+                    plot_ui.simulate_hover(PlotPoint::new(2.0, 0.0));
+                    plot_ui.show_tooltip_across_series_with(&options, |_, hits, _| {
+                        hovered = hits.to_vec();
+                    });
+                });
+            });
+        }
+        assert_eq!(hovered.len(), 1);
+        assert!((hovered[0].value.y - 4.0).abs() < 1e-3);
+
+        // The oldest pin made room for the last one.
+        let xs: Vec<f64> = pins.iter().map(|pin| pin.plot_x).collect();
+        assert_eq!(xs, [1.0, 2.0]);
+        assert!((pins[0].hits[0].value.y - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_band_hits_on_right_axis() {
+        use crate::{AxisHints, Plot, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let (mut hits, mut frame) = (Vec::new(), Rect::NOTHING);
+        for _ in 0..2 {
+            run_headless_frame(&ctx, Vec::new(), |ui| {
+                Plot::new("right")
+                    .right_y_axis(AxisHints::new_y())
+                    .show(ui, |plot_ui| {
+                        let xs = || (0..=100).map(f64::from);
+                        let left = xs().map(|x| [x, x / 100.0]).collect::<Vec<_>>();
+                        let right = xs().map(|x| [x, 1000.0 + 10.0 * x]).collect::<Vec<_>>();
+                        plot_ui.line(Line::new("left", left));
+                        plot_ui.line(Line::new("right", right).on_right_axis(true));
+                        plot_ui.simulate_hover(PlotPoint::new(50.0, 0.5));
+                        plot_ui.show_tooltip_across_series_with(
+                            &TooltipOptions::default(),
+                            |_, h, _| hits = h.to_vec(),
+                        );
+                        frame = *plot_ui.transform().frame();
+                    });
+            });
+        }
+        let right = hits.iter().find(|h| h.series_name == "right").unwrap();
+        assert!((right.value.y - 1500.0).abs() < 1e-3);
+        assert!(
+            frame.contains(right.screen_pos),
+            "drawn with the secondary transform"
+        );
+    }
+
+    #[test]
+    fn test_pin_overflow_event() {
+        use crate::{Plot, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let press_p = || egui::Event::Key {
+            key: egui::Key::P,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let frames = [
+            vec![egui::Event::PointerMoved(Pos2::new(200.0, 150.0))],
+            vec![press_p()],
+            vec![press_p()],
+        ];
+        let mut events = Vec::new();
+        for input in frames {
+            run_headless_frame(&ctx, input, |ui| {
+                let response = Plot::new("overflow").show(ui, |plot_ui| {
+                    let points: Vec<[f64; 2]> = (0..=100).map(|i| [i as f64, 0.0]).collect();
+                    plot_ui.line(Line::new("a", points));
+                    let options = TooltipOptions::default().max_pins(Some(1));
+                    plot_ui.show_tooltip_with_options(&options);
+                });
+                events.extend(response.events);
+            });
+        }
+        let overflows: Vec<usize> = events
+            .iter()
+            .filter_map(|e| match e {
+                PlotEvent::PinOverflow { evicted } => Some(*evicted),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            overflows,
+            [1],
+            "only the second pin overflows, evicting the first"
+        );
+        assert!(
+            !events
+                .iter()
+                .any(|e| matches!(e, PlotEvent::PinRemoved { .. }))
+        );
+    }
+
+    #[test]
+    fn test_crosshair_lock_gesture() {
+        use egui::Modifiers;
+
+        use crate::{Plot, pointer_gesture, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let pos = Pos2::new(200.0, 150.0);
+        let lock = |modifiers| {
+            let mut locked = None;
+            // Idle long enough afterwards that the next click isn't a double-click.
+            let frames = pointer_gesture(PointerButton::Primary, modifiers, pos, pos)
+                .into_iter()
+                .chain(std::iter::repeat_n(Vec::new(), 30));
+            for input in frames {
+                run_headless_frame(&ctx, input, |ui| {
+                    let response = Plot::new("lock").show(ui, |plot_ui| {
+                        plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0]]));
+                        let options = TooltipOptions::default().lockable_crosshair(true);
+                        plot_ui.show_tooltip_across_series_with(&options, |_, _, _| {});
+                    });
+                    let lock_id = crosshair_lock_id(response.response.id);
+                    locked = ctx
+                        .data(|d| d.get_temp::<Option<PlotPoint>>(lock_id))
+                        .flatten();
+                });
+            }
+            locked
+        };
+        assert!(
+            lock(Modifiers::CTRL).is_none(),
+            "modified clicks don't lock"
+        );
+        assert!(lock(Modifiers::NONE).is_some());
+        assert!(lock(Modifiers::SHIFT).is_some(), "nor do they release");
+        assert!(lock(Modifiers::NONE).is_none());
+    }
+
+    #[test]
+    fn test_rail_click_is_consumed() {
+        use egui::Modifiers;
+
+        use crate::{Plot, pointer_gesture, run_headless_frame};
+
+        let ctx = egui::Context::default();
+        let pos = Pos2::new(200.0, 150.0);
+        // The pins panel would cover the rail.
+        let options = TooltipOptions::default()
+            .lockable_crosshair(true)
+            .show_pins_panel(false);
+        let mut events = Vec::new();
+        let mut locked = None;
+        // Idle, move, press, release. The pin is placed on the press, once the layout has
+        // settled around the hovering pointer.
+        let frames = std::iter::once(Vec::new()).chain(pointer_gesture(
+            PointerButton::Primary,
+            Modifiers::NONE,
+            pos,
+            pos,
+        ));
+        for (frame, input) in frames.enumerate() {
+            run_headless_frame(&ctx, input, |ui| {
+                let response = Plot::new("rail").show(ui, |plot_ui| {
+                    plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0]]));
+                    if frame == 2 {
+                        let x = plot_ui.transform().value_from_position(pos).x;
+                        plot_ui.add_pin_at(x, &options);
+                    }
+                    plot_ui.show_tooltip_across_series_with(&options, |_, _, _| {});
+                });
+                events.extend(response.events);
+                let lock_id = crosshair_lock_id(response.response.id);
+                locked = ctx
+                    .data(|d| d.get_temp::<Option<PlotPoint>>(lock_id))
+                    .flatten();
+            });
+        }
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, PlotEvent::PinRemoved { index: 0 }))
+        );
+        assert!(
+            !events.iter().any(|e| matches!(
+                e,
+                PlotEvent::Activate { .. } | PlotEvent::ItemClicked { .. }
+            )),
+            "the click only removes the pin"
+        );
+        assert!(locked.is_none());
+    }
+}
@@ -16,6 +16,7 @@ use crate::transform::PlotBounds;
     note = "PlotPoint is deprecated. Use ColumnarSeries<'a> and Line::from_series / Line::new_xy."
 )]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PlotPoint {
     /// This is often something monotonically increasing, such as time, but doesn't have to be.
     /// Goes from left to right.
@@ -59,17 +60,32 @@ impl PlotPoint {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum LineStyle {
     Solid,
-    Dotted { spacing: f32 },
-    Dashed { length: f32 },
+    Dotted {
+        spacing: f32,
+    },
+    /// Dashes of `dash` screen points separated by `gap` screen points.
+    Dashed {
+        dash: f32,
+        gap: f32,
+    },
 }
 
 impl LineStyle {
+    /// Dashed with the gap set to the golden ratio of the dash.
+    pub fn dashed(dash: f32) -> Self {
+        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+        Self::Dashed {
+            dash,
+            gap: dash * golden_ratio,
+        }
+    }
+
     pub fn dashed_loose() -> Self {
-        Self::Dashed { length: 10.0 }
+        Self::dashed(10.0)
     }
 
     pub fn dashed_dense() -> Self {
-        Self::Dashed { length: 5.0 }
+        Self::dashed(5.0)
     }
 
     pub fn dotted_loose() -> Self {
@@ -80,11 +96,12 @@ impl LineStyle {
         Self::Dotted { spacing: 5.0 }
     }
 
+    /// Draw `positions` in this style; `highlight` is the highlight amount in `0..=1`.
     pub(super) fn style_line_iter<I>(
         &self,
         positions: I,
         mut stroke: PathStroke,
-        highlight: bool,
+        highlight: f32,
         shapes: &mut Vec<Shape>,
         scratch: &mut Vec<Pos2>,
     ) where
@@ -102,24 +119,18 @@ impl LineStyle {
             0 => {}
             1 => {
                 let mut radius = stroke.width / 2.0;
-                if highlight {
-                    radius *= 2f32.sqrt();
-                }
+                radius *= lerp(1.0..=2f32.sqrt(), highlight);
                 shapes.push(Shape::circle_filled(scratch[0], radius, path_stroke_color));
             }
             _ => match self {
                 Self::Solid => {
-                    if highlight {
-                        stroke.width *= 2.0;
-                    }
+                    stroke.width *= 1.0 + highlight;
                     let buf = std::mem::take(scratch);
                     shapes.push(Shape::line(buf, stroke));
                 }
                 Self::Dotted { spacing } => {
                     let mut radius = stroke.width;
-                    if highlight {
-                        radius *= 2f32.sqrt();
-                    }
+                    radius *= lerp(1.0..=2f32.sqrt(), highlight);
                     shapes.extend(Shape::dotted_line(
                         scratch,
                         path_stroke_color,
@@ -127,27 +138,25 @@ impl LineStyle {
                         radius,
                     ));
                 }
-                Self::Dashed { length } => {
-                    if highlight {
-                        stroke.width *= 2.0;
-                    }
-                    let golden = (5.0_f32.sqrt() - 1.0) / 2.0;
+                Self::Dashed { dash, gap } => {
+                    stroke.width *= 1.0 + highlight;
                     shapes.extend(Shape::dashed_line(
                         scratch,
                         Stroke::new(stroke.width, path_stroke_color),
-                        *length,
-                        length * golden,
+                        *dash,
+                        *gap,
                     ));
                 }
             },
         }
     }
 
+    /// [`Self::style_line_iter`] for an owned list of points.
     pub(super) fn style_line(
         &self,
         line: Vec<Pos2>,
         mut stroke: PathStroke,
-        highlight: bool,
+        highlight: f32,
         shapes: &mut Vec<Shape>,
     ) {
         let path_stroke_color = match &stroke.color {
@@ -160,26 +169,20 @@ impl LineStyle {
             0 => {}
             1 => {
                 let mut radius = stroke.width / 2.0;
-                if highlight {
-                    radius *= 2f32.sqrt();
-                }
+                radius *= lerp(1.0..=2f32.sqrt(), highlight);
                 shapes.push(Shape::circle_filled(line[0], radius, path_stroke_color));
             }
             _ => {
                 match self {
                     Self::Solid => {
-                        if highlight {
-                            stroke.width *= 2.0;
-                        }
+                        stroke.width *= 1.0 + highlight;
                         shapes.push(Shape::line(line, stroke));
                     }
                     Self::Dotted { spacing } => {
                         // Take the stroke width for the radius even though it's not "correct", otherwise
                         // the dots would become too small.
                         let mut radius = stroke.width;
-                        if highlight {
-                            radius *= 2f32.sqrt();
-                        }
+                        radius *= lerp(1.0..=2f32.sqrt(), highlight);
                         shapes.extend(Shape::dotted_line(
                             &line,
                             path_stroke_color,
@@ -187,16 +190,13 @@ impl LineStyle {
                             radius,
                         ));
                     }
-                    Self::Dashed { length } => {
-                        if highlight {
-                            stroke.width *= 2.0;
-                        }
-                        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+                    Self::Dashed { dash, gap } => {
+                        stroke.width *= 1.0 + highlight;
                         shapes.extend(Shape::dashed_line(
                             &line,
                             Stroke::new(stroke.width, path_stroke_color),
-                            *length,
-                            length * golden_ratio,
+                            *dash,
+                            *gap,
                         ));
                     }
                 }
@@ -210,11 +210,30 @@ impl std::fmt::Display for LineStyle {
         match self {
             Self::Solid => write!(f, "Solid"),
             Self::Dotted { spacing } => write!(f, "Dotted({spacing} px)"),
-            Self::Dashed { length } => write!(f, "Dashed({length} px)"),
+            Self::Dashed { dash, gap } => write!(f, "Dashed({dash} px, gap {gap:.1} px)"),
         }
     }
 }
 
+/// Where a stepped [`crate::Line`] puts the vertical riser between two samples.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StepMode {
+    /// Straight segments from sample to sample.
+    #[default]
+    None,
+
+    /// Each value applies up to its sample: the riser sits at the previous sample.
+    Before,
+
+    /// Each value holds until the next sample (sample-and-hold): the riser sits at the next
+    /// sample.
+    After,
+
+    /// The riser sits halfway between the two samples.
+    Center,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Determines whether a plot element is vertically or horizontally oriented.
@@ -512,10 +531,8 @@ pub enum PlotGeometry<'a> {
     // Instead, geometry-based functions are directly implemented in the respective PlotItem impl.
     Rects,
 
-    PointsXY {
-        xs: &'a [f64],
-        ys: &'a [f64],
-    },
+    /// Columnar X-Y samples.
+    PointsXY { xs: &'a [f64], ys: &'a [f64] },
     BlocksXY {
         xs_blocks: Vec<&'a [f64]>,
         ys_blocks: Vec<&'a [f64]>,
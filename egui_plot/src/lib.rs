@@ -9,8 +9,10 @@
 //!
 #![allow(deprecated)]
 mod axis;
+mod axis_scale;
 mod bound;
 mod collect_events;
+mod colormap;
 mod items;
 mod legend;
 mod memory;
@@ -19,26 +21,36 @@ mod plot_ui;
 mod segmented_axis;
 mod span;
 mod span_utils;
+mod time_axis;
 mod transform;
 use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
 mod action;
 pub use crate::action::PlotEvent;
 pub use crate::action::{ActionExecutor, ActionQueue};
-pub use crate::action::{BoundsChangeCause, InputInfo, PinSnapshot};
-pub use navigation::{AxisToggle, BoxZoomConfig, NavigationConfig, ResetBehavior, ZoomConfig};
+pub use crate::action::{BoundsChangeCause, EventMask, InputInfo, PinSnapshot, SeriesStats};
+pub use navigation::{
+    AxisToggle, BoxZoomConfig, NavigationConfig, ResetBehavior, ScrollAction, ScrollConfig,
+    ZoomConfig,
+};
 
-pub use crate::segmented_axis::SegmentedAxis;
+pub use crate::axis_scale::AxisScale;
+pub use crate::colormap::ColorMap;
+pub use crate::segmented_axis::{BreakStyle, Segment, SegmentScale, SegmentSizing, SegmentedAxis};
+pub use crate::time_axis::TimeFormat;
 pub use crate::{
-    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    axis::{Axis, AxisHints, HPlacement, Placement, TickMarkStyle, VPlacement},
     items::{
-        Arrows, Band, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, ColumnarSeries,
-        HLine, HitPoint, Line, LineStyle, Marker, MarkerShape, Orientation, PinnedPoints,
-        PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotItemBase, PlotPoint, PlotPoints, Points,
-        Polygon, Scatter, ScatterEncodings, Text, TooltipOptions, VLine,
+        Arrows, AsColumnar, Band, BandStats, Bar, BarChart, BoxElem, BoxPlot, BoxSpread,
+        ClosestElem, ColumnarSeries, ColumnarSeriesF32, ConfidenceEllipse, DragHandle,
+        DraggablePoint, HLine, HitOrder, HitPoint, Line, LineStyle, Marker, MarkerShape,
+        Orientation, PickMode, PinOverflow, PinnedPoints, PlotConfig, PlotGeometry, PlotImage,
+        PlotItem, PlotItemBase, PlotPoint, PlotPoints, Points, Polygon, Rug, Scatter,
+        ScatterEncodings, Simplify, StackedArea, StepMode, Text, TooltipFormatter, TooltipOptions,
+        VLine,
     },
     legend::{ColorConflictHandling, Corner, Legend},
     memory::PlotMemory,
-    plot_ui::PlotUi,
+    plot_ui::{PlotUi, SeriesRef},
     transform::{PlotBounds, PlotTransform},
 };
 use ahash::HashMap;
@@ -57,6 +69,7 @@ use emath::Float as _;
 use axis::AxisWidget;
 use items::{horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
+use navigation::{BoundsAnimation, DRAG_SNAP_MIN_DISTANCE};
 
 type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
 pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
@@ -66,6 +79,8 @@ type GridSpacer<'a> = Box<GridSpacerFn<'a>>;
 
 type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a;
 
+type ContextMenuFn<'a> = dyn FnMut(&mut Ui, Option<Id>, PlotPoint) + 'a;
+
 /// Specifies the coordinates formatting when passed to [`Plot::coordinates_formatter`].
 pub struct CoordinatesFormatter<'a> {
     function: Box<CoordinatesFormatterFn<'a>>,
@@ -122,11 +137,51 @@ struct CursorLinkGroups(HashMap<Id, Vec<PlotFrameCursors>>);
 struct LinkedBounds {
     bounds: PlotBounds,
     auto_bounds: Vec2b,
+
+    /// The plot whose bounds change this is, so it doesn't read its own change back.
+    source: Id,
 }
 
 #[derive(Default, Clone)]
 struct BoundsLinkGroups(HashMap<Id, LinkedBounds>);
 
+/// A group of plots that pan and zoom together, see [`Plot::link_axis`].
+///
+/// The group only holds an [`Id`]; the shared bounds live in egui's temporary memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkGroup {
+    id: Id,
+}
+
+impl LinkGroup {
+    /// A link group identified by `id_salt`. Plots using the same salt share bounds.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+        }
+    }
+
+    /// The [`Id`] the shared bounds are stored under.
+    #[inline]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl From<LinkGroup> for Id {
+    #[inline]
+    fn from(group: LinkGroup) -> Self {
+        group.id
+    }
+}
+
+impl From<&LinkGroup> for Id {
+    #[inline]
+    fn from(group: &LinkGroup) -> Self {
+        group.id
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// What [`Plot::show`] returns.
@@ -150,6 +205,25 @@ pub struct PlotResponse<R> {
     /// All interaction events produced this frame
     /// empty when no events occurred.
     pub events: Vec<PlotEvent>,
+
+    /// Screen-space bounding box of what each item drew this frame, clipped to the plot frame.
+    ///
+    /// One `(item name, rect)` entry per item that drew something visible, in draw order.
+    /// Useful for UI tests and for placing overlays so they don't cover specific items.
+    pub item_rects: Vec<(String, Rect)>,
+}
+
+impl<R> PlotResponse<R> {
+    /// The pointer position in plot coordinates, or `None` when the plot isn't hovered.
+    ///
+    /// Handy for a status bar readout without going through [`Self::events`]. Follows a
+    /// segmented X axis, and is also `None` over a gap between its segments.
+    pub fn cursor_plot_pos(&self) -> Option<PlotPoint> {
+        let value = self
+            .transform
+            .value_from_position(self.response.hover_pos()?);
+        (!value.x.is_nan()).then_some(value)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -183,6 +257,10 @@ pub struct Plot<'a> {
     allow_boxed_zoom: bool,
     default_auto_bounds: Vec2b,
     min_auto_bounds: PlotBounds,
+    fixed_x_bounds: Option<(f64, f64)>,
+    fixed_y_bounds: Option<(f64, f64)>,
+    fallback_bounds: Option<PlotBounds>,
+    autoscale_suspend_frames: Option<u32>,
     margin_fraction: Vec2,
     boxed_zoom_pointer_button: PointerButton,
     linked_axes: Option<(Id, Vec2b)>,
@@ -192,6 +270,7 @@ pub struct Plot<'a> {
     width: Option<f32>,
     height: Option<f32>,
     data_aspect: Option<f32>,
+    equal_tick_spacing: bool,
     view_aspect: Option<f32>,
 
     reset: bool,
@@ -200,8 +279,10 @@ pub struct Plot<'a> {
     show_y: bool,
     label_formatter: LabelFormatter<'a>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
+    context_menu: Option<Box<ContextMenuFn<'a>>>,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
+    right_y_axis: Option<AxisHints<'a>>,
     legend_config: Option<Legend>,
     cursor_color: Option<Color32>,
     show_background: bool,
@@ -211,10 +292,17 @@ pub struct Plot<'a> {
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
     clamp_grid: bool,
+    grid_on_top: bool,
+    number_format: NumberFormat,
+    enabled_events: EventMask,
 
     sense: Sense,
+    static_mode: bool,
 
     segmented_x_axis: Option<SegmentedAxis>,
+    tick_basis: Option<TickBasis>,
+    axis_scales: [AxisScale; 2],
+    reversed_axes: Vec2b,
 
     navigation: Option<NavigationConfig>,
 }
@@ -235,6 +323,10 @@ impl<'a> Plot<'a> {
             allow_boxed_zoom: true,
             default_auto_bounds: true.into(),
             min_auto_bounds: PlotBounds::NOTHING,
+            fixed_x_bounds: None,
+            fixed_y_bounds: None,
+            fallback_bounds: None,
+            autoscale_suspend_frames: None,
             margin_fraction: Vec2::splat(0.05),
             boxed_zoom_pointer_button: PointerButton::Secondary,
             linked_axes: None,
@@ -244,6 +336,7 @@ impl<'a> Plot<'a> {
             width: None,
             height: None,
             data_aspect: None,
+            equal_tick_spacing: false,
             view_aspect: None,
 
             reset: false,
@@ -252,8 +345,10 @@ impl<'a> Plot<'a> {
             show_y: true,
             label_formatter: None,
             coordinates_formatter: None,
+            context_menu: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
+            right_y_axis: None,
             legend_config: None,
             cursor_color: None,
             show_background: true,
@@ -263,10 +358,17 @@ impl<'a> Plot<'a> {
             grid_spacing: Rangef::new(8.0, 300.0),
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
             clamp_grid: false,
+            grid_on_top: false,
+            number_format: NumberFormat::default(),
+            enabled_events: EventMask::ALL,
 
             sense: egui::Sense::click_and_drag(),
+            static_mode: false,
 
             segmented_x_axis: None,
+            tick_basis: None,
+            axis_scales: [AxisScale::Linear; 2],
+            reversed_axes: Vec2b::FALSE,
             navigation: None,
         }
     }
@@ -301,6 +403,20 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Make the same data step span the same number of pixels on both axes, and use the same
+    /// grid step on both, so the grid forms squares (e.g. for technical drawings).
+    ///
+    /// Unlike [`Self::data_aspect`] alone, which still lets each axis pick its own tick step,
+    /// this also shares the step between the axes. Implies (and overrides) `data_aspect(1.0)`.
+    /// Both axes should use the same grid spacer.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn equal_tick_spacing(mut self, on: bool) -> Self {
+        self.equal_tick_spacing = on;
+        self
+    }
+
     /// width / height ratio of the plot region.
     /// By default no fixed aspect ratio is set (and width/height will fill the ui it is in).
     #[inline]
@@ -476,6 +592,19 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Show a right-click menu built by `add_contents`.
+    ///
+    /// The menu opens where the secondary button was clicked (released without a drag). It
+    /// gets the item under the pointer, if any, and the clicked position in plot coordinates.
+    /// A secondary-button box zoom (see [`BoxZoomConfig`]) still works by dragging.
+    pub fn context_menu(
+        mut self,
+        add_contents: impl FnMut(&mut Ui, Option<Id>, PlotPoint) + 'a,
+    ) -> Self {
+        self.context_menu = Some(Box::new(add_contents));
+        self
+    }
+
     /// Configure how the grid in the background is spaced apart along the X axis.
     ///
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
@@ -521,6 +650,78 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Round grid steps to the given "nice number" ladder, on both axes.
+    ///
+    /// This replaces the grid spacers with [`nice_grid_spacer`] and is also applied to the
+    /// ticks of a segmented x-axis. Without this call the default log-10 grid is used.
+    #[inline]
+    pub fn tick_basis(mut self, basis: TickBasis) -> Self {
+        self.grid_spacers = [nice_grid_spacer(basis), nice_grid_spacer(basis)];
+        self.tick_basis = Some(basis);
+        self
+    }
+
+    /// Symmetric-log X axis: linear within `[-threshold, threshold]`, logarithmic beyond,
+    /// with decade grid marks. See [`AxisScale::Symlog`].
+    ///
+    /// Ignored while a [`SegmentedAxis`] is set.
+    #[inline]
+    pub fn x_axis_symlog(mut self, threshold: f64) -> Self {
+        self.axis_scales[0] = AxisScale::Symlog { threshold };
+        self
+    }
+
+    /// Symmetric-log Y axis, see [`Self::x_axis_symlog`].
+    #[inline]
+    pub fn y_axis_symlog(mut self, threshold: f64) -> Self {
+        self.axis_scales[1] = AxisScale::Symlog { threshold };
+        self
+    }
+
+    /// Base-10 logarithmic X axis with `1–2–5` grid marks per decade, or linear when `false`.
+    /// See [`AxisScale::Log10`].
+    ///
+    /// Auto-bounds then start at the smallest positive value. Ignored while a
+    /// [`SegmentedAxis`] is set.
+    #[inline]
+    pub fn x_axis_log10(mut self, log: bool) -> Self {
+        self.axis_scales[0] = if log {
+            AxisScale::Log10
+        } else {
+            AxisScale::Linear
+        };
+        self
+    }
+
+    /// Base-10 logarithmic Y axis, see [`Self::x_axis_log10`].
+    #[inline]
+    pub fn y_axis_log10(mut self, log: bool) -> Self {
+        self.axis_scales[1] = if log {
+            AxisScale::Log10
+        } else {
+            AxisScale::Linear
+        };
+        self
+    }
+
+    /// Reverse the X axis so larger values appear further left. Default: `false`.
+    ///
+    /// Panning and zooming still follow the pointer, and tooltips report the data values.
+    /// Ignored while a [`SegmentedAxis`] is set.
+    #[inline]
+    pub fn x_axis_reversed(mut self, reversed: bool) -> Self {
+        self.reversed_axes.x = reversed;
+        self
+    }
+
+    /// Reverse the Y axis so larger values appear further down, e.g. for depth profiles or
+    /// rankings. Default: `false`. See [`Self::x_axis_reversed`].
+    #[inline]
+    pub fn y_axis_reversed(mut self, reversed: bool) -> Self {
+        self.reversed_axes.y = reversed;
+        self
+    }
+
     /// Set when the grid starts showing.
     ///
     /// When grid lines are closer than the given minimum, they will be hidden.
@@ -541,6 +742,29 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Draw the grid on top of the items instead of behind them.
+    ///
+    /// The grid is drawn with reduced alpha so it doesn't obscure the data.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn grid_on_top(mut self, on_top: bool) -> Self {
+        self.grid_on_top = on_top;
+        self
+    }
+
+    /// Which [`PlotEvent`]s [`Self::show_actions`] reports.
+    ///
+    /// Disabled events are dropped, and the ones emitted every frame (hover, keys,
+    /// bounds changes) are skipped before they are built.
+    ///
+    /// Default: [`EventMask::ALL`].
+    #[inline]
+    pub fn enabled_events(mut self, mask: EventMask) -> Self {
+        self.enabled_events = mask;
+        self
+    }
+
     /// Set the sense for the plot rect.
     ///
     /// Default: `Sense::click_and_drag()`.
@@ -550,6 +774,19 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Draw the items, grid and axes only, skipping all input handling.
+    ///
+    /// No navigation, legend, hover labels or hotkeys, and [`Self::show_actions`] reports no
+    /// events. Meant for many small read-only plots such as sparklines, where it saves
+    /// per-frame work. Don't call the tooltip helpers of [`PlotUi`] in this mode.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn static_mode(mut self, on: bool) -> Self {
+        self.static_mode = on;
+        self
+    }
+
     /// Overwrite the starting and reset bounds used for the x axis.
     /// Set the `default_auto_bounds` of the x axis to `false`.
     ///
@@ -582,6 +819,56 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Bounds to auto-fit to when the items provide none, e.g. when every series is all-NaN
+    /// or empty. Applied per axis.
+    ///
+    /// Default: `None`, which leaves the view as computed from [`Self::include_x`] and friends.
+    #[inline]
+    pub fn fallback_bounds(mut self, bounds: PlotBounds) -> Self {
+        self.fallback_bounds = Some(bounds);
+        self
+    }
+
+    /// After the user pans or zooms, hold off autoscale for `frames` frames, then switch
+    /// auto-bounds back on (to [`Self::auto_bounds`]'s setting).
+    ///
+    /// Keeps streaming data from snapping the view back right after a zoom, without turning
+    /// autoscale off for good. Enabling auto-bounds meanwhile (from code or the fit key) or
+    /// resetting the view ends the wait early.
+    ///
+    /// Default: `None` (navigation turns auto-bounds off until reset).
+    #[inline]
+    pub fn autoscale_suspend_frames(mut self, frames: u32) -> Self {
+        self.autoscale_suspend_frames = Some(frames);
+        self
+    }
+
+    /// Pin the x axis to `min..=max` every frame.
+    ///
+    /// Unlike [`Self::default_x_bounds`], the range can't be changed by auto-bounds or
+    /// navigation: panning and zooming only act on the y axis.
+    ///
+    /// Panics in debug builds if `min >= max`.
+    #[inline]
+    pub fn set_x_bounds(mut self, min: f64, max: f64) -> Self {
+        debug_assert!(min < max, "`min` must be less than `max` in `set_x_bounds`");
+        self.fixed_x_bounds = Some((min, max));
+        self
+    }
+
+    /// Pin the y axis to `min..=max` every frame, e.g. `[0, 1]` with an auto-fitting x axis.
+    ///
+    /// Unlike [`Self::default_y_bounds`], the range can't be changed by auto-bounds or
+    /// navigation: panning and zooming only act on the x axis.
+    ///
+    /// Panics in debug builds if `min >= max`.
+    #[inline]
+    pub fn set_y_bounds(mut self, min: f64, max: f64) -> Self {
+        debug_assert!(min < max, "`min` must be less than `max` in `set_y_bounds`");
+        self.fixed_y_bounds = Some((min, max));
+        self
+    }
+
     /// Expand bounds to include the given x value.
     /// For instance, to always show the y axis, call `plot.include_x(0.0)`.
     #[inline]
@@ -642,6 +929,10 @@ impl<'a> Plot<'a> {
 
     /// Show axis labels and grid tick values on the side of the plot.
     ///
+    /// A hidden axis reserves no space: its axis widget is skipped and the plot area
+    /// extends over the whole widget rect on that side. Combine with
+    /// [`Self::show_grid`] and [`Self::show_background`] for spine-less sparklines.
+    ///
     /// Default: `true`.
     #[inline]
     pub fn show_axes(mut self, show: impl Into<Vec2b>) -> Self {
@@ -660,6 +951,9 @@ impl<'a> Plot<'a> {
 
     /// Add this plot to an axis link group so that this plot will share the bounds with other plots in the
     /// same group. A plot cannot belong to more than one axis group.
+    ///
+    /// `group_id` is usually a [`LinkGroup`]. Each bounds change (see [`PlotEvent::BoundsChanged`])
+    /// is published to the group and picked up by the other members on their next frame.
     #[inline]
     pub fn link_axis(mut self, group_id: impl Into<Id>, link: impl Into<Vec2b>) -> Self {
         self.linked_axes = Some((group_id.into(), link.into()));
@@ -740,6 +1034,7 @@ impl<'a> Plot<'a> {
     ) -> Self {
         if let Some(main) = self.x_axes.first_mut() {
             main.formatter = Arc::new(fmt);
+            main.custom_formatter = true;
         }
         self
     }
@@ -755,6 +1050,7 @@ impl<'a> Plot<'a> {
     ) -> Self {
         if let Some(main) = self.y_axes.first_mut() {
             main.formatter = Arc::new(fmt);
+            main.custom_formatter = true;
         }
         self
     }
@@ -777,6 +1073,26 @@ impl<'a> Plot<'a> {
         self.y_axis_min_width(12.0 * digits as f32)
     }
 
+    /// Set the decimal separator used by the default axis, tooltip and pins formatting,
+    /// e.g. `','` for `1234,56`.
+    ///
+    /// Custom formatters are not affected. Default: `'.'`.
+    #[inline]
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.number_format.decimal_separator = separator;
+        self
+    }
+
+    /// Set the thousands separator used by the default axis, tooltip and pins formatting,
+    /// e.g. `Some('.')` for `1.234,56`.
+    ///
+    /// Custom formatters are not affected. Default: `None`.
+    #[inline]
+    pub fn thousands_separator(mut self, separator: Option<char>) -> Self {
+        self.number_format.thousands_separator = separator;
+        self
+    }
+
     /// Set custom configuration for X-axis
     ///
     /// More than one axis may be specified. The first specified axis is considered the main axis.
@@ -795,6 +1111,20 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add a secondary Y axis with an independent scale, drawn on the right of the plot.
+    ///
+    /// Items opt in with `on_right_axis(true)` (e.g. [`Line::on_right_axis`]); they share X
+    /// with the rest of the plot. The secondary axis is linear and auto-fits to those items
+    /// along with the primary Y, and pans and zooms together with it.
+    ///
+    /// Its ticks come from the primary [`Self::y_grid_spacer`], applied to the secondary
+    /// range; there is no separate spacer for it.
+    #[inline]
+    pub fn right_y_axis(mut self, hints: AxisHints<'a>) -> Self {
+        self.right_y_axis = Some(hints);
+        self
+    }
+
     /// Set custom cursor color.
     ///
     /// You may set the color to [`Color32::TRANSPARENT`] to hide the cursors.
@@ -831,19 +1161,26 @@ impl<'a> Plot<'a> {
             boxed_zoom_pointer_button,
             default_auto_bounds,
             min_auto_bounds,
+            fixed_x_bounds,
+            fixed_y_bounds,
+            fallback_bounds,
+            autoscale_suspend_frames,
             margin_fraction,
             width,
             height,
             mut min_size,
-            data_aspect,
+            mut data_aspect,
+            equal_tick_spacing,
             view_aspect,
             mut show_x,
             mut show_y,
             label_formatter,
-            coordinates_formatter,
+            mut coordinates_formatter,
+            mut context_menu,
             x_axes,
             y_axes,
-            legend_config,
+            right_y_axis,
+            mut legend_config,
             cursor_color,
             reset,
             show_background,
@@ -853,12 +1190,47 @@ impl<'a> Plot<'a> {
             linked_axes,
             linked_cursors,
             clamp_grid,
+            grid_on_top,
+            number_format,
+            mut enabled_events,
             grid_spacers,
-            sense,
-            segmented_x_axis,
+            mut sense,
+            static_mode,
+            mut segmented_x_axis,
+            tick_basis,
+            axis_scales,
+            reversed_axes,
             navigation,
         } = self;
 
+        if let (Some(segmented), Some(basis)) = (&mut segmented_x_axis, tick_basis) {
+            segmented.tick_basis = basis;
+        }
+        if equal_tick_spacing {
+            data_aspect = Some(1.0);
+        }
+
+        let max_ticks = [
+            x_axes.iter().filter_map(|h| h.max_ticks).min(),
+            y_axes.iter().filter_map(|h| h.max_ticks).min(),
+        ];
+
+        let x_axes: Vec<_> = x_axes
+            .into_iter()
+            .map(|hints| hints.localized(number_format))
+            .collect();
+        let mut y_axes: Vec<_> = y_axes
+            .into_iter()
+            .map(|hints| hints.localized(number_format))
+            .collect();
+        // The secondary axis goes last, always on the right.
+        let secondary_axis =
+            right_y_axis.map(|hints| hints.placement(HPlacement::Right).localized(number_format));
+        let secondary_index = secondary_axis.is_some().then_some(y_axes.len());
+        y_axes.extend(secondary_axis);
+        // Right-axis items don't count towards the primary Y.
+        let on_primary_y = |item: &dyn PlotItem| secondary_index.is_none() || !item.on_right_axis();
+
         let mut nav = if let Some(cfg) = navigation {
             cfg
         } else {
@@ -875,13 +1247,43 @@ impl<'a> Plot<'a> {
 
         // Disable interaction if ui is disabled.
         let ui_enabled = ui.is_enabled();
-        if !ui_enabled {
+        if !ui_enabled || static_mode {
             nav.drag.enabled = false;
             nav.scroll.enabled = false;
             nav.zoom.enabled = false;
             nav.double_click_reset = false;
             nav.box_zoom.enabled = false;
         }
+        if static_mode {
+            nav.axis_zoom_drag = Vec2b::FALSE;
+            nav.pinning_enabled = false;
+            nav.fit_to_view_key = None;
+            nav.measure = None;
+            nav.box_select = None;
+            sense = Sense::hover();
+            enabled_events = EventMask::NONE;
+            legend_config = None;
+            coordinates_formatter = None;
+            show_x = false;
+            show_y = false;
+        }
+
+        // Pinned axes can't be navigated.
+        let pinned = Vec2b::new(fixed_x_bounds.is_some(), fixed_y_bounds.is_some());
+        nav.drag.axis = nav.drag.axis.and(!pinned);
+        nav.scroll.axis = nav.scroll.axis.and(!pinned);
+        nav.zoom.axis = nav.zoom.axis.and(!pinned);
+        nav.axis_zoom_drag = nav.axis_zoom_drag.and(!pinned);
+        let pin_bounds = |bounds: &mut PlotBounds| {
+            if let Some((min, max)) = fixed_x_bounds {
+                bounds.min[0] = min;
+                bounds.max[0] = max;
+            }
+            if let Some((min, max)) = fixed_y_bounds {
+                bounds.min[1] = min;
+                bounds.max[1] = max;
+            }
+        };
 
         // Determine position of widget.
         let pos = ui.available_rect_before_wrap().min;
@@ -937,10 +1339,15 @@ impl<'a> Plot<'a> {
         }
 
         // Axis hit-areas for axis-zoom-drag
+        let axis_sense = if static_mode {
+            Sense::hover()
+        } else {
+            Sense::drag()
+        };
         let x_axis_responses = x_axis_widgets
             .iter()
             .map(|widget| {
-                let axis_resp = ui.allocate_rect(widget.rect, Sense::drag());
+                let axis_resp = ui.allocate_rect(widget.rect, axis_sense);
                 if nav.axis_zoom_drag.x {
                     axis_resp.on_hover_cursor(CursorIcon::ResizeHorizontal)
                 } else {
@@ -952,7 +1359,7 @@ impl<'a> Plot<'a> {
         let y_axis_responses = y_axis_widgets
             .iter()
             .map(|widget| {
-                let axis_resp = ui.allocate_rect(widget.rect, Sense::drag());
+                let axis_resp = ui.allocate_rect(widget.rect, axis_sense);
                 if nav.axis_zoom_drag.y {
                     axis_resp.on_hover_cursor(CursorIcon::ResizeVertical)
                 } else {
@@ -983,6 +1390,20 @@ impl<'a> Plot<'a> {
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
             original_bounds: None,
+            dragged_point: None,
+            last_scroll_source: None,
+            measure: None,
+            select_start: None,
+            drag_snap: None,
+            pan_drag: None,
+            context_menu_at: None,
+            autoscale_suspended_since: None,
+            bounds_animation: None,
+        });
+
+        // A click placing a measure point belongs to the measure tool alone.
+        let measure_click = nav.measure.is_some_and(|(button, mods)| {
+            response.clicked_by(button) && modifiers_ok(ui.input(|i| i.modifiers), mods)
         });
 
         let last_plot_transform = mem.transform.clone();
@@ -996,6 +1417,12 @@ impl<'a> Plot<'a> {
             response: response.clone(),
             called_once: false,
             navigation: nav,
+            number_format,
+            forced_highlights: Vec::new(),
+            screen_overlays: Vec::new(),
+            simulated_hover: None,
+            click_consumed: measure_click,
+            readout_formatters: [None, None],
         };
 
         let inner = build_fn(&mut plot_ui);
@@ -1005,6 +1432,10 @@ impl<'a> Plot<'a> {
             response: _,
             last_plot_transform,
             last_auto_bounds,
+            forced_highlights,
+            screen_overlays,
+            click_consumed,
+            readout_formatters,
             ..
         } = plot_ui;
 
@@ -1036,7 +1467,9 @@ impl<'a> Plot<'a> {
 
         // IMPORTANT: create events ONCE here and keep pushing into it
         let mut events = applied.events;
-        let mut last_user_cause: Option<BoundsChangeCause> = None;
+        // Bounds set from `PlotUi` count as programmatic unless navigation moves them further.
+        let mut last_user_cause =
+            (bounds != *last_plot_transform.bounds()).then_some(BoundsChangeCause::Programmatic);
 
         // Legend filtering/highlighting
         let legend = legend_config
@@ -1055,6 +1488,13 @@ impl<'a> Plot<'a> {
                 .filter(|entry| &entry.id() == item_id)
                 .for_each(|entry| entry.highlight());
         }
+        // Highlight the items requested with `PlotUi::highlight_series`.
+        for series in &forced_highlights {
+            items
+                .iter_mut()
+                .filter(|entry| series.matches(&***entry))
+                .for_each(|entry| entry.highlight());
+        }
         // Move highlighted items to front.
         items.sort_by_key(|it| it.highlighted());
 
@@ -1089,7 +1529,12 @@ impl<'a> Plot<'a> {
         if let Some((id, axes)) = linked_axes.as_ref() {
             ui.data_mut(|data| {
                 let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
-                if let Some(linked_bounds) = link_groups.0.get(id) {
+                let linked = link_groups
+                    .0
+                    .get(id)
+                    .filter(|linked| linked.source != plot_id);
+                if let Some(linked_bounds) = linked {
+                    let before = bounds;
                     if axes.x {
                         bounds.set_x(&linked_bounds.bounds);
                         mem.auto_bounds.x = linked_bounds.auto_bounds.x;
@@ -1098,6 +1543,9 @@ impl<'a> Plot<'a> {
                         bounds.set_y(&linked_bounds.bounds);
                         mem.auto_bounds.y = linked_bounds.auto_bounds.y;
                     }
+                    if bounds != before {
+                        last_user_cause = Some(BoundsChangeCause::LinkSync);
+                    }
                 }
             });
         }
@@ -1121,6 +1569,25 @@ impl<'a> Plot<'a> {
             }
         }
 
+        if let (Some(frames), Some(since)) =
+            (autoscale_suspend_frames, mem.autoscale_suspended_since)
+        {
+            if mem.auto_bounds == default_auto_bounds
+                || last_user_cause == Some(BoundsChangeCause::Reset)
+            {
+                // Auto-bounds are back on (from code or a fit), or the view was reset:
+                // either way there is nothing left to restore.
+                mem.autoscale_suspended_since = None;
+            } else if ui.ctx().cumulative_pass_nr() >= since + u64::from(frames) {
+                mem.autoscale_suspended_since = None;
+                mem.auto_bounds = default_auto_bounds;
+            } else {
+                // Keep counting frames while the app is idle.
+                ui.ctx().request_repaint();
+            }
+        }
+
+        mem.auto_bounds = mem.auto_bounds.and(!pinned);
         if mem.auto_bounds.x {
             bounds.set_x(&min_auto_bounds);
         }
@@ -1137,24 +1604,58 @@ impl<'a> Plot<'a> {
                 if auto_x {
                     bounds.merge_x(&b);
                 }
-                if auto_y {
+                if auto_y && on_primary_y(&**it) {
                     bounds.merge_y(&b);
                 }
             }
+            if let Some(fallback) = &fallback_bounds {
+                if auto_x && !bounds.is_valid_x() {
+                    bounds.set_x(fallback);
+                }
+                if auto_y && !bounds.is_valid_y() {
+                    bounds.set_y(fallback);
+                }
+            }
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                if segmented_x_axis.is_some() || axis_scales[0].is_linear() {
+                    bounds.add_relative_margin_x(margin_fraction);
+                } else {
+                    fit_scaled_axis(
+                        &mut bounds,
+                        &items,
+                        Axis::X,
+                        axis_scales[0],
+                        margin_fraction.x,
+                    );
+                }
             }
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                if axis_scales[1].is_linear() {
+                    bounds.add_relative_margin_y(margin_fraction);
+                } else {
+                    fit_scaled_axis(
+                        &mut bounds,
+                        &items,
+                        Axis::Y,
+                        axis_scales[1],
+                        margin_fraction.y,
+                    );
+                }
             }
             events.push(PlotEvent::AutoFitApplied { new: bounds });
             last_user_cause.get_or_insert(BoundsChangeCause::AutoFit);
         }
 
+        pin_bounds(&mut bounds);
+
         // Build transform
         mem.transform = PlotTransform::new(plot_rect, bounds, center_axis);
 
         mem.transform.set_segment_xaxis(segmented_x_axis);
+        mem.transform.set_scale(Axis::X, axis_scales[0]);
+        mem.transform.set_scale(Axis::Y, axis_scales[1]);
+        mem.transform.set_reversed(Axis::X, reversed_axes.x);
+        mem.transform.set_reversed(Axis::Y, reversed_axes.y);
 
         if last_plot_transform.segment_xaxis().is_some() && mem.transform.segment_xaxis().is_some()
         {
@@ -1162,6 +1663,24 @@ impl<'a> Plot<'a> {
                 .set_segment_x_offset(last_plot_transform.segment_x_offset());
         }
 
+        // Secondary Y: keep last frame's mapping so it follows navigation, refit it along
+        // with the primary Y.
+        if secondary_index.is_some() {
+            mem.transform
+                .set_secondary_y_map(last_plot_transform.secondary_y_map());
+            if auto_y || mem.transform.secondary_y_map().is_none() {
+                let mut right = PlotBounds::NOTHING;
+                for item in items.iter().filter(|item| item.on_right_axis()) {
+                    right.merge_y(&item.bounds());
+                }
+                if right.is_valid_y() {
+                    right.add_relative_margin_y(margin_fraction);
+                    mem.transform
+                        .set_secondary_y_range(right.min[1], right.max[1]);
+                }
+            }
+        }
+
         // Aspect
         if let Some(data_aspect) = data_aspect {
             if let Some((_, linked_axes)) = &linked_axes {
@@ -1178,11 +1697,138 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // Draggable points: grabbing a handle takes over the primary drag (no panning).
+        if response.drag_started_by(PointerButton::Primary) {
+            let grab_radius = ui.style().interaction.interact_radius;
+            mem.dragged_point = ui.input(|i| i.pointer.press_origin()).and_then(|origin| {
+                items
+                    .iter_mut()
+                    .filter(|item| item.allow_hover())
+                    .filter_map(|item| {
+                        let id = item.id();
+                        let handle = item.drag_handle_mut()?;
+                        let dist = mem
+                            .transform
+                            .position_from_point(&handle.pos)
+                            .distance(origin);
+                        (dist <= handle.radius.max(grab_radius)).then_some((id, dist))
+                    })
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(id, _)| id)
+            });
+        }
+        let mut dragging_point = false;
+        if let Some(dragged_id) = mem.dragged_point {
+            if response.dragged_by(PointerButton::Primary) {
+                dragging_point = true;
+                response = response.on_hover_cursor(CursorIcon::Grabbing);
+
+                let snap = items
+                    .iter_mut()
+                    .find(|item| item.id() == dragged_id)
+                    .and_then(|item| item.drag_handle_mut().map(|h| h.snap_to_data));
+                if let (Some(snap), Some(pointer)) = (snap, response.interact_pointer_pos()) {
+                    let mut pos = mem.transform.value_from_position(pointer);
+                    let mut snapped_to = None;
+                    if snap {
+                        let hit = items::pick_nearest(
+                            items
+                                .iter()
+                                .filter(|item| item.id() != dragged_id)
+                                .map(|item| &**item),
+                            &mem.transform,
+                            pointer,
+                            ui.style().interaction.interact_radius,
+                            Color32::TRANSPARENT,
+                        );
+                        if let Some(hit) = hit {
+                            // `hit.value` is un-offset and in the item's own Y axis; map it back
+                            // to the primary plot space the handle lives in.
+                            let item = items.iter().find(|item| item.id() == hit.series_id);
+                            let mut y = hit.value.y + item.map_or(0.0, |item| item.y_offset());
+                            if item.is_some_and(|item| item.on_right_axis()) {
+                                y = mem.transform.primary_y_from_secondary(y);
+                            }
+                            pos = PlotPoint::new(hit.value.x, y);
+                            snapped_to = Some(hit.series_name);
+                        }
+                    }
+                    if let Some(handle) = items
+                        .iter_mut()
+                        .find(|item| item.id() == dragged_id)
+                        .and_then(|item| item.drag_handle_mut())
+                    {
+                        handle.pos = pos;
+                    }
+                    events.push(PlotEvent::PointDragged {
+                        id: dragged_id,
+                        pos,
+                        snapped_to,
+                    });
+                }
+            } else {
+                mem.dragged_point = None;
+            }
+        }
+
+        // Box selection: drag a rectangle, report the stats of the samples inside on release.
+        let mut selection_rect = None;
+        let selecting = nav.box_select.is_some_and(|(button, mods)| {
+            response.dragged_by(button) && modifiers_ok(ui.input(|i| i.modifiers), mods)
+        });
+        if selecting {
+            if response.drag_started() {
+                mem.select_start = ui.input(|i| i.pointer.press_origin());
+            }
+            if let (Some(start), Some(end)) = (mem.select_start, response.hover_pos()) {
+                selection_rect = Some(Rect::from_two_pos(start, end));
+            }
+        }
+        if nav
+            .box_select
+            .is_some_and(|(button, _)| response.drag_stopped_by(button))
+        {
+            if let (Some(start), Some(end)) = (mem.select_start.take(), response.hover_pos()) {
+                let (s_val, e_val) = (
+                    mem.transform.value_from_position(start),
+                    mem.transform.value_from_position(end),
+                );
+                let bounds = PlotBounds::from_min_max(
+                    [s_val.x.min(e_val.x), s_val.y.min(e_val.y)],
+                    [s_val.x.max(e_val.x), s_val.y.max(e_val.y)],
+                );
+                events.push(PlotEvent::SelectionStats {
+                    bounds,
+                    per_series: items::selection_stats(items.iter().map(|item| &**item), &bounds),
+                });
+            }
+        }
+
         // Pan
-        if nav.drag.enabled
+        let panning = nav.drag.enabled
+            && !dragging_point
+            && !selecting
+            && !click_consumed
             && (nav.drag.axis.x || nav.drag.axis.y)
-            && response.dragged_by(PointerButton::Primary)
-        {
+            && response.dragged_by(PointerButton::Primary);
+        if panning && mem.pan_drag.is_none() {
+            if let Some(origin) = ui.input(|i| i.pointer.press_origin()) {
+                let start_pos = mem.transform.value_from_position(origin);
+                mem.pan_drag = Some((origin, start_pos));
+                events.push(PlotEvent::DragStarted { start_pos });
+            }
+        } else if !panning {
+            if let Some((origin, start_pos)) = mem.pan_drag.take() {
+                let end = ui.input(|i| i.pointer.latest_pos()).unwrap_or(origin);
+                let d = mem.transform.dvalue_dpos();
+                let end_pos = PlotPoint::new(
+                    start_pos.x + f64::from(end.x - origin.x) * d[0],
+                    start_pos.y + f64::from(end.y - origin.y) * d[1],
+                );
+                events.push(PlotEvent::DragEnded { start_pos, end_pos });
+            }
+        }
+        if panning {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
 
             if response.drag_started() {
@@ -1195,11 +1841,38 @@ impl<'a> Plot<'a> {
                 });
             }
 
+            // Decide the snapped axis once per drag, as soon as the pointer has moved far
+            // enough from the press origin to tell the direction. Until then, hold still.
+            if response.drag_started() {
+                mem.drag_snap = None;
+            }
             let mut delta = -response.drag_delta();
-            if !nav.drag.axis.x {
+            if let (Some(snap_deg), None) = (nav.drag_snap_deg, mem.drag_snap) {
+                let motion = ui
+                    .input(|i| i.pointer.press_origin())
+                    .zip(response.interact_pointer_pos())
+                    .map_or(Vec2::ZERO, |(origin, pos)| pos - origin);
+                if motion.length() < DRAG_SNAP_MIN_DISTANCE {
+                    delta = Vec2::ZERO;
+                } else {
+                    let angle = motion.y.abs().atan2(motion.x.abs()).to_degrees();
+                    mem.drag_snap = Some(if angle <= snap_deg {
+                        Vec2b::new(true, false)
+                    } else if angle >= 90.0 - snap_deg {
+                        Vec2b::new(false, true)
+                    } else {
+                        Vec2b::TRUE
+                    });
+                    // Catch up with the motion held back while undecided.
+                    delta = -motion;
+                }
+            }
+            let drag_axes = nav.drag.axis.and(mem.drag_snap.unwrap_or(Vec2b::TRUE));
+
+            if !drag_axes.x {
                 delta.x = 0.0;
             }
-            if !nav.drag.axis.y {
+            if !drag_axes.y {
                 delta.y = 0.0;
             }
 
@@ -1222,7 +1895,7 @@ impl<'a> Plot<'a> {
                     .translate_bounds((delta.x as f64, delta.y as f64));
             }
 
-            mem.auto_bounds = mem.auto_bounds.and(!nav.drag.axis);
+            mem.auto_bounds = mem.auto_bounds.and(!drag_axes);
             last_user_cause = Some(BoundsChangeCause::Pan);
 
             if response.drag_stopped() {
@@ -1308,17 +1981,9 @@ impl<'a> Plot<'a> {
             }
         }
 
-        // Boxed zoom
+        // Boxed zoom (a box selection with the same button and modifiers takes precedence)
         let mut boxed_zoom_rect = None;
-        if nav.box_zoom.enabled {
-            let modifiers_ok = |cur: Modifiers, req: Modifiers| -> bool {
-                (!req.alt || cur.alt)
-                    && (!req.ctrl || cur.ctrl)
-                    && (!req.shift || cur.shift)
-                    && (!req.command || cur.command)
-                    && (!req.mac_cmd || cur.mac_cmd)
-            };
-
+        if nav.box_zoom.enabled && !selecting && !click_consumed {
             if response.drag_started()
                 && response.dragged_by(nav.box_zoom.button)
                 && modifiers_ok(ui.input(|i| i.modifiers), nav.box_zoom.required_mods)
@@ -1340,7 +2005,7 @@ impl<'a> Plot<'a> {
                     && modifiers_ok(ui.input(|i| i.modifiers), nav.box_zoom.required_mods)
                 {
                     response = response.on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(s, e);
+                    let (rect, _) = nav.box_zoom.zoom_rect(s, e, plot_rect);
                     boxed_zoom_rect = Some((
                         epaint::RectShape::stroke(
                             rect,
@@ -1358,15 +2023,24 @@ impl<'a> Plot<'a> {
                 }
 
                 if response.drag_stopped() {
-                    let s_val = mem.transform.value_from_position(s);
-                    let e_val = mem.transform.value_from_position(e);
-                    let new_bounds = PlotBounds {
+                    let (rect, zoomed) = nav.box_zoom.zoom_rect(s, e, plot_rect);
+                    let s_val = mem.transform.value_from_position(rect.left_bottom());
+                    let e_val = mem.transform.value_from_position(rect.right_top());
+                    let mut new_bounds = PlotBounds {
                         min: [s_val.x.min(e_val.x), s_val.y.min(e_val.y)],
                         max: [s_val.x.max(e_val.x), s_val.y.max(e_val.y)],
                     };
+                    let current = *mem.transform.bounds();
+                    if !zoomed.x {
+                        new_bounds.set_x(&current);
+                    }
+                    if !zoomed.y {
+                        new_bounds.set_y(&current);
+                    }
                     if new_bounds.is_valid() {
                         mem.transform.set_bounds(new_bounds);
-                        mem.auto_bounds = false.into();
+                        mem.auto_bounds.x &= !zoomed.x;
+                        mem.auto_bounds.y &= !zoomed.y;
                         let new_x = new_bounds.range_x();
                         let new_y = new_bounds.range_y();
                         events.push(PlotEvent::BoxZoomFinished {
@@ -1448,8 +2122,65 @@ impl<'a> Plot<'a> {
                 }
             }
 
+            // Scroll: pan or zoom, depending on the device the scroll came from.
+            // The smoothed delta outlives the raw events, so the last seen source is remembered.
+            if let Some(source) = ui.input(|i| {
+                i.events.iter().rev().find_map(|e| match e {
+                    egui::Event::MouseWheel { unit, .. } => Some(match unit {
+                        egui::MouseWheelUnit::Point => navigation::ScrollSource::Touchpad,
+                        egui::MouseWheelUnit::Line | egui::MouseWheelUnit::Page => {
+                            navigation::ScrollSource::Wheel
+                        }
+                    }),
+                    _ => None,
+                })
+            }) {
+                mem.last_scroll_source = Some(source);
+            }
+            let scroll_action = mem
+                .last_scroll_source
+                .map_or(ScrollAction::Pan, |source| nav.scroll.action(source));
+
+            if nav.scroll.enabled
+                && scroll_action == ScrollAction::Zoom
+                && nav.zoom.enabled
+                && (nav.zoom.axis.x || nav.zoom.axis.y)
+            {
+                let scroll = ui.input(|i| i.smooth_scroll_delta);
+                // Same scale egui uses to turn a ctrl+wheel into a zoom factor.
+                let factor = (scroll.y / 200.0).exp();
+                if factor != 1.0 {
+                    let z = Vec2::new(
+                        if nav.zoom.axis.x { factor } else { 1.0 },
+                        if nav.zoom.axis.y { factor } else { 1.0 },
+                    );
+                    let center = if nav.zoom.zoom_to_mouse {
+                        hover_pos
+                    } else {
+                        plot_rect.center()
+                    };
+                    mem.transform.zoom(z, center);
+                    events.push(PlotEvent::ZoomDelta {
+                        factor_x: z.x,
+                        factor_y: z.y,
+                        center_plot_x: mem.transform.value_from_position(center).x,
+                        center_plot_y: mem.transform.value_from_position(center).y,
+                        input: InputInfo {
+                            pointer: Some(hover_pos),
+                            button: None,
+                            modifiers: ui.input(|i| i.modifiers),
+                        },
+                    });
+                    last_user_cause = Some(BoundsChangeCause::Zoom);
+                    mem.auto_bounds = mem.auto_bounds.and(!nav.zoom.axis);
+                }
+            }
+
             // Scroll pan
-            if nav.scroll.enabled && (nav.scroll.axis.x || nav.scroll.axis.y) {
+            if nav.scroll.enabled
+                && scroll_action == ScrollAction::Pan
+                && (nav.scroll.axis.x || nav.scroll.axis.y)
+            {
                 let mut scroll = ui.input(|i| i.smooth_scroll_delta);
                 if !nav.scroll.axis.x {
                     scroll.x = 0.0;
@@ -1470,25 +2201,62 @@ impl<'a> Plot<'a> {
             }
         }
 
-        // --- transform initialized
-
+        // Slide the view back inside the navigation limits.
+        if nav.clamp_enabled {
+            let limit = nav.clamp_bounds.unwrap_or_else(|| {
+                let mut data = PlotBounds::NOTHING;
+                for item in &items {
+                    data.merge(&item.bounds());
+                }
+                data
+            });
+            let mut bounds = *mem.transform.bounds();
+            bounds.slide_within(&limit);
+            mem.transform.set_bounds(bounds);
+        }
+
+        // Box zoom and aspect locking may have moved a pinned axis; put it back.
+        if pinned.any() {
+            let mut bounds = *mem.transform.bounds();
+            pin_bounds(&mut bounds);
+            mem.transform.set_bounds(bounds);
+        }
+
+        // Ease towards the new bounds instead of jumping to them.
+        if nav.animation.is_zero() {
+            mem.bounds_animation = None;
+        } else {
+            animate_bounds(
+                &mut mem,
+                last_plot_transform.bounds(),
+                last_user_cause,
+                ui,
+                nav.animation,
+            );
+        }
+
+        // --- transform initialized
+
         // Add legend widgets to plot
         let bounds_now = mem.transform.bounds();
         let x_axis_range = bounds_now.range_x();
+        let step_scale = grid_step_scale(&mem.transform, equal_tick_spacing);
         let x_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds_now.min[0], bounds_now.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
+                base_step_size: step_scale[0] * grid_spacing.min as f64,
             };
-            (grid_spacers[0])(input)
+            let scale = mem.transform.scale(Axis::X);
+            grid_marks(&grid_spacers[0], scale, input, max_ticks[0])
         });
         let y_axis_range = bounds_now.range_y();
         let y_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds_now.min[1], bounds_now.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
+                base_step_size: step_scale[1] * grid_spacing.min as f64,
             };
-            (grid_spacers[1])(input)
+            let scale = mem.transform.scale(Axis::Y);
+            grid_marks(&grid_spacers[1], scale, input, max_ticks[1])
         });
 
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
@@ -1498,10 +2266,35 @@ impl<'a> Plot<'a> {
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
         }
+        let secondary_transform = mem.transform.secondary();
+        // The secondary axis is always linear and reuses the primary Y spacer.
+        let secondary_steps = secondary_transform.as_ref().map(|transform| {
+            let bounds = transform.bounds();
+            let input = GridInput {
+                bounds: (bounds.min[1], bounds.max[1]),
+                base_step_size: transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
+            };
+            Arc::new(grid_marks(
+                &grid_spacers[1],
+                AxisScale::Linear,
+                input,
+                max_ticks[1],
+            ))
+        });
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform.clone());
-            widget.steps = y_steps.clone();
+            if let (true, Some(transform), Some(steps)) = (
+                secondary_index == Some(i),
+                &secondary_transform,
+                &secondary_steps,
+            ) {
+                widget.range = transform.bounds().range_y();
+                widget.transform = Some(transform.clone());
+                widget.steps = steps.clone();
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform.clone());
+                widget.steps = y_steps.clone();
+            }
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
@@ -1510,6 +2303,22 @@ impl<'a> Plot<'a> {
             item.initialize(mem.transform.bounds().range_x());
         }
 
+        // Resolve what a click activates with a full 2D nearest search, before the items are
+        // handed over to the prepared plot.
+        let activate_pick = if response.clicked() && !click_consumed {
+            response.interact_pointer_pos().and_then(|pos| {
+                items::pick_nearest_indexed(
+                    items.iter().map(|item| &**item),
+                    &mem.transform,
+                    pos,
+                    ui.style().interaction.interact_radius,
+                    ui.visuals().text_color(),
+                )
+            })
+        } else {
+            None
+        };
+
         // Draw items/grid/tooltip
         let prepared: PreparedPlot<'_, '_> = PreparedPlot {
             plot_area_response: &response,
@@ -1518,6 +2327,8 @@ impl<'a> Plot<'a> {
             show_y,
             label_formatter,
             coordinates_formatter,
+            readout_formatters,
+            number_format,
             show_grid,
             grid_spacing,
             transform: mem.transform.clone(),
@@ -1526,25 +2337,103 @@ impl<'a> Plot<'a> {
             draw_cursors,
             cursor_color,
             grid_spacers,
+            max_ticks,
             clamp_grid,
+            grid_on_top,
+            static_mode,
+            equal_tick_spacing,
         };
 
-        let (plot_cursors, mut hovered_plot_item) = prepared.ui(ui, &response);
+        let (plot_cursors, mut hovered_plot_item, item_rects) = prepared.ui(ui, &response);
 
         // Click/Context menu -> events
-        if response.clicked() {
+        if response.clicked() && !click_consumed {
             events.push(PlotEvent::Activate {
-                hovered_item: hovered_plot_item,
+                hovered_item: activate_pick
+                    .as_ref()
+                    .map(|(hit, _)| hit.series_id)
+                    .or(hovered_plot_item),
             });
+            if let Some((hit, index)) = activate_pick {
+                events.push(PlotEvent::ItemClicked {
+                    item: hit.series_id,
+                    series_name: hit.series_name,
+                    index,
+                    value: hit.value,
+                });
+            }
         }
         if response.secondary_clicked() {
             if let Some(screen_pos) = ui.input(|i| i.pointer.hover_pos()) {
+                mem.context_menu_at = Some((
+                    hovered_plot_item,
+                    mem.transform.value_from_position(screen_pos),
+                ));
                 events.push(PlotEvent::ContextMenuRequested {
                     screen_pos,
                     item: hovered_plot_item,
                 });
             }
         }
+        if let (Some(add_contents), Some((item, pos))) = (&mut context_menu, mem.context_menu_at) {
+            response.context_menu(|ui| add_contents(ui, item, pos));
+        }
+
+        // Measure ruler
+        if nav.measure.is_some() {
+            if measure_click {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    let value = mem.transform.value_from_position(pos);
+                    mem.measure = match mem.measure {
+                        Some((start, None)) => {
+                            events.push(PlotEvent::MeasureUpdated { start, end: value });
+                            Some((start, Some(value)))
+                        }
+                        _ => Some((value, None)),
+                    };
+                }
+            }
+            if response.contains_pointer() && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                mem.measure = None;
+            }
+            if let Some((start, end)) = mem.measure {
+                // Until the second click, the ruler follows the pointer.
+                let end = end.or_else(|| {
+                    response
+                        .hover_pos()
+                        .map(|pos| mem.transform.value_from_position(pos))
+                });
+                if let Some(end) = end {
+                    paint_measure_ruler(ui, &mem.transform, start, end, number_format);
+                }
+            }
+        } else {
+            mem.measure = None;
+        }
+
+        // Screen-space overlays, anchored to the plot frame.
+        for (anchor, add_contents) in screen_overlays {
+            let layout = Layout::top_down(anchor.x()).with_main_align(anchor.y());
+            let mut overlay_ui = ui.new_child(
+                egui::UiBuilder::new()
+                    .max_rect(plot_rect.shrink(4.0))
+                    .layout(layout),
+            );
+            overlay_ui.set_clip_rect(plot_rect);
+            add_contents(&mut overlay_ui);
+        }
+
+        // Draw box selection
+        if let Some(rect) = selection_rect {
+            let color = ui.visuals().selection.bg_fill;
+            ui.painter().with_clip_rect(plot_rect).rect(
+                rect,
+                0.0,
+                color.gamma_multiply(0.25),
+                Stroke::new(1.0, color),
+                egui::StrokeKind::Middle,
+            );
+        }
 
         // Draw boxed zoom preview
         if let Some((outer, inner)) = boxed_zoom_rect {
@@ -1576,20 +2465,6 @@ impl<'a> Plot<'a> {
             });
         }
 
-        if let Some((id, _)) = linked_axes.as_ref() {
-            // Save the linked bounds.
-
-            ui.data_mut(|data| {
-                let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
-                link_groups.0.insert(
-                    *id,
-                    LinkedBounds {
-                        bounds: *mem.transform.bounds(),
-                        auto_bounds: mem.auto_bounds,
-                    },
-                );
-            });
-        }
         if response.has_focus() || response.contains_pointer() {
             let pressed = |k: egui::Key| ui.ctx().input(|i| i.key_pressed(k));
             let released = |k: egui::Key| ui.ctx().input(|i| i.key_released(k));
@@ -1604,13 +2479,13 @@ impl<'a> Plot<'a> {
                 egui::Key::ArrowUp,
                 egui::Key::ArrowDown,
             ] {
-                if pressed(k) {
+                if enabled_events.contains(EventMask::KEY_PRESSED) && pressed(k) {
                     events.push(PlotEvent::KeyPressed {
                         key: k,
                         modifiers: mods,
                     });
                 }
-                if released(k) {
+                if enabled_events.contains(EventMask::KEY_RELEASED) && released(k) {
                     events.push(PlotEvent::KeyReleased {
                         key: k,
                         modifiers: mods,
@@ -1623,6 +2498,15 @@ impl<'a> Plot<'a> {
                 if ui.ctx().input(|i| i.key_pressed(k)) {
                     mem.auto_bounds = true.into();
                     last_user_cause = Some(BoundsChangeCause::AutoFit);
+                    if !nav.animation.is_zero() {
+                        // The fit lands next frame; the animation retargets to it there.
+                        let current = *mem.transform.bounds();
+                        mem.bounds_animation = Some(BoundsAnimation {
+                            from: current,
+                            to: current,
+                            start_time: ui.input(|i| i.time),
+                        });
+                    }
                 }
             }
 
@@ -1658,12 +2542,44 @@ impl<'a> Plot<'a> {
             }
         }
 
+        if autoscale_suspend_frames.is_some()
+            && matches!(
+                last_user_cause,
+                Some(
+                    BoundsChangeCause::Pan
+                        | BoundsChangeCause::Zoom
+                        | BoundsChangeCause::AxisZoomX
+                        | BoundsChangeCause::AxisZoomY
+                        | BoundsChangeCause::BoxZoom
+                )
+            )
+        {
+            mem.autoscale_suspended_since = Some(ui.ctx().cumulative_pass_nr());
+        }
+
         let old_bounds = *last_plot_transform.bounds();
         let new_bounds = *mem.transform.bounds();
         if mem.original_bounds.is_none() {
             mem.original_bounds = Some(new_bounds);
         }
-        if old_bounds != new_bounds {
+        // Publish our own bounds changes to the link group; changes adopted from it are not
+        // sent back.
+        if let Some((id, _)) = linked_axes.as_ref() {
+            if old_bounds != new_bounds && last_user_cause != Some(BoundsChangeCause::LinkSync) {
+                ui.data_mut(|data| {
+                    let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                    link_groups.0.insert(
+                        *id,
+                        LinkedBounds {
+                            bounds: new_bounds,
+                            auto_bounds: mem.auto_bounds,
+                            source: plot_id,
+                        },
+                    );
+                });
+            }
+        }
+        if enabled_events.contains(EventMask::BOUNDS_CHANGED) && old_bounds != new_bounds {
             events.push(PlotEvent::BoundsChanged {
                 old: old_bounds,
                 new: new_bounds,
@@ -1680,10 +2596,16 @@ impl<'a> Plot<'a> {
         };
         ui.advance_cursor_after_rect(complete_rect);
 
-        if let Some(screen) = response.hover_pos() {
+        if let Some(screen) = response
+            .hover_pos()
+            .filter(|_| enabled_events.contains(EventMask::HOVER))
+        {
             let pos = transform.value_from_position(screen);
             events.push(PlotEvent::Hover { pos });
         }
+        if enabled_events != EventMask::ALL {
+            events.retain(|event| enabled_events.allows(event));
+        }
 
         PlotResponse {
             inner,
@@ -1691,6 +2613,7 @@ impl<'a> Plot<'a> {
             transform,
             hovered_plot_item,
             events,
+            item_rects,
         }
     }
 
@@ -1899,6 +2822,85 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
     Box::new(step_sizes)
 }
 
+/// "Nice number" ladder used to round step sizes, per decade.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TickBasis {
+    /// 1, 2, 5, 10.
+    #[default]
+    Standard,
+
+    /// 1, 2, 2.5, 5, 10.
+    Fine,
+
+    /// 1, 2.5, 5, 10.
+    Quarter,
+
+    /// 1, 3, 10 (roughly half a decade per step).
+    HalfDecade,
+}
+
+impl TickBasis {
+    /// The mantissas of this ladder within one decade, ending with `10`.
+    pub fn mantissas(self) -> &'static [f64] {
+        match self {
+            Self::Standard => &[1.0, 2.0, 5.0, 10.0],
+            Self::Fine => &[1.0, 2.0, 2.5, 5.0, 10.0],
+            Self::Quarter => &[1.0, 2.5, 5.0, 10.0],
+            Self::HalfDecade => &[1.0, 3.0, 10.0],
+        }
+    }
+
+    /// Round `step` to the nearest value of the ladder.
+    pub fn nice_step(self, step: f64) -> f64 {
+        let pow10 = 10.0_f64.powf(step.log10().floor());
+        let mant = step / pow10;
+        let ladder = self.mantissas();
+        let nice_mant = ladder
+            .windows(2)
+            .find(|w| mant < 0.5 * (w[0] + w[1]))
+            .map_or(10.0, |w| w[0]);
+        nice_mant * pow10
+    }
+
+    /// Round `step` up to the next value of the ladder.
+    pub fn nice_step_at_least(self, step: f64) -> f64 {
+        let pow10 = 10.0_f64.powf(step.log10().floor());
+        let mant = step / pow10;
+        let nice_mant = self
+            .mantissas()
+            .iter()
+            .copied()
+            .find(|&m| mant <= m * (1.0 + 1e-9))
+            .unwrap_or(10.0);
+        nice_mant * pow10
+    }
+}
+
+/// Grid with steps rounded up to the given [`TickBasis`] ladder.
+///
+/// The thinnest lines use the smallest ladder step that is at least the recommended
+/// step size; thicker lines are drawn every 10 and 100 of those steps.
+pub fn nice_grid_spacer(basis: TickBasis) -> GridSpacer<'static> {
+    let get_marks = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || !input.base_step_size.is_finite() {
+            return Vec::new();
+        }
+
+        let smallest_visible_unit = basis.nice_step_at_least(input.base_step_size.abs());
+        let step_sizes = [
+            smallest_visible_unit,
+            smallest_visible_unit * 10.0,
+            smallest_visible_unit * 100.0,
+        ];
+
+        generate_marks(step_sizes, input.bounds)
+    };
+
+    Box::new(get_marks)
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1916,6 +2918,31 @@ pub fn uniform_grid_spacer<'a>(spacer: impl Fn(GridInput) -> [f64; 3] + 'a) -> G
     Box::new(get_marks)
 }
 
+/// Grid for Unix timestamps (seconds) with steps on clock boundaries: seconds, minutes,
+/// hours and days (UTC), instead of decimal steps.
+///
+/// Pair it with [`AxisHints::time_formatter`].
+pub fn time_grid_spacer() -> GridSpacer<'static> {
+    let get_marks = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || !input.base_step_size.is_finite() {
+            return Vec::new();
+        }
+
+        let smallest_visible_unit = time_axis::time_step_at_least(input.base_step_size.abs());
+        let medium = time_axis::coarser_time_step(smallest_visible_unit);
+        let step_sizes = [
+            smallest_visible_unit,
+            medium,
+            time_axis::coarser_time_step(medium),
+        ];
+
+        generate_marks(step_sizes, input.bounds)
+    };
+
+    Box::new(get_marks)
+}
+
 // ----------------------------------------------------------------------------
 
 struct PreparedPlot<'cfg, 'items> {
@@ -1926,21 +2953,31 @@ struct PreparedPlot<'cfg, 'items> {
     show_y: bool,
     label_formatter: LabelFormatter<'cfg>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'cfg>)>,
+    readout_formatters: [Option<TooltipFormatter>; 2],
+    number_format: NumberFormat,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'cfg>; 2],
+    max_ticks: [Option<usize>; 2],
     draw_cursor_x: bool,
     draw_cursor_y: bool,
     draw_cursors: Vec<Cursor>,
     cursor_color: Option<Color32>,
 
     clamp_grid: bool,
+    grid_on_top: bool,
+    static_mode: bool,
+    equal_tick_spacing: bool,
 }
 
 impl PreparedPlot<'_, '_> {
-    fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
+    fn ui(
+        self,
+        ui: &mut Ui,
+        response: &Response,
+    ) -> (Vec<Cursor>, Option<Id>, Vec<(String, Rect)>) {
         let mut axes_shapes = Vec::new();
 
         if self.show_grid.x {
@@ -1953,9 +2990,17 @@ impl PreparedPlot<'_, '_> {
         // Sort the axes by strength so that those with higher strength are drawn in front.
         axes_shapes.sort_by(|(_, strength1), (_, strength2)| strength1.total_cmp(strength2));
 
-        let mut shapes = axes_shapes.into_iter().map(|(shape, _)| shape).collect();
+        let grid_shapes = axes_shapes.into_iter().map(|(shape, _)| shape);
+        let mut shapes: Vec<Shape> = Vec::new();
+        let mut grid_on_top_shapes: Vec<Shape> = Vec::new();
+        if self.grid_on_top {
+            grid_on_top_shapes.extend(grid_shapes);
+        } else {
+            shapes.extend(grid_shapes);
+        }
 
         let transform = &self.transform;
+        let secondary = transform.secondary();
 
         let mut plot_ui = ui.new_child(
             egui::UiBuilder::new()
@@ -1963,11 +3008,32 @@ impl PreparedPlot<'_, '_> {
                 .layout(Layout::default()),
         );
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
+        let mut item_rects = Vec::with_capacity(self.items.len());
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            let first = shapes.len();
+            let item_transform = item_transform(&**item, transform, secondary.as_ref());
+            item.shapes(&plot_ui, item_transform, &mut shapes);
+            let opacity = item.opacity();
+            if opacity < 1.0 {
+                for shape in &mut shapes[first..] {
+                    egui::epaint::shape_transform::adjust_colors(shape, move |color| {
+                        *color = color.gamma_multiply(opacity);
+                    });
+                }
+            }
+            let rect = shapes[first..]
+                .iter()
+                .fold(Rect::NOTHING, |rect, shape| {
+                    rect.union(shape.visual_bounding_rect())
+                })
+                .intersect(*transform.frame());
+            if rect.is_positive() {
+                item_rects.push((item.name().to_owned(), rect));
+            }
         }
+        shapes.append(&mut grid_on_top_shapes);
 
-        let hover_pos = response.hover_pos();
+        let hover_pos = response.hover_pos().filter(|_| !self.static_mode);
         let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
             self.hover(ui, pointer, &mut shapes)
         } else {
@@ -2014,7 +3080,20 @@ impl PreparedPlot<'_, '_> {
             if let Some(pointer) = hover_pos {
                 let font_id = TextStyle::Monospace.resolve(ui.style());
                 let coordinate = transform.value_from_position(pointer);
-                let text = formatter.format(&coordinate, transform.bounds());
+                let text = match &self.readout_formatters {
+                    [None, None] => formatter.format(&coordinate, transform.bounds()),
+                    [x_fmt, y_fmt] => {
+                        let format = |fmt: &Option<TooltipFormatter>, value: f64| match fmt {
+                            Some(fmt) => fmt(value),
+                            None => self.number_format.format(value, 3),
+                        };
+                        format!(
+                            "x: {}\ny: {}",
+                            format(x_fmt, coordinate.x),
+                            format(y_fmt, coordinate.y)
+                        )
+                    }
+                };
                 let padded_frame = transform.frame().shrink(4.0);
                 let (anchor, position) = match corner {
                     Corner::LeftTop => (Align2::LEFT_TOP, padded_frame.left_top()),
@@ -2035,7 +3114,7 @@ impl PreparedPlot<'_, '_> {
             }
         }
 
-        (cursors, hovered_item_id)
+        (cursors, hovered_item_id, item_rects)
     }
 
     fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, fade_range: Rangef) {
@@ -2044,7 +3123,9 @@ impl PreparedPlot<'_, '_> {
             transform,
             // axis_formatters,
             grid_spacers,
+            max_ticks,
             clamp_grid,
+            equal_tick_spacing,
             ..
         } = self;
 
@@ -2056,9 +3137,11 @@ impl PreparedPlot<'_, '_> {
 
         let input = GridInput {
             bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
+            base_step_size: grid_step_scale(transform, *equal_tick_spacing)[iaxis]
+                * fade_range.min as f64,
         };
-        let steps = (grid_spacers[iaxis])(input);
+        let scale = transform.scale(axis);
+        let steps = grid_marks(&grid_spacers[iaxis], scale, input, max_ticks[iaxis]);
 
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
@@ -2094,7 +3177,7 @@ impl PreparedPlot<'_, '_> {
             };
 
             let pos_in_gui = transform.position_from_point(&value);
-            let spacing_in_points = (transform.dpos_dvalue()[iaxis] * step.step_size).abs() as f32;
+            let spacing_in_points = transform.mark_spacing_px(axis, &step);
 
             if spacing_in_points <= fade_range.min {
                 continue; // Too close together
@@ -2102,7 +3185,11 @@ impl PreparedPlot<'_, '_> {
 
             let line_strength = remap_clamp(spacing_in_points, fade_range, 0.0..=1.0);
 
-            let line_color = color_from_strength(ui, line_strength);
+            let mut line_color = color_from_strength(ui, line_strength);
+            if self.grid_on_top {
+                // Keep the grid from obscuring the data it's drawn over.
+                line_color = line_color.gamma_multiply(0.5);
+            }
 
             let mut p0 = pos_in_gui;
             let mut p1 = pos_in_gui;
@@ -2157,13 +3244,15 @@ impl PreparedPlot<'_, '_> {
         }
 
         let interact_radius_sq = ui.style().interaction.interact_radius.powi(2);
+        let secondary = transform.secondary();
 
         let candidates = items
             .iter()
             .filter(|entry| entry.allow_hover())
             .filter_map(|item| {
                 let item = &**item;
-                let closest = item.find_closest(pointer, transform);
+                let closest =
+                    item.find_closest(pointer, item_transform(item, transform, secondary.as_ref()));
                 Some(item).zip(closest)
             });
 
@@ -2181,14 +3270,26 @@ impl PreparedPlot<'_, '_> {
         let mut cursors = Vec::new();
 
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
+            let item_plot = items::PlotConfig {
+                transform: item_transform(item, transform, secondary.as_ref()),
+                ..plot
+            };
             item.on_hover(
                 plot_area_response,
                 elem,
                 shapes,
                 &mut cursors,
-                &plot,
+                &item_plot,
                 label_formatter,
             );
+            if item.on_right_axis() {
+                // The cursors are drawn with the primary transform.
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        *y = transform.primary_y_from_secondary(*y);
+                    }
+                }
+            }
             Some(item.id())
         } else {
             let value = transform.value_from_position(pointer);
@@ -2206,6 +3307,18 @@ impl PreparedPlot<'_, '_> {
         (cursors, hovered_plot_item_id)
     }
 }
+/// The transform `item` is drawn and hovered with: `secondary` for right-axis items, if set.
+fn item_transform<'t>(
+    item: &dyn PlotItem,
+    primary: &'t PlotTransform,
+    secondary: Option<&'t PlotTransform>,
+) -> &'t PlotTransform {
+    match secondary {
+        Some(secondary) if item.on_right_axis() => secondary,
+        _ => primary,
+    }
+}
+
 /// Returns next bigger power in given base
 /// e.g.
 /// ```ignore
@@ -2219,6 +3332,198 @@ fn next_power(value: f64, base: f64) -> f64 {
     base.powi(value.abs().log(base).ceil() as i32)
 }
 
+/// Data units per screen point on each axis, for the grid spacers.
+///
+/// With `equal` (see [`Plot::equal_tick_spacing`]) both axes get the coarser of the two, so they
+/// pick the same step.
+fn grid_step_scale(transform: &PlotTransform, equal: bool) -> [f64; 2] {
+    let d = transform.dvalue_dpos();
+    let d = [d[0].abs(), d[1].abs()];
+    if equal {
+        let m = d[0].max(d[1]);
+        [m, m]
+    } else {
+        d
+    }
+}
+
+/// Add the auto-fit margin along a non-linear `axis` in the scale's linear space.
+///
+/// A log axis first moves a non-positive minimum up to the smallest positive item value.
+fn fit_scaled_axis(
+    bounds: &mut PlotBounds,
+    items: &[Box<dyn PlotItem + '_>],
+    axis: Axis,
+    scale: AxisScale,
+    margin_fraction: f32,
+) {
+    let i = usize::from(axis);
+    if scale == AxisScale::Log10 && bounds.min[i] <= 0.0 {
+        if let Some(v) = min_positive(items, axis) {
+            bounds.min[i] = v.min(bounds.max[i]);
+        }
+    }
+    let (min, max) = scale.expand((bounds.min[i], bounds.max[i]), f64::from(margin_fraction));
+    if min.is_finite() && max.is_finite() && min < max {
+        bounds.min[i] = min;
+        bounds.max[i] = max;
+    }
+}
+
+/// Smallest positive value along `axis` in the items' data.
+fn min_positive(items: &[Box<dyn PlotItem + '_>], axis: Axis) -> Option<f64> {
+    let i = usize::from(axis);
+    let mut best = f64::INFINITY;
+    let mut see = |v: f64| {
+        if v > 0.0 && v < best {
+            best = v;
+        }
+    };
+    for item in items {
+        match item.geometry() {
+            PlotGeometry::Points(points) => {
+                for p in points {
+                    see(if i == 0 { p.x } else { p.y });
+                }
+            }
+            PlotGeometry::PointsXY { xs, ys, .. } => {
+                for &v in if i == 0 { xs } else { ys } {
+                    see(v);
+                }
+            }
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => {
+                for block in if i == 0 { xs_blocks } else { ys_blocks } {
+                    block.iter().for_each(|&v| see(v));
+                }
+            }
+            PlotGeometry::Rects | PlotGeometry::None => {
+                let b = item.bounds();
+                see(b.min[i]);
+                see(b.max[i]);
+            }
+        }
+    }
+    best.is_finite().then_some(best)
+}
+
+/// Start, retarget, cancel or advance the bounds animation (see [`NavigationConfig::animate`]),
+/// and show the eased bounds for this frame.
+///
+/// `old` is what was shown last frame, and `mem.transform` holds this frame's target.
+fn animate_bounds(
+    mem: &mut PlotMemory,
+    old: &PlotBounds,
+    cause: Option<BoundsChangeCause>,
+    ui: &Ui,
+    duration: std::time::Duration,
+) {
+    let now = ui.input(|i| i.time);
+    let target = *mem.transform.bounds();
+    match cause {
+        Some(
+            BoundsChangeCause::Pan
+            | BoundsChangeCause::Zoom
+            | BoundsChangeCause::AxisZoomX
+            | BoundsChangeCause::AxisZoomY
+            | BoundsChangeCause::BoxZoom,
+        ) => {
+            mem.bounds_animation = None;
+        }
+        Some(BoundsChangeCause::Reset | BoundsChangeCause::Programmatic)
+            if mem.original_bounds.is_some() && target != *old =>
+        {
+            mem.bounds_animation = Some(BoundsAnimation {
+                from: *old,
+                to: target,
+                start_time: now,
+            });
+        }
+        _ => {
+            // Auto-fitted axes keep following the data while the animation runs.
+            if let Some(animation) = &mut mem.bounds_animation {
+                if mem.auto_bounds.x {
+                    animation.to.set_x(&target);
+                }
+                if mem.auto_bounds.y {
+                    animation.to.set_y(&target);
+                }
+            }
+        }
+    }
+
+    let Some(animation) = mem.bounds_animation else {
+        return;
+    };
+    if let Some(bounds) = animation.sample(now, duration) {
+        mem.transform.set_bounds(bounds);
+        ui.ctx().request_repaint();
+    } else {
+        mem.transform.set_bounds(animation.to);
+        mem.bounds_animation = None;
+    }
+}
+
+/// Run `spacer`, keeping at most `max_ticks` marks (see [`AxisHints::max_ticks`]).
+///
+/// A non-linear `scale` generates its own marks instead, under the same cap.
+fn grid_marks(
+    spacer: &GridSpacer<'_>,
+    scale: AxisScale,
+    mut input: GridInput,
+    max_ticks: Option<usize>,
+) -> Vec<GridMark> {
+    if !scale.is_linear() {
+        return scale.grid_marks(input.bounds, max_ticks);
+    }
+    let Some(max_ticks) = max_ticks else {
+        return spacer(input);
+    };
+
+    // Don't even ask for marks finer than the cap allows.
+    let span = (input.bounds.1 - input.bounds.0).abs();
+    if span.is_finite() {
+        input.base_step_size = input.base_step_size.max(span / max_ticks as f64);
+    }
+    cap_grid_marks(spacer(input), max_ticks)
+}
+
+/// Drop the finest mark levels until at most `max_ticks` remain; if a single level is still
+/// too dense, thin it to a 1-2-5 multiple of its step.
+fn cap_grid_marks(mut marks: Vec<GridMark>, max_ticks: usize) -> Vec<GridMark> {
+    while marks.len() > max_ticks {
+        let finest = marks.iter().fold(f64::INFINITY, |a, m| a.min(m.step_size));
+        let coarser = marks.iter().filter(|m| m.step_size > finest).count();
+        if coarser == 0 {
+            break;
+        }
+        marks.retain(|m| m.step_size > finest);
+    }
+
+    if marks.len() > max_ticks {
+        let step = marks[0].step_size;
+        let ratio = marks.len().div_ceil(max_ticks) as f64;
+        let magnitude = 10f64.powf(ratio.log10().floor());
+        let factor = [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|f| f * magnitude)
+            .find(|&f| f >= ratio)
+            .unwrap_or(10.0 * magnitude);
+        let new_step = step * factor;
+        marks.retain(|m| {
+            let k = m.value / new_step;
+            (k - k.round()).abs() < 1e-6
+        });
+        for m in &mut marks {
+            m.step_size = new_step;
+        }
+    }
+
+    marks
+}
+
 /// Fill in all values between [min, max] which are a multiple of `step_size`
 fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
     let mut steps = vec![];
@@ -2303,6 +3608,23 @@ fn test_generate_marks() {
     }
 }
 
+#[test]
+fn test_cap_grid_marks() {
+    // Three levels: 1, 10, 100 over [0, 1000.5] -> 0..=1000, 1001 marks in total.
+    let marks = generate_marks([1.0, 10.0, 100.0], (0.0, 1000.5));
+    assert_eq!(marks.len(), 1001);
+
+    let capped = cap_grid_marks(marks.clone(), 200);
+    assert_eq!(capped.len(), 101);
+    assert!(capped.iter().all(|m| m.step_size >= 10.0));
+
+    // Even the coarsest level has 11 marks: thinned to every 500.
+    let capped = cap_grid_marks(marks, 4);
+    let values: Vec<f64> = capped.iter().map(|m| m.value).collect();
+    assert_eq!(values, vec![0.0, 500.0, 1000.0]);
+    assert!(capped.iter().all(|m| m.step_size == 500.0));
+}
+
 fn cmp_f64(a: f64, b: f64) -> Ordering {
     match a.partial_cmp(&b) {
         Some(ord) => ord,
@@ -2336,8 +3658,351 @@ pub fn format_number(number: f64, num_decimals: usize) -> String {
     }
 }
 
+/// Locale settings for the built-in number formatting (axis ticks, tooltip, pins panel).
+///
+/// Custom formatters are not affected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Character between the integer and fractional part. Default: `'.'`.
+    pub decimal_separator: char,
+
+    /// Optional character grouping the integer digits by thousands. Default: `None`.
+    pub thousands_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Format `number` with exactly `num_decimals` decimals.
+    pub fn format(&self, number: f64, num_decimals: usize) -> String {
+        self.localize(&format!("{number:.num_decimals$}"))
+    }
+
+    /// Rewrite a number formatted with `.` as decimal separator (and no grouping)
+    /// according to these settings.
+    ///
+    /// Text that isn't a plain decimal number (e.g. `NaN`, `inf`, exponents) is returned as is.
+    pub fn localize(&self, formatted: &str) -> String {
+        if *self == Self::default() {
+            return formatted.to_owned();
+        }
+        let (sign, unsigned) = match formatted.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", formatted),
+        };
+        let (int_part, frac_part) = match unsigned.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (unsigned, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(int_part) || frac_part.is_some_and(|f| !is_digits(f)) {
+            return formatted.to_owned();
+        }
+
+        let mut out = String::with_capacity(formatted.len() + int_part.len() / 3 + 1);
+        out.push_str(sign);
+        for (i, c) in int_part.chars().enumerate() {
+            if let Some(sep) = self.thousands_separator {
+                if i > 0 && (int_part.len() - i) % 3 == 0 {
+                    out.push(sep);
+                }
+            }
+            out.push(c);
+        }
+        if let Some(frac_part) = frac_part {
+            out.push(self.decimal_separator);
+            out.push_str(frac_part);
+        }
+        out
+    }
+}
+
+#[test]
+fn test_number_format() {
+    let nf = NumberFormat {
+        decimal_separator: ',',
+        thousands_separator: Some('.'),
+    };
+    assert_eq!(nf.format(1234.5678, 2), "1.234,57");
+    assert_eq!(nf.format(-1_234_567.0, 0), "-1.234.567");
+    assert_eq!(nf.format(12.0, 1), "12,0");
+    assert_eq!(nf.localize("NaN"), "NaN");
+    assert_eq!(NumberFormat::default().format(1234.5, 1), "1234.5");
+}
+
+/// Per-frame input for pressing `button` at `from`, dragging to `to` and releasing there,
+/// with `modifiers` held while pressing and releasing.
+#[cfg(test)]
+pub(crate) fn pointer_gesture(
+    button: PointerButton,
+    modifiers: Modifiers,
+    from: Pos2,
+    to: Pos2,
+) -> Vec<Vec<egui::Event>> {
+    let press = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button,
+        pressed,
+        modifiers,
+    };
+    let mut frames = vec![
+        vec![egui::Event::PointerMoved(from)],
+        vec![press(from, true)],
+    ];
+    if from != to {
+        frames.push(vec![egui::Event::PointerMoved(from.lerp(to, 0.5))]);
+        frames.push(vec![egui::Event::PointerMoved(to)]);
+    }
+    frames.push(vec![press(to, false)]);
+    frames
+}
+
+/// Show a plot of one line with `nav` for each frame of input, collecting its events.
+#[cfg(test)]
+fn plot_events(nav: NavigationConfig, frames: Vec<Vec<egui::Event>>) -> Vec<PlotEvent> {
+    let ctx = egui::Context::default();
+    let mut events = Vec::new();
+    for input in frames {
+        run_headless_frame(&ctx, input, |ui| {
+            let response = Plot::new("test_plot").navigation(nav).show(ui, |plot_ui| {
+                plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0]]));
+            });
+            events.extend(response.events);
+        });
+    }
+    events
+}
+
+#[test]
+fn test_measure_click_is_consumed() {
+    let nav = NavigationConfig::default().measure(Some((PointerButton::Primary, Modifiers::NONE)));
+    let pos = Pos2::new(200.0, 150.0);
+    let mut frames = pointer_gesture(PointerButton::Primary, Modifiers::NONE, pos, pos);
+    frames.extend(pointer_gesture(
+        PointerButton::Primary,
+        Modifiers::NONE,
+        pos,
+        pos,
+    ));
+    let events = plot_events(nav, frames);
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, PlotEvent::MeasureUpdated { .. }))
+    );
+    assert!(!events.iter().any(|e| matches!(
+        e,
+        PlotEvent::Activate { .. } | PlotEvent::ItemClicked { .. }
+    )));
+}
+
+#[test]
+fn test_box_select_suppresses_box_zoom() {
+    let nav = NavigationConfig::default()
+        .box_zoom(BoxZoomConfig::new(
+            true,
+            PointerButton::Secondary,
+            Modifiers::NONE,
+        ))
+        .box_select(Some((PointerButton::Secondary, Modifiers::NONE)));
+    let events = plot_events(
+        nav,
+        pointer_gesture(
+            PointerButton::Secondary,
+            Modifiers::NONE,
+            Pos2::new(100.0, 100.0),
+            Pos2::new(250.0, 200.0),
+        ),
+    );
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, PlotEvent::SelectionStats { .. }))
+    );
+    assert!(!events.iter().any(|e| matches!(
+        e,
+        PlotEvent::BoxZoomStarted { .. } | PlotEvent::BoxZoomFinished { .. }
+    )));
+}
+
+#[test]
+fn test_drag_snap_waits_for_direction() {
+    let nav = NavigationConfig::default().drag_snap_deg(Some(20.0));
+    let press = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    // A small vertical wobble starts the drag, then the pointer moves mostly horizontally.
+    let frames = vec![
+        vec![egui::Event::PointerMoved(Pos2::new(100.0, 100.0))],
+        vec![press(Pos2::new(100.0, 100.0), true)],
+        vec![egui::Event::PointerMoved(Pos2::new(100.0, 108.0))],
+        vec![egui::Event::PointerMoved(Pos2::new(140.0, 110.0))],
+        vec![egui::Event::PointerMoved(Pos2::new(250.0, 115.0))],
+        vec![press(Pos2::new(250.0, 115.0), false)],
+    ];
+    let deltas: Vec<(f64, f64)> = plot_events(nav, frames)
+        .into_iter()
+        .filter_map(|e| match e {
+            PlotEvent::PanDelta {
+                delta_plot_x,
+                delta_plot_y,
+                ..
+            } => Some((delta_plot_x, delta_plot_y)),
+            _ => None,
+        })
+        .collect();
+    assert!(deltas.iter().any(|&(dx, _)| dx != 0.0));
+    assert!(deltas.iter().all(|&(_, dy)| dy == 0.0));
+}
+
+#[test]
+fn test_autoscale_suspend_frames() {
+    let ctx = egui::Context::default();
+    let pan = pointer_gesture(
+        PointerButton::Primary,
+        Modifiers::NONE,
+        Pos2::new(200.0, 150.0),
+        Pos2::new(120.0, 150.0),
+    );
+    let gesture_len = pan.len();
+    let frames = pan.into_iter().chain(std::iter::repeat_n(Vec::new(), 10));
+    let mut auto = Vec::new();
+    for input in frames {
+        run_headless_frame(&ctx, input, |ui| {
+            let id = Id::new("suspend");
+            Plot::new("suspend")
+                .id(id)
+                .autoscale_suspend_frames(5)
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0]]));
+                });
+            let mem = PlotMemory::load(ui.ctx(), id).unwrap();
+            auto.push((mem.auto_bounds, ui.ctx().has_requested_repaint()));
+        });
+    }
+    // Off after the pan, with repaints requested to count the frames down...
+    assert_eq!(auto[gesture_len + 2], (Vec2b::FALSE, true));
+    // ...then back on without any further input.
+    assert_eq!(auto.last().unwrap().0, Vec2b::TRUE);
+}
+
+#[test]
+fn test_cursor_plot_pos_segmented() {
+    let ctx = egui::Context::default();
+    let cursor = |pointer: Option<Pos2>| {
+        let mut out = None;
+        let input = pointer.map(egui::Event::PointerMoved).into_iter().collect();
+        run_headless_frame(&ctx, input, |ui| {
+            let response = Plot::new("segmented")
+                .segmented_x_axis(Some(SegmentedAxis::new(
+                    vec![Interval::new(0.0, 4.0), Interval::new(6.0, 10.0)],
+                    40.0,
+                )))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new("a", vec![[0.0, 0.0], [10.0, 1.0]]));
+                });
+            let gap = response.transform.segment_x_gap_screen_ranges().unwrap()[0];
+            out = Some((response.cursor_plot_pos(), gap, *response.transform.frame()));
+        });
+        out.unwrap()
+    };
+
+    let (none, _, frame) = cursor(None);
+    assert_eq!(none, None);
+    // The axes widen once the plot is hovered; measure the gap after they settle.
+    let in_segment = Pos2::new(frame.left() + 20.0, frame.center().y);
+    cursor(Some(in_segment));
+    let (hovered, (gap_left, gap_right), frame) = cursor(Some(in_segment));
+    assert!(hovered.is_some_and(|p| (0.0..4.0).contains(&p.x)));
+    let in_gap = Pos2::new(0.5 * (gap_left + gap_right), frame.center().y);
+    assert_eq!(cursor(Some(in_gap)).0, None, "no X over a gap");
+}
+
+/// `true` if every modifier required by `req` is held in `cur`.
+fn modifiers_ok(cur: Modifiers, req: Modifiers) -> bool {
+    (!req.alt || cur.alt)
+        && (!req.ctrl || cur.ctrl)
+        && (!req.shift || cur.shift)
+        && (!req.command || cur.command)
+        && (!req.mac_cmd || cur.mac_cmd)
+}
+
+/// Draw the measure ruler between `start` and `end` with a Δx / Δy / distance label.
+fn paint_measure_ruler(
+    ui: &Ui,
+    transform: &PlotTransform,
+    start: PlotPoint,
+    end: PlotPoint,
+    number_format: NumberFormat,
+) {
+    let painter = ui.painter().with_clip_rect(*transform.frame());
+    let color = rulers_color(ui);
+    let (a, b) = (
+        transform.position_from_point(&start),
+        transform.position_from_point(&end),
+    );
+    painter.line_segment([a, b], Stroke::new(1.5, color));
+    painter.circle_filled(a, 3.0, color);
+    painter.circle_filled(b, 3.0, color);
+
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let scale = transform.dvalue_dpos();
+    let decimals = |s: f64| ((-s.abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+    let (x_decimals, y_decimals) = (decimals(scale[0]), decimals(scale[1]));
+    let text = format!(
+        "Δx = {}\nΔy = {}\nd = {}",
+        number_format.format(dx, x_decimals),
+        number_format.format(dy, y_decimals),
+        number_format.format(dx.hypot(dy), x_decimals.max(y_decimals)),
+    );
+    painter.text(
+        a + (b - a) * 0.5 + Vec2::new(6.0, -6.0),
+        Align2::LEFT_BOTTOM,
+        text,
+        TextStyle::Body.resolve(ui.style()),
+        ui.visuals().text_color(),
+    );
+}
+
 /// Determine a color from a 0-1 strength value.
 pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
     let base_color = ui.visuals().text_color();
     base_color.gamma_multiply(strength.sqrt())
 }
+
+/// Run one frame of `add_contents` in a headless egui context, fed with `events`.
+///
+/// The modifiers of a pointer button event in `events` are held for the whole frame.
+#[cfg(test)]
+pub(crate) fn run_headless_frame(
+    ctx: &egui::Context,
+    events: Vec<egui::Event>,
+    mut add_contents: impl FnMut(&mut Ui),
+) {
+    let modifiers = events
+        .iter()
+        .find_map(|e| match e {
+            egui::Event::PointerButton { modifiers, .. } => Some(*modifiers),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+        events,
+        modifiers,
+        ..Default::default()
+    };
+    let _full_output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+    });
+}
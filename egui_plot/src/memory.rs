@@ -35,6 +35,42 @@ pub struct PlotMemory {
 
     ///  first bounds that has been shown.
     pub original_bounds: Option<crate::transform::PlotBounds>,
+
+    /// The [`crate::DraggablePoint`] currently being dragged, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragged_point: Option<Id>,
+
+    /// Device of the most recent scroll, used to classify smoothed scroll deltas.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_scroll_source: Option<crate::navigation::ScrollSource>,
+
+    /// Measure ruler start and (once placed) end, in plot coordinates.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) measure: Option<(crate::PlotPoint, Option<crate::PlotPoint>)>,
+
+    /// Screen position where the current box selection started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) select_start: Option<Pos2>,
+
+    /// Axes the current drag-pan is snapped to, `None` until the drag direction is decided.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) drag_snap: Option<Vec2b>,
+
+    /// Screen and plot position where the current pan drag started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) pan_drag: Option<(Pos2, crate::PlotPoint)>,
+
+    /// Item and plot position of the last secondary click, for [`crate::Plot::context_menu`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) context_menu_at: Option<(Option<Id>, crate::PlotPoint)>,
+
+    /// Pass number of the last user navigation, while autoscale is suspended after it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) autoscale_suspended_since: Option<u64>,
+
+    /// Bounds transition in progress, see [`crate::NavigationConfig::animate`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) bounds_animation: Option<crate::navigation::BoundsAnimation>,
 }
 
 impl PlotMemory {
@@ -1,6 +1,10 @@
 //! Navigation module.
 
-use egui::{Key, Modifiers, PointerButton, Vec2b};
+use std::time::Duration;
+
+use egui::{Key, Modifiers, PointerButton, Pos2, Rect, Vec2b};
+
+use crate::PlotBounds;
 
 /// A reset operation.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -25,6 +29,77 @@ impl AxisToggle {
     }
 }
 
+/// What a scroll gesture does to the plot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollAction {
+    /// Translate the bounds by the scroll delta.
+    Pan,
+    /// Zoom around the pointer (or plot center, see [`ZoomConfig::zoom_to_mouse`]).
+    Zoom,
+}
+
+/// The device a scroll came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ScrollSource {
+    /// Mouse wheel, scrolling in lines or pages.
+    Wheel,
+    /// Touchpad (or any pixel-precise device), scrolling in points.
+    Touchpad,
+}
+
+/// Scroll configuration.
+#[derive(Clone, Copy, Debug)]
+pub struct ScrollConfig {
+    /// Master enable.
+    pub enabled: bool,
+    /// Axes to scroll (`x` and/or `y`).
+    pub axis: Vec2b,
+    /// What a mouse wheel does. Default: [`ScrollAction::Pan`].
+    pub wheel: ScrollAction,
+    /// What a two-finger touchpad scroll does. Default: [`ScrollAction::Pan`].
+    pub touchpad: ScrollAction,
+}
+
+impl ScrollConfig {
+    #[inline]
+    pub const fn new(enabled: bool, axis: Vec2b) -> Self {
+        Self {
+            enabled,
+            axis,
+            wheel: ScrollAction::Pan,
+            touchpad: ScrollAction::Pan,
+        }
+    }
+
+    /// Map each input source to pan or zoom.
+    ///
+    /// Sources are told apart by the unit egui reports for the wheel event:
+    /// lines/pages for a mouse wheel, points for a touchpad.
+    /// Zooming still requires [`NavigationConfig::zoom`] to be enabled.
+    #[inline]
+    pub fn source_behavior(mut self, wheel: ScrollAction, touchpad: ScrollAction) -> Self {
+        self.wheel = wheel;
+        self.touchpad = touchpad;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn action(&self, source: ScrollSource) -> ScrollAction {
+        match source {
+            ScrollSource::Wheel => self.wheel,
+            ScrollSource::Touchpad => self.touchpad,
+        }
+    }
+}
+
+impl From<AxisToggle> for ScrollConfig {
+    /// Both input sources pan, as before [`ScrollConfig`] existed.
+    #[inline]
+    fn from(toggle: AxisToggle) -> Self {
+        Self::new(toggle.enabled, toggle.axis)
+    }
+}
+
 /// Zoom configuration.
 #[derive(Clone, Copy, Debug)]
 pub struct ZoomConfig {
@@ -72,6 +147,9 @@ pub struct BoxZoomConfig {
     pub button: PointerButton,
     /// Which modifiers must be down. Any `true` field here must be pressed at runtime.
     pub required_mods: Modifiers,
+    /// Zoom only one axis when the box is thinner than this fraction of its length.
+    /// `None` always zooms both axes.
+    pub single_axis_threshold: Option<f32>,
 }
 
 impl BoxZoomConfig {
@@ -81,17 +159,58 @@ impl BoxZoomConfig {
             enabled,
             button,
             required_mods,
+            single_axis_threshold: None,
+        }
+    }
+
+    /// When one side of the box is shorter than `fraction` times the other (e.g. `Some(0.1)`),
+    /// only the axis of the longer side is zoomed and the other keeps its current range.
+    /// A wide, flat box then means "zoom X to this range". Default: `None`.
+    #[inline]
+    pub fn single_axis_threshold(mut self, fraction: Option<f32>) -> Self {
+        self.single_axis_threshold = fraction;
+        self
+    }
+
+    /// The screen rectangle to zoom to for a box dragged from `start` to `end`,
+    /// and which axes it zooms.
+    pub(crate) fn zoom_rect(&self, start: Pos2, end: Pos2, frame: Rect) -> (Rect, Vec2b) {
+        let rect = Rect::from_two_pos(start, end);
+        let Some(fraction) = self.single_axis_threshold else {
+            return (rect, Vec2b::TRUE);
+        };
+        if rect.height() < fraction * rect.width() {
+            (
+                Rect::from_x_y_ranges(rect.x_range(), frame.y_range()),
+                Vec2b::new(true, false),
+            )
+        } else if rect.width() < fraction * rect.height() {
+            (
+                Rect::from_x_y_ranges(frame.x_range(), rect.y_range()),
+                Vec2b::new(false, true),
+            )
+        } else {
+            (rect, Vec2b::TRUE)
         }
     }
 }
 
+/// How far (in points) a drag-pan must move before [`NavigationConfig::drag_snap_deg`]
+/// picks its axis.
+pub(crate) const DRAG_SNAP_MIN_DISTANCE: f32 = 12.0;
+
 /// All navigation & shortcut controls in one place.
 #[derive(Clone, Copy, Debug)]
 pub struct NavigationConfig {
     /// Dragging (per axis).
     pub drag: AxisToggle,
+    /// Snap a drag-pan to a single axis when its direction is within this many degrees
+    /// of that axis. `None` disables snapping.
+    pub drag_snap_deg: Option<f32>,
     /// Scrolling/panning with mouse wheel/touchpad (per axis).
-    pub scroll: AxisToggle,
+    ///
+    /// This used to be an [`AxisToggle`]; convert an existing one with `.into()`.
+    pub scroll: ScrollConfig,
     /// Axis-zoom-drag (drag on axis strips).
     pub axis_zoom_drag: Vec2b,
     /// Wheel/pinch zoom.
@@ -111,13 +230,31 @@ pub struct NavigationConfig {
     pub pin_add_key: Option<Key>,
     pub pin_remove_key: Option<Key>,
     pub pins_clear_key: Option<Key>,
+
+    /// Measure mode: button and modifiers whose clicks place the ruler endpoints.
+    /// `None` disables it.
+    pub measure: Option<(PointerButton, Modifiers)>,
+
+    /// Box selection: button and modifiers that drag a selection rectangle.
+    /// `None` disables it.
+    pub box_select: Option<(PointerButton, Modifiers)>,
+
+    /// Keep the view inside [`Self::clamp_bounds`] after panning and zooming.
+    pub clamp_enabled: bool,
+    /// Region the view is clamped to. `None` uses the bounds of the plotted data.
+    pub clamp_bounds: Option<PlotBounds>,
+
+    /// How long reset, fit-to-view and programmatic bounds changes take to ease in.
+    /// `Duration::ZERO` jumps instantly.
+    pub animation: Duration,
 }
 
 impl Default for NavigationConfig {
     fn default() -> Self {
         Self {
             drag: AxisToggle::new(true, Vec2b::new(true, true)),
-            scroll: AxisToggle::new(true, Vec2b::new(true, true)),
+            drag_snap_deg: None,
+            scroll: ScrollConfig::new(true, Vec2b::new(true, true)),
             axis_zoom_drag: Vec2b::new(false, false),
             zoom: ZoomConfig::new(true, Vec2b::new(true, true))
                 .zoom_to_mouse(true)
@@ -131,6 +268,14 @@ impl Default for NavigationConfig {
             pin_add_key: Some(Key::P),
             pin_remove_key: Some(Key::U),
             pins_clear_key: Some(Key::Delete),
+
+            measure: None,
+            box_select: None,
+
+            clamp_enabled: false,
+            clamp_bounds: None,
+
+            animation: Duration::ZERO,
         }
     }
 }
@@ -148,7 +293,7 @@ impl NavigationConfig {
     ) -> Self {
         Self {
             drag: AxisToggle::new(allow_drag.any(), allow_drag),
-            scroll: AxisToggle::new(allow_scroll.any(), allow_scroll),
+            scroll: ScrollConfig::new(allow_scroll.any(), allow_scroll),
             axis_zoom_drag: allow_axis_zoom_drag,
             zoom: ZoomConfig::new(allow_zoom.any(), allow_zoom)
                 .zoom_to_mouse(true)
@@ -183,24 +328,44 @@ impl NavigationConfig {
         self
     }
 
+    /// Snap drag-panning to the X or Y axis.
+    ///
+    /// When a drag starts within `degrees` of horizontal (or vertical), motion along the other
+    /// axis is ignored until the drag ends. The decision is made once per drag, after the pointer
+    /// has moved a few points from where it was pressed.
+    /// `None` (the default) pans freely.
+    #[inline]
+    pub fn drag_snap_deg(mut self, degrees: Option<f32>) -> Self {
+        self.drag_snap_deg = degrees;
+        self
+    }
+
     /// Configure scrolling/panning with the mouse wheel or touchpad.
     ///
     /// Same `(x, y)` ordering as `drag`:
     /// - `Some(Vec2b::new(true, false))` → scroll horizontally only
     /// - `None`                          → disable scroll-based navigation
+    ///
+    /// The per-source behavior set with [`ScrollConfig::source_behavior`] is kept.
     #[inline]
     pub fn scroll(mut self, axes: Option<Vec2b>) -> Self {
-        match axes {
-            Some(axis) => {
-                self.scroll = AxisToggle::new(true, axis);
-            }
-            None => {
-                self.scroll = AxisToggle::new(false, Vec2b::new(false, false));
-            }
+        if let Some(axis) = axes {
+            self.scroll.enabled = true;
+            self.scroll.axis = axis;
+        } else {
+            self.scroll.enabled = false;
+            self.scroll.axis = Vec2b::new(false, false);
         }
         self
     }
 
+    /// Set the full scroll configuration.
+    #[inline]
+    pub fn scroll_config(mut self, cfg: ScrollConfig) -> Self {
+        self.scroll = cfg;
+        self
+    }
+
     /// Configure zoom-drag on the axis strips.
     ///
     /// `axis` selects which axes can be zoomed by dragging on their axis strips.
@@ -290,4 +455,100 @@ impl NavigationConfig {
         self.pins_clear_key = clear;
         self
     }
+
+    /// Enable measure mode.
+    ///
+    /// Two successive clicks with `button` (while `modifiers` are held) place a ruler that shows
+    /// Δx, Δy and the distance between the two points. A third click starts a new ruler and
+    /// `Escape` clears it. Pass `None` to disable.
+    #[inline]
+    pub fn measure(mut self, trigger: Option<(PointerButton, Modifiers)>) -> Self {
+        self.measure = trigger;
+        self
+    }
+
+    /// Enable box selection.
+    ///
+    /// Dragging with `button` (while `modifiers` are held) draws a selection rectangle;
+    /// on release a [`crate::PlotEvent::SelectionStats`] reports the per-series statistics
+    /// of the samples inside it. The drag doesn't pan the plot. Pass `None` to disable.
+    #[inline]
+    pub fn box_select(mut self, trigger: Option<(PointerButton, Modifiers)>) -> Self {
+        self.box_select = trigger;
+        self
+    }
+
+    /// Keep panning and zooming inside `bounds`, or inside the data bounds when `None`.
+    ///
+    /// After each navigation step the view slides back into the region rather than
+    /// shrinking; an axis on which the view is already wider than the region is left free.
+    #[inline]
+    pub fn clamp_to_bounds(mut self, bounds: Option<PlotBounds>) -> Self {
+        self.clamp_enabled = true;
+        self.clamp_bounds = bounds;
+        self
+    }
+
+    /// Ease reset, fit-to-view and programmatic bounds changes over `duration`.
+    ///
+    /// Each axis follows an ease-out cubic curve. Dragging, scrolling or zooming cancels a
+    /// running animation at once. Default: `Duration::ZERO` (instant).
+    #[inline]
+    pub fn animate(mut self, duration: Duration) -> Self {
+        self.animation = duration;
+        self
+    }
+}
+
+/// A bounds transition in flight, see [`NavigationConfig::animate`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BoundsAnimation {
+    pub from: PlotBounds,
+    pub to: PlotBounds,
+    /// `egui::InputState::time` when the transition started.
+    pub start_time: f64,
+}
+
+impl BoundsAnimation {
+    /// The bounds to show at `time`, or `None` once the transition is over.
+    pub fn sample(&self, time: f64, duration: Duration) -> Option<PlotBounds> {
+        let t = (time - self.start_time) / duration.as_secs_f64().max(f64::EPSILON);
+        if t.is_nan() || t >= 1.0 {
+            return None;
+        }
+        let eased = 1.0 - (1.0 - t.max(0.0)).powi(3);
+        let lerp = |a: f64, b: f64| a + (b - a) * eased;
+        Some(PlotBounds::from_min_max(
+            [
+                lerp(self.from.min[0], self.to.min[0]),
+                lerp(self.from.min[1], self.to.min[1]),
+            ],
+            [
+                lerp(self.from.max[0], self.to.max[0]),
+                lerp(self.from.max[1], self.to.max[1]),
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_animation_ease_out() {
+        let animation = BoundsAnimation {
+            from: PlotBounds::from_min_max([0.0, 0.0], [8.0, 8.0]),
+            to: PlotBounds::from_min_max([8.0, 0.0], [16.0, 16.0]),
+            start_time: 10.0,
+        };
+        let duration = std::time::Duration::from_secs(2);
+        assert_eq!(animation.sample(10.0, duration), Some(animation.from));
+        // Half way in time is 7/8 of the way for an ease-out cubic.
+        assert_eq!(
+            animation.sample(11.0, duration),
+            Some(PlotBounds::from_min_max([7.0, 0.0], [15.0, 15.0]))
+        );
+        assert_eq!(animation.sample(12.0, duration), None);
+    }
 }
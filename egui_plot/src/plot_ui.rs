@@ -1,14 +1,51 @@
 use std::ops::RangeInclusive;
 
-use egui::{Color32, Pos2, Response, Vec2, Vec2b, epaint::Hsva};
+use egui::{Align2, Color32, Id, Pos2, Response, Ui, Vec2, Vec2b, epaint::Hsva};
 
 use crate::{
-    NavigationConfig, PlotBounds, PlotItem, PlotPoint, PlotTransform, action::ActionQueue,
+    NavigationConfig, NumberFormat, PlotBounds, PlotGeometry, PlotItem, PlotPoint, PlotTransform,
+    TooltipFormatter, action::ActionQueue,
 };
 
 #[allow(unused_imports)] // for links in docstrings
 use crate::Plot;
 
+type ScreenOverlay<'a> = (Align2, Box<dyn FnOnce(&mut Ui) + 'a>);
+
+/// Selects a series either by its legend name or by its item id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SeriesRef {
+    Name(String),
+    Id(Id),
+}
+
+impl SeriesRef {
+    pub(crate) fn matches(&self, item: &dyn PlotItem) -> bool {
+        match self {
+            Self::Name(name) => item.name() == name,
+            Self::Id(id) => item.id() == *id,
+        }
+    }
+}
+
+impl From<&str> for SeriesRef {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for SeriesRef {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<Id> for SeriesRef {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
 /// Provides methods to interact with a plot while building it. It is the single argument of the closure
 /// provided to [`Plot::show`]. See [`Plot`] for an example of how to use it.
 pub struct PlotUi<'a> {
@@ -20,6 +57,16 @@ pub struct PlotUi<'a> {
     pub(crate) response: Response,
     pub(crate) called_once: bool,
     pub(crate) navigation: NavigationConfig,
+    pub(crate) number_format: NumberFormat,
+    pub(crate) forced_highlights: Vec<SeriesRef>,
+    pub(crate) screen_overlays: Vec<ScreenOverlay<'a>>,
+    /// Stands in for the pointer in the band tooltip, see [`Self::simulate_hover`].
+    pub(crate) simulated_hover: Option<PlotPoint>,
+    /// This frame's click was already handled (e.g. by the measure tool or a pin rail)
+    /// and mustn't trigger anything else.
+    pub(crate) click_consumed: bool,
+    /// The tooltip's X/Y formatters, which override the coordinate readout this frame.
+    pub(crate) readout_formatters: [Option<TooltipFormatter>; 2],
 }
 
 impl<'a> PlotUi<'a> {
@@ -119,6 +166,37 @@ impl<'a> PlotUi<'a> {
         }
     }
 
+    /// Add UI anchored to a corner (or edge/center) of the plot frame, drawn after the items.
+    ///
+    /// Unlike [`crate::Text`], which lives in plot coordinates, the overlay stays put while
+    /// panning and zooming. Useful for titles, units, watermarks and status badges.
+    pub fn add_screen_overlay(&mut self, anchor: Align2, add_contents: impl FnOnce(&mut Ui) + 'a) {
+        self.screen_overlays.push((anchor, Box::new(add_contents)));
+    }
+
+    /// Highlight the given series this frame, as if it were hovered in the legend.
+    ///
+    /// Can be called before or after the item is added. Useful for brushing across linked
+    /// plots: forward the hovered series from one plot to the others.
+    pub fn highlight_series(&mut self, series: impl Into<SeriesRef>) {
+        self.forced_highlights.push(series.into());
+    }
+
+    /// Data bounds of the given series among the items added so far, e.g. for a
+    /// "zoom to this series" button. Several items matching the same name are merged.
+    ///
+    /// Returns `None` if no item matches or it has no finite data.
+    pub fn bounds_of_series(&self, series: impl Into<SeriesRef>) -> Option<PlotBounds> {
+        let series = series.into();
+        let mut bounds = PlotBounds::NOTHING;
+        for item in self.actions.iter_items() {
+            if series.matches(&**item) {
+                bounds.merge(&item.bounds());
+            }
+        }
+        bounds.is_valid().then_some(bounds)
+    }
+
     /// The pointer position in plot coordinates. Independent of whether the pointer is in the plot area.
     pub fn pointer_coordinate(&self) -> Option<PlotPoint> {
         // We need to subtract the drag delta to keep in sync with the frame-delayed screen transform:
@@ -149,6 +227,72 @@ impl<'a> PlotUi<'a> {
         self.last_plot_transform.value_from_position(position)
     }
 
+    /// Screen X of the vertical gridline at plot value `x`, e.g. to align a custom label with it.
+    ///
+    /// Honors a segmented X axis. The result may lie outside the plot frame.
+    pub fn gridline_screen_x(&self, x: f64) -> f32 {
+        self.last_plot_transform.position_from_point_x(x)
+    }
+
+    /// Screen Y of the horizontal gridline at plot value `y`.
+    pub fn gridline_screen_y(&self, y: f64) -> f32 {
+        self.last_plot_transform.position_from_point_y(y)
+    }
+
+    /// Samples of every item added so far whose X lies within the current plot bounds.
+    ///
+    /// Returns one `(series name, samples)` entry per item with point geometry.
+    /// Series with sorted X use a binary search; others are filtered linearly.
+    /// Y values are the original data (see [`Self::waterfall`] for offset traces).
+    pub fn visible_data(&self) -> Vec<(String, Vec<[f64; 2]>)> {
+        let bounds = self.plot_bounds();
+        let (x_min, x_max) = (bounds.min()[0], bounds.max()[0]);
+        let visible = |x: f64| x_min <= x && x <= x_max;
+
+        let mut out = Vec::new();
+        for item in self.actions.iter_items() {
+            let dy = item.y_offset();
+            let mut samples: Vec<[f64; 2]> = Vec::new();
+            let sorted_x = item.sorted_x();
+            let mut push_xy = |xs: &[f64], ys: &[f64], sorted_x: bool| {
+                let series = crate::ColumnarSeries::new_truncating(xs, ys);
+                if sorted_x {
+                    let range = series.visible_range(x_min, x_max);
+                    samples.extend(series.slice(range).iter().map(|(x, y)| [x, y - dy]));
+                } else {
+                    samples.extend(
+                        series
+                            .iter()
+                            .filter(|(x, _)| visible(*x))
+                            .map(|(x, y)| [x, y - dy]),
+                    );
+                }
+            };
+            match item.geometry() {
+                PlotGeometry::Points(points) => {
+                    samples.extend(
+                        points
+                            .iter()
+                            .filter(|p| visible(p.x))
+                            .map(|p| [p.x, p.y - dy]),
+                    );
+                }
+                PlotGeometry::PointsXY { xs, ys } => push_xy(xs, ys, sorted_x),
+                PlotGeometry::BlocksXY {
+                    xs_blocks,
+                    ys_blocks,
+                } => {
+                    for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                        push_xy(xs, ys, false);
+                    }
+                }
+                PlotGeometry::Rects | PlotGeometry::None => continue,
+            }
+            out.push((item.name().to_owned(), samples));
+        }
+        out
+    }
+
     /// Add an arbitrary item.
     pub fn add(&mut self, item: impl PlotItem + 'a) {
         self.actions.add_item(Box::new(item));
@@ -163,9 +307,41 @@ impl<'a> PlotUi<'a> {
         if line.stroke.color == Color32::TRANSPARENT {
             line.stroke.color = self.auto_color();
         }
+        line.number_format = self.number_format;
         self.actions.add_item(Box::new(line));
     }
 
+    /// Add several series as a waterfall: series `i` is drawn shifted up by `i * offset`.
+    ///
+    /// Useful for comparing many similar traces without them overlapping.
+    /// Tooltips report the original (un-offset) Y values.
+    ///
+    /// The offset is baked into the geometry, so each call copies every series into an owned
+    /// line. For large or many series, add pre-offset [`crate::Line`]s instead.
+    pub fn waterfall(&mut self, series: &[(&str, crate::ColumnarSeries<'_>)], offset: f64) {
+        for (i, (name, s)) in series.iter().enumerate() {
+            let dy = i as f64 * offset;
+            let points: Vec<[f64; 2]> = s.iter().map(|(x, y)| [x, y + dy]).collect();
+            let mut line = crate::Line::new(*name, points);
+            line.y_offset = dy;
+            self.line(line);
+        }
+    }
+
+    /// Add several data lines at once. Equivalent to calling [`Self::line`] for each.
+    pub fn lines(&mut self, lines: impl IntoIterator<Item = crate::Line<'a>>) {
+        for line in lines {
+            self.line(line);
+        }
+    }
+
+    /// Add several scatter series at once. Equivalent to calling [`Self::add`] for each.
+    pub fn scatters(&mut self, scatters: impl IntoIterator<Item = crate::Scatter<'a>>) {
+        for scatter in scatters {
+            self.add(scatter);
+        }
+    }
+
     /// Add a polygon. The polygon has to be convex.
     pub fn polygon(&mut self, mut polygon: crate::Polygon<'a>) {
         if polygon.series.is_empty() {
@@ -206,6 +382,25 @@ impl<'a> PlotUi<'a> {
         }
         self.actions.add_item(Box::new(arrows));
     }
+    /// Add a rug: short ticks along the bottom (X) or left (Y) edge, one per position.
+    pub fn rug(&mut self, mut rug: crate::Rug<'a>) {
+        if rug.positions.is_empty() {
+            return;
+        }
+        if rug.stroke.color == Color32::TRANSPARENT {
+            rug.stroke.color = self.auto_color();
+        }
+        self.actions.add_item(Box::new(rug));
+    }
+
+    /// Add a confidence ellipse.
+    pub fn confidence_ellipse(&mut self, mut ellipse: crate::ConfidenceEllipse) {
+        if ellipse.stroke.color == Color32::TRANSPARENT {
+            ellipse.stroke.color = self.auto_color();
+        }
+        self.actions.add_item(Box::new(ellipse));
+    }
+
     /// Add an image.
     pub fn image(&mut self, image: crate::PlotImage) {
         self.actions.add_item(Box::new(image));
@@ -265,4 +460,39 @@ impl<'a> PlotUi<'a> {
         }
         self.actions.add_item(Box::new(band));
     }
+
+    /// Add a stacked area chart, one [`crate::Band`] per layer (see [`crate::StackedArea`]).
+    pub fn stacked_area(&mut self, area: crate::StackedArea<'_>) {
+        for band in area.into_bands() {
+            self.band(band);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColumnarSeries, Plot, run_headless_frame};
+
+    #[test]
+    fn test_waterfall_offsets() {
+        let xs = [0.0, 1.0];
+        let (a, b) = ([1.0, 2.0], [3.0, 4.0]);
+        let ctx = egui::Context::default();
+        let mut offsets = Vec::new();
+        run_headless_frame(&ctx, Vec::new(), |ui| {
+            Plot::new("waterfall").show(ui, |plot_ui| {
+                let series = [
+                    ("a", ColumnarSeries::new(&xs, &a)),
+                    ("b", ColumnarSeries::new(&xs, &b)),
+                ];
+                plot_ui.waterfall(&series, 10.0);
+                offsets = plot_ui
+                    .actions
+                    .iter_items()
+                    .map(|item| (item.y_offset(), item.bounds().range_y()))
+                    .collect();
+            });
+        });
+        assert_eq!(offsets, vec![(0.0, 1.0..=2.0), (10.0, 13.0..=14.0)]);
+    }
 }
@@ -1,43 +1,274 @@
-use crate::Interval;
+use crate::{Interval, TickBasis};
+
+/// Glyph drawn on the axis strip in each gap of a [`SegmentedAxis`], marking the cut.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BreakStyle {
+    /// Leave the gap empty.
+    #[default]
+    None,
+
+    /// Two parallel slashes, `//`.
+    Slash,
+
+    /// A zigzag across the gap.
+    Zigzag,
+}
+
+/// How a [`SegmentedAxis`] shares the plot width between its segments.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SegmentSizing {
+    /// Each segment is as wide as its data span, at one common scale.
+    #[default]
+    ByDataSpan,
+
+    /// Every segment gets the same width, whatever its data span.
+    Equal,
+}
+
+/// How data maps to screen inside one segment of a [`SegmentedAxis`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SegmentScale {
+    /// Screen position proportional to the value.
+    #[default]
+    Linear,
+
+    /// Screen position proportional to `log10(value)`, with decade ticks.
+    ///
+    /// Falls back to [`Self::Linear`] for segments that don't lie strictly above zero
+    /// or have an infinite end.
+    Log10,
+}
+
+impl SegmentScale {
+    /// The scale actually applied to `seg`, after the fallback described on [`Self::Log10`].
+    fn effective(self, seg: &Interval) -> Self {
+        match self {
+            Self::Log10 if seg.start > 0.0 && seg.end.is_finite() && seg.end > seg.start => {
+                Self::Log10
+            }
+            _ => Self::Linear,
+        }
+    }
+
+    /// Position of `x` inside `seg`, from `0.0` at the start to `1.0` at the end.
+    pub(crate) fn fraction(self, seg: &Interval, x: f64) -> f64 {
+        match self.effective(seg) {
+            Self::Linear => {
+                let len = seg.len();
+                if len > 0.0 {
+                    (x - seg.start) / len
+                } else {
+                    0.0
+                }
+            }
+            Self::Log10 => {
+                let (lo, hi) = (seg.start.log10(), seg.end.log10());
+                (x.log10() - lo) / (hi - lo)
+            }
+        }
+    }
+
+    /// Inverse of [`Self::fraction`].
+    pub(crate) fn value_at(self, seg: &Interval, t: f64) -> f64 {
+        match self.effective(seg) {
+            Self::Linear => seg.start + t * seg.len(),
+            Self::Log10 => {
+                let (lo, hi) = (seg.start.log10(), seg.end.log10());
+                10f64.powf(lo + t * (hi - lo))
+            }
+        }
+    }
+}
+
+/// One visible data range of a [`SegmentedAxis`] and how data maps to screen inside it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Segment {
+    pub interval: Interval,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scale: SegmentScale,
+}
+
+impl Segment {
+    /// A linear segment covering `interval`.
+    #[inline]
+    pub fn linear(interval: Interval) -> Self {
+        Self {
+            interval,
+            scale: SegmentScale::Linear,
+        }
+    }
+}
 
 /// Declarative layout for a segmented axis:
-/// - `segments` are the visible data ranges, in order.
+/// - `segments` are the visible data ranges, in order, each with its [`SegmentScale`].
 /// - `gap_px` is the visual gap (in screen points) drawn between them.
+/// - `tick_basis` is the "nice number" ladder used by [`Self::segment_ticks`].
+/// - `tick_cluster_px` is the distance (in screen points) below which tick labels are merged.
+/// - `break_style` is the [`BreakStyle`] glyph drawn in each gap on the axis strip.
+/// - `sizing` is how the segments share the plot width, see [`SegmentSizing`].
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct SegmentedAxis {
-    pub segments: Vec<Interval>,
+    pub segments: Vec<Segment>,
     pub gap_px: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tick_basis: TickBasis,
+    /// `None` = use the axis' minimum label spacing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tick_cluster_px: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub break_style: BreakStyle,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sizing: SegmentSizing,
 }
 
 impl SegmentedAxis {
     /// Create and sanitize (sort, drop empties, merge overlaps).
-    pub fn new(mut segments: Vec<Interval>, gap_px: f32) -> Self {
+    pub fn new(segments: Vec<Interval>, gap_px: f32) -> Self {
+        Self::with_scales(
+            segments
+                .into_iter()
+                .map(|iv| (iv, SegmentScale::Linear))
+                .collect(),
+            gap_px,
+        )
+    }
+
+    /// Like [`Self::new`], with a [`SegmentScale`] per segment, e.g. linear near zero and
+    /// logarithmic further out. Merged segments keep the scale of the first one.
+    pub fn with_scales(mut segments: Vec<(Interval, SegmentScale)>, gap_px: f32) -> Self {
         // 1. drop bad/empty/non-finite segments
-        segments.retain(|iv| !iv.is_empty());
+        segments.retain(|(iv, _)| !iv.is_empty());
 
         // 2. sort by start
-        segments.sort_by(|a, b| {
+        segments.sort_by(|(a, _), (b, _)| {
             a.start
                 .partial_cmp(&b.start)
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
         // 3. merge overlaps / touching ones so we don't get nonsense
-        let mut merged: Vec<Interval> = Vec::new();
-        for iv in segments {
+        let mut merged: Vec<Segment> = Vec::new();
+        for (interval, scale) in segments {
             if let Some(last) = merged.last_mut() {
-                if iv.start <= last.end {
-                    last.end = last.end.max(iv.end);
+                if interval.start <= last.interval.end {
+                    last.interval.end = last.interval.end.max(interval.end);
                     continue;
                 }
             }
-            merged.push(iv);
+            merged.push(Segment { interval, scale });
         }
 
         Self {
             segments: merged,
             gap_px,
+            tick_basis: TickBasis::default(),
+            tick_cluster_px: None,
+            break_style: BreakStyle::None,
+            sizing: SegmentSizing::ByDataSpan,
+        }
+    }
+
+    /// Build the segments from sample positions: wherever consecutive `xs` are more than
+    /// `min_gap` apart, the axis is cut, so each segment spans one dense stretch of data.
+    ///
+    /// Non-finite values are ignored and `xs` need not be sorted. A segment holding a single
+    /// sample is widened by `min_gap / 2` on each side so it stays visible; the result is then
+    /// sanitized like [`Self::new`].
+    pub fn from_gaps(xs: &[f64], min_gap: f64, gap_px: f32) -> Self {
+        let mut xs: Vec<f64> = xs.iter().copied().filter(|x| x.is_finite()).collect();
+        xs.sort_by(f64::total_cmp);
+
+        let mut segments = Vec::new();
+        let mut start = 0;
+        for i in 1..=xs.len() {
+            if i < xs.len() && xs[i] - xs[i - 1] <= min_gap {
+                continue;
+            }
+            let (first, last) = (xs[start], xs[i - 1]);
+            if first < last {
+                segments.push(Interval::new(first, last));
+            } else {
+                let pad = 0.5 * min_gap.max(0.0);
+                segments.push(Interval::new(first - pad, last + pad));
+            }
+            start = i;
+        }
+        Self::new(segments, gap_px)
+    }
+
+    /// The data range of each segment, in order.
+    #[inline]
+    pub fn intervals(&self) -> impl ExactSizeIterator<Item = &Interval> + '_ {
+        self.segments.iter().map(|seg| &seg.interval)
+    }
+
+    /// Scale of segment `index`; [`SegmentScale::Linear`] if out of range.
+    #[inline]
+    pub fn scale_of(&self, index: usize) -> SegmentScale {
+        self.segments
+            .get(index)
+            .map_or(SegmentScale::Linear, |seg| seg.scale)
+    }
+
+    /// Decade values `10^k` inside segment `index` if it is log-scaled, `None` if linear.
+    pub(crate) fn decade_ticks(&self, index: usize) -> Option<Vec<f64>> {
+        let Segment {
+            interval: seg,
+            scale,
+        } = self.segments.get(index)?;
+        if scale.effective(seg) != SegmentScale::Log10 {
+            return None;
+        }
+        let first = seg.start.log10().ceil() as i32;
+        let last = seg.end.log10().floor() as i32;
+        Some((first..=last).map(|k| 10f64.powi(k)).collect())
+    }
+
+    /// Set the "nice number" ladder used for the per-segment ticks. Default: 1-2-5.
+    #[inline]
+    pub fn tick_basis(mut self, basis: TickBasis) -> Self {
+        self.tick_basis = basis;
+        self
+    }
+
+    /// Merge tick labels closer than `px` screen points into one, preferring segment edges.
+    ///
+    /// Default: the axis' minimum label spacing (see [`crate::AxisHints::label_spacing`]).
+    #[inline]
+    pub fn tick_cluster_px(mut self, px: f32) -> Self {
+        self.tick_cluster_px = Some(px);
+        self
+    }
+
+    /// Mark each gap on the axis with a break glyph. Default: [`BreakStyle::None`].
+    #[inline]
+    pub fn break_style(mut self, style: BreakStyle) -> Self {
+        self.break_style = style;
+        self
+    }
+
+    /// Share the plot width by data span (default) or equally, see [`SegmentSizing`].
+    #[inline]
+    pub fn sizing(mut self, sizing: SegmentSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Width of segment `index` in data units at the axis' common scale: its own span, or
+    /// the mean span when segments are sized equally. The widths always add up to the total
+    /// span.
+    pub(crate) fn segment_width(&self, index: usize) -> f64 {
+        match self.sizing {
+            SegmentSizing::ByDataSpan => self.segments.get(index).map_or(0.0, |s| s.interval.len()),
+            SegmentSizing::Equal => {
+                let total: f64 = self.segments.iter().map(|s| s.interval.len()).sum();
+                total / self.segments.len().max(1) as f64
+            }
         }
     }
 
@@ -46,10 +277,25 @@ impl SegmentedAxis {
     pub fn is_multi_segment(&self) -> bool {
         self.segments.len() > 1
     }
+
+    /// Index of the segment containing the data value `x`, or `None` if `x` falls in a gap
+    /// (or is NaN). Segment ends are inclusive and may be infinite.
+    pub fn segment_of(&self, x: f64) -> Option<usize> {
+        if x.is_nan() {
+            return None;
+        }
+        // Segments are sorted and disjoint after `new`.
+        let i = self.segments.partition_point(|seg| seg.interval.end < x);
+        self.segments
+            .get(i)
+            .filter(|seg| seg.interval.contains(x))
+            .map(|_| i)
+    }
+
     pub fn segment_ticks(&self, step_hint: f64) -> Vec<Vec<f64>> {
         let mut max_raw_step = 0.0;
 
-        for seg in &self.segments {
+        for seg in self.intervals() {
             let lo = seg.start;
             let hi = seg.end;
 
@@ -71,11 +317,22 @@ impl SegmentedAxis {
             return vec![Vec::new(); self.segments.len()];
         }
 
-        let nice = nice_step(max_raw_step);
+        let nice = self.tick_basis.nice_step(max_raw_step);
 
         let mut out: Vec<Vec<f64>> = Vec::with_capacity(self.segments.len());
 
-        for seg in &self.segments {
+        for (index, seg) in self.intervals().enumerate() {
+            if let Some(mut ticks) = self.decade_ticks(index) {
+                if ticks.first().copied() != Some(seg.start) {
+                    ticks.insert(0, seg.start);
+                }
+                if ticks.last().copied() != Some(seg.end) {
+                    ticks.push(seg.end);
+                }
+                out.push(ticks);
+                continue;
+            }
+
             let lo = seg.start;
             let hi = seg.end;
 
@@ -115,17 +372,101 @@ impl SegmentedAxis {
     }
 }
 
-fn nice_step(step: f64) -> f64 {
-    let pow10 = 10.0_f64.powf(step.log10().floor());
-    let mant = step / pow10;
-    let nice_mant = if mant < 1.5 {
-        1.0
-    } else if mant < 3.5 {
-        2.0
-    } else if mant < 7.5 {
-        5.0
-    } else {
-        10.0
-    };
-    nice_mant * pow10
+#[cfg(test)]
+mod tests {
+    use egui::{Pos2, Rect, Vec2};
+
+    use super::*;
+    use crate::{PlotBounds, PlotTransform};
+
+    #[test]
+    fn test_segment_sizing_equal() {
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::new(110.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [50.0, 1.0]),
+            false,
+        );
+        transform.set_segment_xaxis(Some(
+            SegmentedAxis::new(
+                vec![Interval::new(0.0, 10.0), Interval::new(20.0, 50.0)],
+                10.0,
+            )
+            .sizing(SegmentSizing::Equal),
+        ));
+        // 100 px of data shared by two segments, whatever their spans.
+        assert_eq!(transform.broken_x_to_screen(5.0), Some(25.0));
+        assert_eq!(transform.broken_x_to_screen(10.0), Some(50.0));
+        assert_eq!(transform.broken_x_to_screen(35.0), Some(85.0));
+        assert_eq!(transform.screen_x_to_broken_world(85.0), Some(35.0));
+        assert_eq!(
+            transform.segment_x_gap_screen_ranges(),
+            Some(vec![(50.0, 60.0)])
+        );
+    }
+
+    #[test]
+    fn test_segment_of() {
+        let axis = SegmentedAxis::new(
+            vec![
+                Interval::new(10.0, 20.0),
+                Interval::below(0.0),
+                Interval::above(30.0),
+            ],
+            8.0,
+        );
+        assert_eq!(axis.segment_of(f64::NEG_INFINITY), Some(0));
+        assert_eq!(axis.segment_of(0.0), Some(0));
+        assert_eq!(axis.segment_of(5.0), None);
+        assert_eq!(axis.segment_of(10.0), Some(1));
+        assert_eq!(axis.segment_of(25.0), None);
+        assert_eq!(axis.segment_of(1e300), Some(2));
+        assert_eq!(axis.segment_of(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_segmented_axis_from_gaps() {
+        let mut xs: Vec<f64> = (0..=100).map(f64::from).collect();
+        xs.extend((1100..=1200).map(f64::from));
+        xs.push(f64::NAN);
+        let axis = SegmentedAxis::from_gaps(&xs, 10.0, 8.0);
+        assert_eq!(
+            axis.segments,
+            vec![
+                Segment::linear(Interval::new(0.0, 100.0)),
+                Segment::linear(Interval::new(1100.0, 1200.0))
+            ]
+        );
+
+        // A lone sample keeps a segment of its own.
+        let axis = SegmentedAxis::from_gaps(&[0.0, 1.0, 50.0, 100.0, 101.0], 10.0, 8.0);
+        assert_eq!(axis.segments.len(), 3);
+        assert_eq!(axis.segments[1].interval, Interval::new(45.0, 55.0));
+        assert!(SegmentedAxis::from_gaps(&[], 10.0, 8.0).segments.is_empty());
+    }
+
+    #[test]
+    fn test_segment_scale_log10() {
+        let axis = SegmentedAxis::with_scales(
+            vec![
+                (Interval::new(10.0, 10_000.0), SegmentScale::Log10),
+                (Interval::new(-1.0, 1.0), SegmentScale::Linear),
+            ],
+            8.0,
+        );
+        assert_eq!(axis.scale_of(0), SegmentScale::Linear);
+        assert_eq!(axis.scale_of(1), SegmentScale::Log10);
+
+        let seg = axis.segments[1].interval;
+        assert!((SegmentScale::Log10.fraction(&seg, 100.0) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((SegmentScale::Log10.value_at(&seg, 2.0 / 3.0) - 1000.0).abs() < 1e-9);
+        assert_eq!(
+            axis.decade_ticks(1),
+            Some(vec![10.0, 100.0, 1000.0, 10_000.0])
+        );
+        assert_eq!(axis.decade_ticks(0), None);
+
+        // Non-positive segments can't be log-scaled and stay linear.
+        let seg = axis.segments[0].interval;
+        assert_eq!(SegmentScale::Log10.fraction(&seg, 0.0), 0.5);
+    }
 }
@@ -1,21 +1,27 @@
-use crate::{Interval, PlotPoint, PlotTransform};
+use crate::{Axis, Interval, PlotPoint, PlotTransform};
 use egui::Rect;
 
 /// Convert a Y-interval in data space to screen-space vertical span.
 pub fn interval_to_screen_y(interval: &Interval, tf: &PlotTransform) -> (f32, f32) {
     let frame: Rect = *tf.frame();
+    // Screen edges of the unbounded ends; a reversed axis grows downwards.
+    let (lo, hi) = if tf.is_reversed(Axis::Y) {
+        (frame.top(), frame.bottom())
+    } else {
+        (frame.bottom(), frame.top())
+    };
 
     let y0 = if interval.start.is_finite() {
         tf.position_from_point(&PlotPoint::new(0.0, interval.start))
             .y
     } else {
-        frame.bottom()
+        lo
     };
 
     let y1 = if interval.end.is_finite() {
         tf.position_from_point(&PlotPoint::new(0.0, interval.end)).y
     } else {
-        frame.top()
+        hi
     };
 
     (y0.min(y1), y0.max(y1))
@@ -24,18 +30,23 @@ pub fn interval_to_screen_y(interval: &Interval, tf: &PlotTransform) -> (f32, f3
 /// Convert an X-interval in data space to screen-space horizontal span.
 pub fn interval_to_screen_x(interval: &Interval, tf: &PlotTransform) -> (f32, f32) {
     let frame: Rect = *tf.frame();
+    let (lo, hi) = if tf.is_reversed(Axis::X) {
+        (frame.right(), frame.left())
+    } else {
+        (frame.left(), frame.right())
+    };
 
     let x0 = if interval.start.is_finite() {
         tf.position_from_point(&PlotPoint::new(interval.start, 0.0))
             .x
     } else {
-        frame.left()
+        lo
     };
 
     let x1 = if interval.end.is_finite() {
         tf.position_from_point(&PlotPoint::new(interval.end, 0.0)).x
     } else {
-        frame.right()
+        hi
     };
 
     (x0.min(x1), x0.max(x1))
@@ -0,0 +1,158 @@
+use crate::TickBasis;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// Tick steps that land on clock boundaries, in seconds. Longer steps are whole days.
+const TIME_STEPS: [f64; 18] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    MINUTE,
+    2.0 * MINUTE,
+    5.0 * MINUTE,
+    10.0 * MINUTE,
+    15.0 * MINUTE,
+    30.0 * MINUTE,
+    HOUR,
+    2.0 * HOUR,
+    3.0 * HOUR,
+    6.0 * HOUR,
+    12.0 * HOUR,
+    DAY,
+];
+
+/// How [`crate::AxisHints::time_formatter`] writes Unix timestamps (seconds, UTC).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TimeFormat {
+    /// Pick from the visible span and the tick step: seconds while ticks are finer than a
+    /// minute, then `HH:MM`, the date with time beyond two days, and the date alone for
+    /// ticks of a day or more.
+    #[default]
+    Auto,
+
+    /// `12:30:45`, with milliseconds for sub-second ticks.
+    Time,
+
+    /// `12:30`
+    HourMinute,
+
+    /// `2024-06-01 12:30`
+    DateTime,
+
+    /// `2024-06-01`
+    Date,
+}
+
+impl TimeFormat {
+    /// The format actually used for a tick of `step_size` seconds over `span` seconds.
+    fn resolve(self, step_size: f64, span: f64) -> Self {
+        match self {
+            Self::Auto if step_size < MINUTE => Self::Time,
+            Self::Auto if step_size >= DAY => Self::Date,
+            Self::Auto if span <= 2.0 * DAY => Self::HourMinute,
+            Self::Auto => Self::DateTime,
+            format => format,
+        }
+    }
+
+    /// Label for the Unix time `seconds`, given the tick step and the visible span (seconds).
+    pub(crate) fn format(self, seconds: f64, step_size: f64, span: f64) -> String {
+        if !seconds.is_finite() {
+            return String::new();
+        }
+        let millis = (seconds * 1000.0).round() as i64;
+        let days = millis.div_euclid(86_400_000);
+        let ms_of_day = millis.rem_euclid(86_400_000);
+        let (h, m, s, ms) = (
+            ms_of_day / 3_600_000,
+            ms_of_day / 60_000 % 60,
+            ms_of_day / 1000 % 60,
+            ms_of_day % 1000,
+        );
+        let (year, month, day) = civil_from_days(days);
+
+        match self.resolve(step_size, span) {
+            Self::Time if step_size < 1.0 => format!("{h:02}:{m:02}:{s:02}.{ms:03}"),
+            Self::Time => format!("{h:02}:{m:02}:{s:02}"),
+            Self::HourMinute => format!("{h:02}:{m:02}"),
+            Self::DateTime => format!("{year:04}-{month:02}-{day:02} {h:02}:{m:02}"),
+            Self::Date | Self::Auto => format!("{year:04}-{month:02}-{day:02}"),
+        }
+    }
+}
+
+/// `(year, month, day)` of the proleptic Gregorian calendar, `days` after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    // Howard Hinnant's `civil_from_days`, with eras of 400 years starting on March 1st.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The smallest clock-aligned step of at least `step` seconds.
+///
+/// Below a second, decimal 1-2-5 fractions; beyond a day, 1-2-5 multiples of days.
+pub(crate) fn time_step_at_least(step: f64) -> f64 {
+    if step < 1.0 {
+        return TickBasis::Standard.nice_step_at_least(step);
+    }
+    TIME_STEPS
+        .iter()
+        .copied()
+        .find(|&s| s >= step)
+        .unwrap_or_else(|| DAY * TickBasis::Standard.nice_step_at_least(step / DAY))
+}
+
+/// A coarser clock-aligned step, at least five times and a whole multiple of `step`.
+pub(crate) fn coarser_time_step(step: f64) -> f64 {
+    let mut coarser = time_step_at_least(5.0 * step);
+    while ((coarser / step) - (coarser / step).round()).abs() > 1e-9 {
+        coarser = time_step_at_least(coarser * 1.5);
+    }
+    coarser
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GridInput, time_grid_spacer};
+
+    #[test]
+    fn test_time_axis_labels() {
+        let t = 1_717_245_045.0; // 2024-06-01 12:30:45 UTC
+        let label = |step, span| TimeFormat::Auto.format(t, step, span);
+        assert_eq!(label(5.0, 120.0), "12:30:45");
+        assert_eq!(label(0.25, 2.0), "12:30:45.000");
+        assert_eq!(label(900.0, 6.0 * 3600.0), "12:30");
+        assert_eq!(label(6.0 * 3600.0, 5.0 * 86_400.0), "2024-06-01 12:30");
+        assert_eq!(label(86_400.0, 30.0 * 86_400.0), "2024-06-01");
+        assert_eq!(TimeFormat::Date.format(0.0, 1.0, 1.0), "1970-01-01");
+        assert_eq!(TimeFormat::Date.format(-86_400.0, 1.0, 1.0), "1969-12-31");
+        assert_eq!(
+            TimeFormat::Date.format(951_782_400.0, 1.0, 1.0),
+            "2000-02-29"
+        );
+
+        // Over two hours at ~10 s per pixel: minute ticks, thicker every 5 and 30 minutes.
+        let marks = time_grid_spacer()(GridInput {
+            bounds: (t, t + 7200.0),
+            base_step_size: 10.0 * 6.0,
+        });
+        assert!(marks.iter().all(|m| m.value % 60.0 == 0.0));
+        let steps: Vec<f64> = marks.iter().map(|m| m.step_size).collect();
+        assert!(steps.contains(&60.0) && steps.contains(&1800.0));
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::RangeInclusive;
 
 use egui::{Pos2, Rect, Vec2, Vec2b, pos2, remap};
 
-use crate::{Axis, segmented_axis::SegmentedAxis};
+use crate::{Axis, GridMark, axis_scale::AxisScale, segmented_axis::SegmentedAxis};
 
 use super::PlotPoint;
 
@@ -45,6 +45,20 @@ impl PlotBounds {
         }
     }
 
+    /// Bounds `span` wide and high around `center`, e.g. for a "focus here" button.
+    ///
+    /// Zero, negative or NaN spans are clamped to a tiny minimum (relative to the magnitude of
+    /// `center`), so the result is valid for any finite `center`.
+    pub fn centered(center: PlotPoint, span: [f64; 2]) -> Self {
+        let center = [center.x, center.y];
+        let half = |i: usize| 0.5 * span[i].max(1e-9 * center[i].abs().max(1.0));
+        let half = [half(0), half(1)];
+        Self {
+            min: [center[0] - half[0], center[1] - half[1]],
+            max: [center[0] + half[0], center[1] + half[1]],
+        }
+    }
+
     #[inline]
     pub fn is_finite(&self) -> bool {
         self.min[0].is_finite()
@@ -213,6 +227,28 @@ impl PlotBounds {
         }
     }
 
+    /// Slide these bounds inside `limit`, keeping their size.
+    ///
+    /// An axis is left alone when these bounds are already wider than `limit` on it, or
+    /// when `limit` is not finite there.
+    pub fn slide_within(&mut self, limit: &Self) {
+        for axis in 0..2 {
+            let (lo, hi) = (limit.min[axis], limit.max[axis]);
+            if !(lo.is_finite() && hi.is_finite()) || self.max[axis] - self.min[axis] > hi - lo {
+                continue;
+            }
+            let shift = if self.min[axis] < lo {
+                lo - self.min[axis]
+            } else if self.max[axis] > hi {
+                hi - self.max[axis]
+            } else {
+                continue;
+            };
+            self.min[axis] += shift;
+            self.max[axis] += shift;
+        }
+    }
+
     #[inline]
     pub fn translate(&mut self, delta: (f64, f64)) {
         self.translate_x(delta.0);
@@ -282,6 +318,19 @@ pub struct PlotTransform {
     pixels_per_x: f32,
 
     segment_x_offset: f32,
+
+    /// Value mapping per axis. A segmented X axis takes precedence over `scales[0]`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [AxisScale; 2],
+
+    /// `[scale, offset]` mapping a primary Y value to the secondary (right) Y axis:
+    /// `y2 = scale * y + offset`. `None` without a secondary axis.
+    #[cfg_attr(feature = "serde", serde(default))]
+    secondary_y: Option<[f64; 2]>,
+
+    /// Axes whose values grow leftwards (X) or downwards (Y). Ignored for a segmented X axis.
+    #[cfg_attr(feature = "serde", serde(default))]
+    reversed: Vec2b,
 }
 
 impl PlotTransform {
@@ -349,12 +398,15 @@ impl PlotTransform {
             segmented_xaxis: None,
             pixels_per_x,
             segment_x_offset: 0.0,
+            scales: [AxisScale::Linear; 2],
+            secondary_y: None,
+            reversed: Vec2b::FALSE,
         }
     }
 
     /// Enable segmented-x layout on this transform. Call this after constructing with `new()`.
     pub fn with_segmented_xaxis(mut self, segmented: SegmentedAxis) -> Self {
-        if let Some(first) = segmented.segments.first() {
+        if let Some(first) = segmented.intervals().next() {
             let seg_len = first.len().max(f64::EPSILON) as f32;
 
             self.pixels_per_x = self.frame.width() / seg_len;
@@ -384,6 +436,110 @@ impl PlotTransform {
         }
     }
 
+    /// Set how values map to the screen along `axis`, see [`AxisScale`].
+    pub fn set_scale(&mut self, axis: Axis, scale: AxisScale) {
+        self.scales[usize::from(axis)] = scale;
+    }
+
+    /// The value mapping in effect along `axis`; always linear for a segmented X axis.
+    pub fn scale(&self, axis: Axis) -> AxisScale {
+        match axis {
+            Axis::X if self.segmented_xaxis.is_some() => AxisScale::Linear,
+            _ => self.scales[usize::from(axis)],
+        }
+    }
+
+    /// Flip the direction of `axis`: larger X values appear further left, larger Y values
+    /// further down.
+    pub fn set_reversed(&mut self, axis: Axis, reversed: bool) {
+        self.reversed[usize::from(axis)] = reversed;
+    }
+
+    /// Whether `axis` is flipped, see [`Self::set_reversed`]. Always `false` for a segmented
+    /// X axis.
+    pub fn is_reversed(&self, axis: Axis) -> bool {
+        match axis {
+            Axis::X => self.reversed.x && self.segmented_xaxis.is_none(),
+            Axis::Y => self.reversed.y,
+        }
+    }
+
+    /// The screen coordinates the bounds' `min..=max` map to along `axis`.
+    fn screen_range(&self, axis: Axis) -> RangeInclusive<f64> {
+        let (lo, hi) = match axis {
+            Axis::X => (self.frame.left(), self.frame.right()),
+            // negated y axis!
+            Axis::Y => (self.frame.bottom(), self.frame.top()),
+        };
+        if self.is_reversed(axis) {
+            (hi as f64)..=(lo as f64)
+        } else {
+            (lo as f64)..=(hi as f64)
+        }
+    }
+
+    /// Map the current primary Y range onto `min..=max` of the secondary Y axis.
+    ///
+    /// The mapping is kept as the primary bounds change afterwards, so panning and zooming
+    /// move both axes together. Ignored unless `min < max` and the primary Y range is
+    /// non-empty.
+    pub fn set_secondary_y_range(&mut self, min: f64, max: f64) {
+        let height = self.bounds.height();
+        if !(min < max && height > 0.0 && max.is_finite() && min.is_finite()) {
+            return;
+        }
+        let scale = (max - min) / height;
+        self.secondary_y = Some([scale, min - scale * self.bounds.min[1]]);
+    }
+
+    /// The `[scale, offset]` mapping from primary to secondary Y, see
+    /// [`Self::set_secondary_y_range`].
+    #[inline]
+    pub fn secondary_y_map(&self) -> Option<[f64; 2]> {
+        self.secondary_y
+    }
+
+    #[inline]
+    pub fn set_secondary_y_map(&mut self, map: Option<[f64; 2]>) {
+        self.secondary_y = map;
+    }
+
+    /// The transform for items on the secondary (right) Y axis, if there is one.
+    ///
+    /// It shares the frame and X mapping, with linear Y bounds in secondary units.
+    pub fn secondary(&self) -> Option<Self> {
+        let [scale, offset] = self.secondary_y?;
+        let mut secondary = self.clone();
+        secondary.bounds.min[1] = scale * self.bounds.min[1] + offset;
+        secondary.bounds.max[1] = scale * self.bounds.max[1] + offset;
+        secondary.scales[1] = AxisScale::Linear;
+        secondary.secondary_y = None;
+        Some(secondary)
+    }
+
+    /// Convert a secondary Y value back to the primary Y axis.
+    pub fn primary_y_from_secondary(&self, y2: f64) -> f64 {
+        match self.secondary_y {
+            Some([scale, offset]) => (y2 - offset) / scale,
+            None => y2,
+        }
+    }
+
+    /// Apply `f` to the bounds of `axis` in the scale's linear space, then map them back.
+    fn map_scaled_range(&mut self, axis: Axis, f: impl FnOnce(f64, f64) -> (f64, f64)) {
+        let scale = self.scale(axis);
+        let i = usize::from(axis);
+        let (lo, hi) = f(
+            scale.forward(self.bounds.min[i]),
+            scale.forward(self.bounds.max[i]),
+        );
+        let (lo, hi) = (scale.inverse(lo), scale.inverse(hi));
+        if lo.is_finite() && hi.is_finite() && lo < hi {
+            self.bounds.min[i] = lo;
+            self.bounds.max[i] = hi;
+        }
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: (f64, f64)) {
         if self.centered.x {
             delta_pos.0 = 0.;
@@ -391,6 +547,26 @@ impl PlotTransform {
         if self.centered.y {
             delta_pos.1 = 0.;
         }
+
+        // Non-linear axes pan by a constant screen distance, i.e. in their linear space.
+        let frame_size = [
+            *self.screen_range(Axis::X).end() - *self.screen_range(Axis::X).start(),
+            *self.screen_range(Axis::Y).end() - *self.screen_range(Axis::Y).start(),
+        ];
+        for axis in [Axis::X, Axis::Y] {
+            let i = usize::from(axis);
+            let delta = if i == 0 {
+                &mut delta_pos.0
+            } else {
+                &mut delta_pos.1
+            };
+            if !self.scale(axis).is_linear() {
+                let d = *delta / frame_size[i];
+                self.map_scaled_range(axis, |lo, hi| (lo + d * (hi - lo), hi + d * (hi - lo)));
+                *delta = 0.0;
+            }
+        }
+
         delta_pos.0 *= self.dvalue_dpos()[0];
         delta_pos.1 *= self.dvalue_dpos()[1];
         self.bounds.translate((delta_pos.0, delta_pos.1));
@@ -400,11 +576,24 @@ impl PlotTransform {
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
 
+        let old = self.bounds;
         let mut new_bounds = self.bounds;
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
             self.bounds = new_bounds;
+            // Non-linear axes zoom around the center in their linear space.
+            for (axis, factor) in [(Axis::X, zoom_factor.x), (Axis::Y, zoom_factor.y)] {
+                let i = usize::from(axis);
+                if !self.scale(axis).is_linear() {
+                    let c = self
+                        .scale(axis)
+                        .forward(if i == 0 { center.x } else { center.y });
+                    let f = factor as f64;
+                    (self.bounds.min[i], self.bounds.max[i]) = (old.min[i], old.max[i]);
+                    self.map_scaled_range(axis, |lo, hi| (c + (lo - c) / f, c + (hi - c) / f));
+                }
+            }
 
             // keep pixels_per_x in sync ONLY if we are in normal mode
             if self.segmented_xaxis.is_none() {
@@ -420,19 +609,21 @@ impl PlotTransform {
             return self.position_from_point_x_segment(value, bx);
         }
 
+        let s = self.scales[0];
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
+            s.forward(value),
+            s.forward(self.bounds.min[0])..=s.forward(self.bounds.max[0]),
+            self.screen_range(Axis::X),
         ) as f32
     }
 
     /// Y mapping: data.y -> screen.y
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let s = self.scales[1];
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
+            s.forward(value),
+            s.forward(self.bounds.min[1])..=s.forward(self.bounds.max[1]),
+            self.screen_range(Axis::Y),
         ) as f32
     }
 
@@ -452,18 +643,20 @@ impl PlotTransform {
         let x = if let Some(bx) = &self.segmented_xaxis {
             self.value_from_position_x_segment(pos.x, bx)
         } else {
-            remap(
+            let s = self.scales[0];
+            s.inverse(remap(
                 pos.x as f64,
-                (self.frame.left() as f64)..=(self.frame.right() as f64),
-                self.bounds.range_x(),
-            )
+                self.screen_range(Axis::X),
+                s.forward(self.bounds.min[0])..=s.forward(self.bounds.max[0]),
+            ))
         };
 
-        let y = remap(
+        let s = self.scales[1];
+        let y = s.inverse(remap(
             pos.y as f64,
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64),
-            self.bounds.range_y(),
-        );
+            self.screen_range(Axis::Y),
+            s.forward(self.bounds.min[1])..=s.forward(self.bounds.max[1]),
+        ));
 
         PlotPoint::new(x, y)
     }
@@ -482,6 +675,22 @@ impl PlotTransform {
         rect
     }
 
+    /// Screen distance covered by `mark` and its `step_size`, used to fade grid lines and labels.
+    ///
+    /// On a non-linear axis this is measured outwards from the mark (away from zero).
+    pub(crate) fn mark_spacing_px(&self, axis: Axis, mark: &GridMark) -> f32 {
+        let i = usize::from(axis);
+        if self.scale(axis).is_linear() {
+            return (self.dpos_dvalue()[i] * mark.step_size).abs() as f32;
+        }
+        let next = mark.value + mark.step_size.copysign(mark.value);
+        let pos = |v: f64| match axis {
+            Axis::X => self.position_from_point_x(v),
+            Axis::Y => self.position_from_point_y(v),
+        };
+        (pos(next) - pos(mark.value)).abs()
+    }
+
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
     pub fn dpos_dvalue_x(&self) -> f64 {
         if self.segmented_xaxis.is_some() {
@@ -489,13 +698,15 @@ impl PlotTransform {
             // We expose the baseline pixels_per_x.
             self.pixels_per_x as f64
         } else {
-            self.frame.width() as f64 / self.bounds.width()
+            let range = self.screen_range(Axis::X);
+            (range.end() - range.start()) / self.bounds.width()
         }
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height()
+        let range = self.screen_range(Axis::Y);
+        (range.end() - range.start()) / self.bounds.height()
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -574,16 +785,11 @@ impl PlotTransform {
     fn position_from_point_x_segment(&self, x: f64, bx: &SegmentedAxis) -> f32 {
         let mut cursor_px = self.frame.left() + self.segment_x_offset;
 
-        for (i, seg) in bx.segments.iter().enumerate() {
-            let seg_len = seg.len();
-            let seg_px = (seg_len as f32) * self.pixels_per_x;
+        for (i, seg) in bx.intervals().enumerate() {
+            let seg_px = self.segment_px(bx, i);
 
             if seg.contains(x) {
-                let t = if seg_len > 0.0 {
-                    (x - seg.start) / seg_len
-                } else {
-                    0.0
-                };
+                let t = bx.scale_of(i).fraction(seg, x);
                 return cursor_px + (t as f32) * seg_px;
             }
 
@@ -596,13 +802,49 @@ impl PlotTransform {
 
         cursor_px
     }
+
+    /// Screen width of segment `index` of `bx`, see [`SegmentedAxis::sizing`].
+    fn segment_px(&self, bx: &SegmentedAxis, index: usize) -> f32 {
+        (bx.segment_width(index) as f32) * self.pixels_per_x
+    }
+
+    /// Screen X of the data value `world_x`, honoring a segmented X axis.
+    ///
+    /// `None` when `world_x` is NaN or lies in a gap between segments (or outside all of
+    /// them): a segmented axis has no position for skipped values, so annotations there should
+    /// not be drawn. Without a segmented axis this is [`Self::position_from_point_x`].
+    pub fn broken_x_to_screen(&self, world_x: f64) -> Option<f32> {
+        if world_x.is_nan() {
+            return None;
+        }
+        match &self.segmented_xaxis {
+            Some(bx) => {
+                bx.segment_of(world_x)?;
+                Some(self.position_from_point_x_segment(world_x, bx))
+            }
+            None => Some(self.position_from_point_x(world_x)),
+        }
+    }
+
+    /// Inverse of [`Self::broken_x_to_screen`]: the data value at `screen_x`.
+    ///
+    /// `None` when `screen_x` falls in a gap between segments or outside all of them, where
+    /// the screen shows no data values.
+    pub fn screen_x_to_broken_world(&self, screen_x: f32) -> Option<f64> {
+        let x = match &self.segmented_xaxis {
+            Some(bx) => self.value_from_position_x_segment(screen_x, bx),
+            None => self.value_from_position(pos2(screen_x, self.frame.top())).x,
+        };
+        (!x.is_nan()).then_some(x)
+    }
+
     #[inline]
     pub fn segment_xaxis(&self) -> Option<&SegmentedAxis> {
         self.segmented_xaxis.as_ref()
     }
     pub fn is_x_in_visible_segments(&self, x: f64) -> bool {
         if let Some(bx) = &self.segmented_xaxis {
-            for seg in &bx.segments {
+            for seg in bx.intervals() {
                 if x >= seg.start && x <= seg.end {
                     return true;
                 }
@@ -614,13 +856,12 @@ impl PlotTransform {
     }
     /// Inverse of `position_from_point_x_segment`: screen.x -> data.x.
     ///
-    /// Not 1:1 in gaps. Inside a gap we snap to the end of the segment to the left.
+    /// Not 1:1 in gaps: inside a gap, or outside all segments, this is NaN.
     fn value_from_position_x_segment(&self, sx: f32, bx: &SegmentedAxis) -> f64 {
         let mut cursor_px = self.frame.left() + self.segment_x_offset;
 
-        for (i, seg) in bx.segments.iter().enumerate() {
-            let seg_len = seg.len();
-            let seg_px = (seg_len as f32) * self.pixels_per_x;
+        for (i, seg) in bx.intervals().enumerate() {
+            let seg_px = self.segment_px(bx, i);
 
             let seg_start_px = cursor_px;
             let seg_end_px = cursor_px + seg_px;
@@ -631,7 +872,7 @@ impl PlotTransform {
                 } else {
                     0.0
                 };
-                return seg.start + (t as f64) * seg.len();
+                return bx.scale_of(i).value_at(seg, t as f64);
             }
 
             cursor_px += seg_px;
@@ -657,11 +898,7 @@ impl PlotTransform {
         if let Some(bx) = &self.segmented_xaxis {
             let n = bx.segments.len();
 
-            let total_len: f64 = bx
-                .segments
-                .iter()
-                .map(|seg| seg.len().max(f64::EPSILON))
-                .sum();
+            let total_len: f64 = bx.intervals().map(|seg| seg.len().max(f64::EPSILON)).sum();
 
             let total_gap_px: f32 = if n >= 2 {
                 bx.gap_px * ((n as u32).saturating_sub(1)) as f32
@@ -687,8 +924,8 @@ impl PlotTransform {
 
         let mut cursor_px = self.frame.left() + self.segment_x_offset;
 
-        for (i, seg) in bx.segments.iter().enumerate() {
-            let seg_px = (seg.len() as f32) * self.pixels_per_x;
+        for i in 0..bx.segments.len() {
+            let seg_px = self.segment_px(bx, i);
             cursor_px += seg_px;
 
             if i + 1 < bx.segments.len() {
@@ -717,3 +954,128 @@ impl PlotTransform {
         self.segment_x_offset += dx_screen;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::{Color32, Pos2, Vec2};
+
+    use super::*;
+    use crate::{Interval, Line, PlotItem, items};
+
+    #[test]
+    fn test_bounds_centered() {
+        let b = PlotBounds::centered(PlotPoint::new(5.0, -1.0), [10.0, 2.0]);
+        assert_eq!(b, PlotBounds::from_min_max([0.0, -2.0], [10.0, 0.0]));
+        assert!(PlotBounds::centered(PlotPoint::new(1e6, 0.0), [0.0, -3.0]).is_valid());
+        assert!(PlotBounds::centered(PlotPoint::new(0.0, 0.0), [f64::NAN, 1.0]).is_valid());
+    }
+
+    #[test]
+    fn test_bounds_slide_within() {
+        let limit = PlotBounds::from_min_max([0.0, 0.0], [10.0, 4.0]);
+
+        // Panned past the left edge: slides back, keeping the 4 unit width.
+        let mut b = PlotBounds::from_min_max([-3.0, 1.0], [1.0, 2.0]);
+        b.slide_within(&limit);
+        assert_eq!(b, PlotBounds::from_min_max([0.0, 1.0], [4.0, 2.0]));
+
+        // Wider than the limit on Y: that axis is left free.
+        let mut b = PlotBounds::from_min_max([8.0, -5.0], [12.0, 5.0]);
+        b.slide_within(&limit);
+        assert_eq!(b, PlotBounds::from_min_max([6.0, -5.0], [10.0, 5.0]));
+    }
+
+    #[test]
+    fn test_broken_x_screen_mapping() {
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::new(110.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [30.0, 1.0]),
+            false,
+        );
+        transform.set_segment_xaxis(Some(SegmentedAxis::new(
+            vec![Interval::new(0.0, 10.0), Interval::new(20.0, 30.0)],
+            10.0,
+        )));
+        // 100 px of data for 20 units: 5 px per unit, with the 10 px gap after x = 10.
+        let near = |got: Option<f64>, want: f64| got.is_some_and(|v| (v - want).abs() < 1e-4);
+        assert!(near(transform.broken_x_to_screen(5.0).map(f64::from), 25.0));
+        assert!(near(
+            transform.broken_x_to_screen(22.0).map(f64::from),
+            70.0
+        ));
+        assert_eq!(transform.broken_x_to_screen(15.0), None);
+        assert_eq!(transform.broken_x_to_screen(f64::NAN), None);
+
+        assert!(near(transform.screen_x_to_broken_world(25.0), 5.0));
+        assert!(near(transform.screen_x_to_broken_world(70.0), 22.0));
+        assert_eq!(transform.screen_x_to_broken_world(55.0), None);
+    }
+
+    #[test]
+    fn test_secondary_y_transform() {
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+            false,
+        );
+        assert!(transform.secondary().is_none());
+
+        transform.set_secondary_y_range(1000.0, 2000.0);
+        let secondary = transform.secondary().unwrap();
+        assert_eq!(secondary.bounds().range_y(), 1000.0..=2000.0);
+        assert_eq!(secondary.bounds().range_x(), 0.0..=10.0);
+        assert_eq!(
+            secondary.position_from_point_y(1500.0),
+            transform.position_from_point_y(5.0)
+        );
+        assert_eq!(transform.primary_y_from_secondary(1500.0), 5.0);
+
+        // The mapping is kept when the primary bounds move.
+        transform.set_bounds(PlotBounds::from_min_max([0.0, 10.0], [10.0, 20.0]));
+        let secondary = transform.secondary().unwrap();
+        assert_eq!(secondary.bounds().range_y(), 2000.0..=3000.0);
+
+        // A right-axis item is picked through the secondary mapping.
+        let line = Line::new("volume", vec![[5.0, 2500.0]]).on_right_axis(true);
+        let items: [&dyn PlotItem; 1] = [&line];
+        let at = transform.position_from_point(&PlotPoint::new(5.0, 15.0));
+        let (hit, _) =
+            items::pick_nearest_indexed(items.into_iter(), &transform, at, 2.0, Color32::WHITE)
+                .unwrap();
+        assert_eq!(hit.value, PlotPoint::new(5.0, 2500.0));
+    }
+
+    #[test]
+    fn test_reversed_axis_transform() {
+        let mut transform = PlotTransform::new(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+            false,
+        );
+        transform.set_reversed(Axis::X, true);
+        transform.set_reversed(Axis::Y, true);
+
+        // Larger values further left and further down (screen Y grows downwards).
+        let xs: Vec<f32> = (0..=10)
+            .map(|v| transform.position_from_point_x(f64::from(v)))
+            .collect();
+        assert!(xs.windows(2).all(|w| w[1] < w[0]));
+        let ys: Vec<f32> = (0..=10)
+            .map(|v| transform.position_from_point_y(f64::from(v)))
+            .collect();
+        assert!(ys.windows(2).all(|w| w[1] > w[0]));
+        assert_eq!(transform.position_from_point_x(0.0), 100.0);
+        assert_eq!(transform.position_from_point_y(0.0), 0.0);
+
+        // Screen positions still map back to the data values.
+        let value = PlotPoint::new(2.5, 7.5);
+        assert_eq!(
+            transform.value_from_position(transform.position_from_point(&value)),
+            value
+        );
+
+        // Dragging the content to the right (panning by -20 px) shows larger X values.
+        transform.translate_bounds((-20.0, 0.0));
+        assert_eq!(transform.bounds().range_x(), 2.0..=12.0);
+    }
+}
@@ -18,6 +18,7 @@ struct Demo {
     xs: Vec<f64>,
     f1: Vec<f64>,
     f2: Vec<f64>,
+    window_start: f64,
     last_event: String,
 }
 
@@ -42,6 +43,7 @@ impl Demo {
             xs,
             f1,
             f2,
+            window_start: 0.0,
             last_event: String::new(),
         }
     }
@@ -50,7 +52,10 @@ impl Demo {
 impl App for Demo {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
-
+            // Drive the X window from outside the plot (`BoundsChangeCause::Programmatic`).
+            let slider = egui::Slider::new(&mut self.window_start, -TWO_PI..=TWO_PI);
+            let window_changed = ui.add(slider.text("window start")).changed();
+            let window = self.window_start..=self.window_start + TWO_PI;
 
             let xs = self.xs.clone();
             let f1 = self.f1.clone();
@@ -62,7 +67,16 @@ impl App for Demo {
                 .show_y(true)
                 .auto_bounds(false)
                 .default_x_bounds(0.0, TWO_PI)
+                .context_menu(|ui, item, pos| {
+                    #[allow(deprecated)]
+                    let (x, y) = (pos.x, pos.y);
+                    ui.label(format!("x = {x:.3}, y = {y:.3}"));
+                    ui.label(format!("item: {item:?}"));
+                })
                 .show_actions(ui, |plot_ui| {
+                    if window_changed {
+                        plot_ui.set_plot_bounds_x(window);
+                    }
                     plot_ui.line(
                         Line::new_xy("f1(t)", xs.as_slice(), f1.as_slice())
                             .color(Color32::from_rgb(200, 100, 100))
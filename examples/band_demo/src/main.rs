@@ -1,6 +1,6 @@
 #![allow(rustdoc::missing_crate_level_docs)]
 use eframe::{App, Frame, egui};
-use egui::{Color32, Context};
+use egui::{Color32, Context, Stroke};
 use egui_plot::{Band, Line, Plot};
 
 fn main() -> eframe::Result<()> {
@@ -55,7 +55,8 @@ impl App for VarianceDemoApp {
 
             Plot::new("variance_band_plot").show(ui, |plot_ui| {
                 let band = Band::new()
-                    .with_color(Color32::from_rgb(64, 160, 255))
+                    .with_color(Color32::from_rgba_unmultiplied(64, 160, 255, 96))
+                    .envelope_stroke(Some(Stroke::new(1.0, Color32::TRANSPARENT)))
                     .with_series(&self.xs, &self.y_min, &self.y_max);
                 plot_ui.band(band);
 
@@ -0,0 +1,57 @@
+#![allow(rustdoc::missing_crate_level_docs)]
+use eframe::egui;
+use eframe::{App, Frame};
+use egui::{Color32, Context};
+use egui_plot::{ColumnarSeries, Plot, Scatter};
+
+fn main() -> eframe::Result<()> {
+    eframe::run_native(
+        "Scatter::radii demo",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(Demo::new()))),
+    )
+}
+
+struct Demo {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    radii: Vec<f64>,
+    scale: f64,
+}
+
+impl Demo {
+    fn new() -> Self {
+        let n = 40;
+        let xs = (0..n).map(|i| i as f64).collect();
+        let ys = (0..n).map(|i| (i as f64 * 0.7).sin() * 10.0).collect();
+        // Bubble size: some "population" per sample, in points.
+        let radii = (0..n)
+            .map(|i| 4.0 + 10.0 * (1.0 + (i as f64 * 1.3).cos()))
+            .collect();
+        Self {
+            xs,
+            ys,
+            radii,
+            scale: 1.0,
+        }
+    }
+}
+
+impl App for Demo {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Bubble chart: one marker radius per point");
+            ui.add(egui::Slider::new(&mut self.scale, 0.25..=2.0).text("size"));
+
+            let radii: Vec<f64> = self.radii.iter().map(|r| r * self.scale).collect();
+
+            Plot::new("bubble_chart").show(ui, |plot_ui| {
+                plot_ui.add(
+                    Scatter::from_series("bubbles", ColumnarSeries::new(&self.xs, &self.ys))
+                        .color(Color32::from_rgba_unmultiplied(80, 160, 240, 140))
+                        .radii(&radii),
+                );
+            });
+        });
+    }
+}
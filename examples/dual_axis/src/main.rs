@@ -0,0 +1,68 @@
+#![allow(rustdoc::missing_crate_level_docs)]
+use eframe::egui;
+use eframe::{App, Frame};
+use egui::{Color32, Context};
+use egui_plot::{Axis, AxisHints, Bar, BarChart, Line, Plot};
+
+fn main() -> eframe::Result<()> {
+    eframe::run_native(
+        "Secondary Y axis demo",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(Demo::new()))),
+    )
+}
+
+struct Demo {
+    days: Vec<f64>,
+    price: Vec<f64>,
+    volume: Vec<f64>,
+}
+
+impl Demo {
+    fn new() -> Self {
+        let days: Vec<f64> = (0..120).map(f64::from).collect();
+        let price = days
+            .iter()
+            .map(|&d| 100.0 + 8.0 * (d * 0.08).sin() + 0.1 * d)
+            .collect();
+        let volume = days
+            .iter()
+            .map(|&d| 1.5e6 + 1.0e6 * (d * 0.31).cos().abs())
+            .collect();
+        Self {
+            days,
+            price,
+            volume,
+        }
+    }
+}
+
+impl App for Demo {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Price (left axis) over volume (right axis)");
+
+            Plot::new("dual_axis")
+                .custom_y_axes(vec![AxisHints::new(Axis::Y).label("price")])
+                .right_y_axis(AxisHints::new(Axis::Y).label("volume"))
+                .show(ui, |plot_ui| {
+                    let bars = self
+                        .days
+                        .iter()
+                        .zip(&self.volume)
+                        .map(|(&d, &v)| Bar::new(d, v).width(0.8))
+                        .collect();
+                    plot_ui.bar_chart(
+                        BarChart::new("volume", bars)
+                            .color(Color32::from_rgb(120, 120, 160))
+                            .on_right_axis(true),
+                    );
+                    plot_ui.line(
+                        Line::new_xy("price", &self.days, &self.price)
+                            .color(Color32::from_rgb(240, 180, 60))
+                            .width(2.0),
+                    );
+                });
+        });
+    }
+}
@@ -0,0 +1,54 @@
+#![allow(rustdoc::missing_crate_level_docs)]
+use eframe::egui;
+use eframe::{App, Frame};
+use egui::{Context, Vec2b};
+use egui_plot::{Line, LinkGroup, Plot, PlotPoints};
+
+fn main() -> eframe::Result<()> {
+    eframe::run_native(
+        "LinkGroup demo",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(Demo::new()))),
+    )
+}
+
+struct Demo {
+    timeline: LinkGroup,
+}
+
+impl Demo {
+    fn new() -> Self {
+        Self {
+            timeline: LinkGroup::new("timeline"),
+        }
+    }
+}
+
+impl App for Demo {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Pan or zoom either plot: both follow along X");
+            let height = ui.available_height() / 2.0 - ui.spacing().item_spacing.y;
+
+            Plot::new("upper")
+                .height(height)
+                .link_axis(self.timeline, Vec2b::new(true, false))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(
+                        "sin",
+                        PlotPoints::from_explicit_callback(f64::sin, .., 512),
+                    ));
+                });
+
+            Plot::new("lower")
+                .height(height)
+                .link_axis(self.timeline, Vec2b::new(true, false))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(
+                        "x cos(x)",
+                        PlotPoints::from_explicit_callback(|x| x * x.cos(), .., 512),
+                    ));
+                });
+        });
+    }
+}
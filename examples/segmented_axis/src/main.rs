@@ -1,7 +1,7 @@
 #![allow(rustdoc::missing_crate_level_docs)]
 use eframe::egui;
 use egui::{Color32, Slider};
-use egui_plot::{Interval, Line, Plot, SegmentedAxis, TooltipOptions};
+use egui_plot::{BreakStyle, Interval, Line, Plot, SegmentedAxis, TooltipOptions};
 
 fn main() -> eframe::Result<()> {
     eframe::run_native(
@@ -17,6 +17,7 @@ struct MyApp {
 
     mode: usize,
     gap_px: f32,
+    break_style: BreakStyle,
 }
 
 impl Default for MyApp {
@@ -45,6 +46,7 @@ impl Default for MyApp {
             ys,
             mode: 2,
             gap_px: 12.0,
+            break_style: BreakStyle::Slash,
         }
     }
 }
@@ -69,6 +71,12 @@ impl eframe::App for MyApp {
             );
 
             ui.add(Slider::new(&mut self.gap_px, 16.0..=100.0).text("gap_px (cut width)"));
+            ui.horizontal(|ui| {
+                ui.label("Break marks:");
+                ui.radio_value(&mut self.break_style, BreakStyle::None, "none");
+                ui.radio_value(&mut self.break_style, BreakStyle::Slash, "//");
+                ui.radio_value(&mut self.break_style, BreakStyle::Zigzag, "zigzag");
+            });
 
             ui.separator();
             match self.mode {
@@ -118,7 +126,8 @@ impl eframe::App for MyApp {
                     self.gap_px,
                 )),
                 _ => None,
-            };
+            }
+            .map(|axis| axis.break_style(self.break_style));
 
             Plot::new("plot_broken_x_multi")
                 .allow_zoom(true)
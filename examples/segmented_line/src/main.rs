@@ -1,6 +1,6 @@
 #![allow(rustdoc::missing_crate_level_docs)]
 use eframe::{egui, egui::Color32};
-use egui_plot::{Legend, Line, Plot, TooltipOptions};
+use egui_plot::{Legend, Line, LineStyle, Plot, TooltipOptions};
 
 fn main() -> eframe::Result<()> {
     eframe::run_native(
@@ -15,6 +15,7 @@ struct Demo {
     y_blocks: Vec<Vec<f64>>,
     xs_full: Vec<f64>,
     ys_full: Vec<f64>,
+    style: LineStyle,
 }
 
 impl Default for Demo {
@@ -37,6 +38,7 @@ impl Default for Demo {
             y_blocks: vec![by0, by1, by2],
             xs_full,
             ys_full,
+            style: LineStyle::Solid,
         }
     }
 }
@@ -45,6 +47,20 @@ impl eframe::App for Demo {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.label("Red = segmented (blocks), Blue = continuous (for comparison)");
+            ui.horizontal(|ui| {
+                ui.label("Style:");
+                for style in [
+                    LineStyle::Solid,
+                    LineStyle::Dashed {
+                        dash: 12.0,
+                        gap: 4.0,
+                    },
+                    LineStyle::dashed_dense(),
+                    LineStyle::Dotted { spacing: 6.0 },
+                ] {
+                    ui.selectable_value(&mut self.style, style, style.to_string());
+                }
+            });
 
             let plot = Plot::new("seg_blocks")
                 .show_x(true)
@@ -58,13 +74,15 @@ impl eframe::App for Demo {
                 plot_ui.line(
                     Line::new_xy_blocks("segmented blocks", xs_blocks, ys_blocks)
                         .color(Color32::from_rgb(220, 80, 80))
-                        .width(2.0),
+                        .width(2.0)
+                        .style(self.style),
                 );
 
                 plot_ui.line(
                     Line::new_xy("continuous (reference)", &self.xs_full, &self.ys_full)
                         .color(Color32::from_rgb(90, 140, 255))
-                        .width(1.0),
+                        .width(1.0)
+                        .style(self.style),
                 );
 
                 let opts = TooltipOptions::default();
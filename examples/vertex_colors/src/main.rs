@@ -0,0 +1,65 @@
+#![allow(rustdoc::missing_crate_level_docs)]
+use eframe::egui;
+use eframe::{App, Frame};
+use egui::{Color32, Context, epaint::Hsva};
+use egui_plot::{Line, Plot};
+
+fn main() -> eframe::Result<()> {
+    eframe::run_native(
+        "Line::colors demo",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(Demo::new()))),
+    )
+}
+
+struct Demo {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    colors: Vec<Color32>,
+    width: f32,
+}
+
+impl Demo {
+    fn new() -> Self {
+        let n = 600;
+        let turns = 4.0;
+        let angles: Vec<f64> = (0..n)
+            .map(|i| i as f64 / (n - 1) as f64 * turns * std::f64::consts::TAU)
+            .collect();
+        let xs = angles.iter().map(|&a| a * a.cos()).collect();
+        let ys = angles.iter().map(|&a| a * a.sin()).collect();
+        let colors = angles
+            .iter()
+            .map(|&a| {
+                let hue = (a / std::f64::consts::TAU).fract() as f32;
+                Hsva::new(hue, 0.85, 1.0, 1.0).into()
+            })
+            .collect();
+
+        Self {
+            xs,
+            ys,
+            colors,
+            width: 4.0,
+        }
+    }
+}
+
+impl App for Demo {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Spiral colored by angle");
+            ui.add(egui::Slider::new(&mut self.width, 1.0..=12.0).text("width"));
+
+            Plot::new("vertex_colors")
+                .data_aspect(1.0)
+                .show(ui, |plot_ui| {
+                    plot_ui.line(
+                        Line::new_xy("spiral", &self.xs, &self.ys)
+                            .colors(&self.colors)
+                            .width(self.width),
+                    );
+                });
+        });
+    }
+}